@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS tasks
+(
+    id          VARCHAR(128) NOT NULL PRIMARY KEY,
+    title       VARCHAR(64)  NOT NULL,
+    description VARCHAR(256) NOT NULL,
+    status      INT          NOT NULL
+);
@@ -0,0 +1,13 @@
+ALTER TABLE tasks
+    DROP FOREIGN KEY fk_tasks_parent_id;
+
+ALTER TABLE tasks
+    DROP INDEX idx_tasks_status,
+    DROP COLUMN completed_at,
+    DROP COLUMN updated_at,
+    DROP COLUMN created_at,
+    DROP COLUMN recurrence,
+    DROP COLUMN parent_id,
+    DROP COLUMN due_all_day,
+    DROP COLUMN due_at,
+    DROP COLUMN priority;
@@ -0,0 +1,14 @@
+ALTER TABLE tasks
+    ADD COLUMN priority     INT          NOT NULL DEFAULT 2 AFTER status,
+    ADD COLUMN due_at       DATETIME     NULL AFTER priority,
+    ADD COLUMN due_all_day  BOOLEAN      NOT NULL DEFAULT FALSE AFTER due_at,
+    ADD COLUMN parent_id    VARCHAR(128) NULL AFTER due_all_day,
+    ADD COLUMN recurrence   VARCHAR(256) NULL AFTER parent_id,
+    ADD COLUMN created_at   DATETIME     NOT NULL DEFAULT CURRENT_TIMESTAMP AFTER recurrence,
+    ADD COLUMN updated_at   DATETIME     NOT NULL DEFAULT CURRENT_TIMESTAMP AFTER created_at,
+    ADD COLUMN completed_at DATETIME     NULL AFTER updated_at,
+    ADD INDEX idx_tasks_status (status),
+    ADD INDEX idx_tasks_priority (priority, due_at),
+    ADD INDEX idx_tasks_due_at (due_at),
+    ADD INDEX idx_tasks_parent_id (parent_id),
+    ADD CONSTRAINT fk_tasks_parent_id FOREIGN KEY (parent_id) REFERENCES tasks (id);
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS labels
+(
+    id    VARCHAR(128) NOT NULL PRIMARY KEY,
+    name  VARCHAR(32)  NOT NULL,
+    color CHAR(7)      NOT NULL,
+    UNIQUE INDEX uq_labels_name (name)
+);
+
+CREATE TABLE IF NOT EXISTS task_labels
+(
+    task_id  VARCHAR(128) NOT NULL,
+    label_id VARCHAR(128) NOT NULL,
+    PRIMARY KEY (task_id, label_id),
+    INDEX idx_task_labels_label_id (label_id),
+    FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+    FOREIGN KEY (label_id) REFERENCES labels (id) ON DELETE CASCADE
+);
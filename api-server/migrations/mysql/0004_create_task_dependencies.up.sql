@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS task_dependencies
+(
+    task_id       VARCHAR(128) NOT NULL,
+    blocked_by_id VARCHAR(128) NOT NULL,
+    PRIMARY KEY (task_id, blocked_by_id),
+    INDEX idx_task_dependencies_blocked_by_id (blocked_by_id),
+    FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+    FOREIGN KEY (blocked_by_id) REFERENCES tasks (id) ON DELETE CASCADE
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS task_events
+(
+    id          BIGINT       NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    task_id     VARCHAR(128) NOT NULL,
+    kind        VARCHAR(16)  NOT NULL,
+    actor       VARCHAR(128) NULL,
+    changes     JSON         NOT NULL,
+    occurred_at DATETIME     NOT NULL,
+    INDEX idx_task_events_task_id (task_id, id)
+);
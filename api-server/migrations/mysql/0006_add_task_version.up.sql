@@ -0,0 +1,2 @@
+ALTER TABLE tasks
+    ADD COLUMN version BIGINT UNSIGNED NOT NULL DEFAULT 1 AFTER completed_at;
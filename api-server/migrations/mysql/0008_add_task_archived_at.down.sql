@@ -0,0 +1,3 @@
+ALTER TABLE tasks
+    DROP INDEX idx_tasks_archived_at,
+    DROP COLUMN archived_at;
@@ -0,0 +1,3 @@
+ALTER TABLE tasks
+    ADD COLUMN archived_at DATETIME NULL AFTER deleted_at,
+    ADD INDEX idx_tasks_archived_at (archived_at);
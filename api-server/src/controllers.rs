@@ -1 +1,6 @@
-pub mod task_controller;
\ No newline at end of file
+pub mod http;
+pub mod label_controller;
+pub mod problem;
+pub mod task_controller;
+pub mod task_export_controller;
+pub mod workflow_controller;
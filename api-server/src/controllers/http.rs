@@ -0,0 +1,151 @@
+use crate::controllers::problem::Problem;
+use serde::Serialize;
+use std::collections::HashMap;
+
+pub struct Request {
+    url: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+#[derive(Debug)]
+pub struct Header {
+    name: String,
+    value: String,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    status: u16,
+    headers: Vec<Header>,
+    body: String,
+    cause: Option<String>,
+}
+
+impl Request {
+    pub fn new(url: &str, headers: Vec<(String, String)>, body: String) -> Self {
+        Self {
+            url: String::from(url),
+            headers: headers
+                .into_iter()
+                .map(|(name, value)| (name.to_ascii_lowercase(), value))
+                .collect(),
+            body,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(|value| value.as_str())
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+impl Header {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: String::from(name),
+            value: String::from(value),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Response {
+    pub fn from_string(body: String) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body,
+            cause: None,
+        }
+    }
+
+    pub fn json<T: Serialize>(payload: &T, status: u16) -> Self {
+        match to_json(payload) {
+            Ok(body) => Self::from_string(body).with_status_code(status),
+            Err(response) => response,
+        }
+    }
+
+    pub fn with_status_code(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_header(mut self, header: Header) -> Self {
+        self.headers.push(header);
+        self
+    }
+
+    pub fn with_cause(mut self, cause: Option<String>) -> Self {
+        self.cause = cause;
+        self
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+
+    pub fn cause(&self) -> Option<&str> {
+        self.cause.as_deref()
+    }
+
+    pub fn into_body(self) -> String {
+        self.body
+    }
+}
+
+pub fn to_json<T: Serialize>(payload: &T) -> Result<String, Response> {
+    serde_json::to_string(payload).map_err(|e| {
+        Problem::internal("Error occurred during serializing response")
+            .with_cause(e)
+            .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_when_name_differs_in_case_then_returns_value() {
+        let request = Request::new(
+            "/api/v1/tasks",
+            vec![(String::from("If-Match"), String::from("\"1\""))],
+            String::new(),
+        );
+        assert_eq!(request.header("if-match"), Some("\"1\""));
+        assert_eq!(request.header("X-Actor"), None);
+    }
+
+    #[test]
+    fn json_when_payload_cannot_be_serialized_then_returns_internal_problem() {
+        let payload = HashMap::from([((1, 2), "value")]);
+
+        let response = Response::json(&payload, 200);
+        assert_eq!(response.status(), 500);
+        assert!(response.cause().is_some());
+        let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+        assert_eq!(body["code"], "internal.error");
+    }
+}
@@ -0,0 +1,245 @@
+use crate::controllers::http::{Request, Response};
+use crate::controllers::problem::Problem;
+use crate::domain::label::label::Label;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::domain::task::task_repository::TaskRepository;
+use crate::use_cases::label::delete_label::{DeleteLabel, DeleteLabelError};
+use crate::use_cases::label::delete_label_command::DeleteLabelCommand;
+use crate::use_cases::label::register_label::{RegisterLabel, RegisterLabelError};
+use crate::use_cases::label::register_label_command::RegisterLabelCommand;
+use crate::use_cases::label::update_label::{UpdateLabel, UpdateLabelError};
+use crate::use_cases::label::update_label_command::UpdateLabelCommand;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LabelDTO {
+    id: String,
+    name: String,
+    color: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LabelPostInput {
+    name: String,
+    color: String,
+}
+#[derive(Serialize, Deserialize)]
+struct LabelPostOutput {
+    data: LabelDTO,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LabelListOutput {
+    data: Vec<LabelDTO>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LabelGetOutput {
+    data: LabelDTO,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LabelPatchInput {
+    name: Option<String>,
+    color: Option<String>,
+}
+#[derive(Serialize, Deserialize)]
+struct LabelPatchOutput {
+    data: LabelDTO,
+}
+#[derive(Serialize, Deserialize)]
+struct LabelDeleteOutput {
+    id: String,
+}
+
+impl LabelDTO {
+    fn from(label: &Label) -> Self {
+        Self {
+            id: label.id.to_string(),
+            name: label.name.to_string(),
+            color: label.color.to_string(),
+        }
+    }
+}
+
+fn label_not_found() -> Problem {
+    Problem::not_found("label.not_found", "Label not found")
+}
+
+fn invalid_label_name() -> Problem {
+    Problem::bad_request("label.name.invalid", "Invalid label name input")
+}
+
+fn invalid_label_color() -> Problem {
+    Problem::bad_request("label.color.invalid", "Invalid label color input")
+}
+
+fn duplicate_label_name() -> Problem {
+    Problem::conflict("label.name.duplicate", "Label name already exists")
+}
+
+impl From<&RegisterLabelError> for Problem {
+    fn from(error: &RegisterLabelError) -> Self {
+        match error {
+            RegisterLabelError::InvalidName => invalid_label_name(),
+            RegisterLabelError::InvalidColor => invalid_label_color(),
+            RegisterLabelError::DuplicateName => duplicate_label_name(),
+            RegisterLabelError::RepositoryError => {
+                Problem::internal("Error occurred during saving label")
+            }
+        }
+    }
+}
+
+impl From<&UpdateLabelError> for Problem {
+    fn from(error: &UpdateLabelError) -> Self {
+        match error {
+            UpdateLabelError::InvalidName => invalid_label_name(),
+            UpdateLabelError::InvalidColor => invalid_label_color(),
+            UpdateLabelError::DuplicateName => duplicate_label_name(),
+            UpdateLabelError::LabelNotFound => label_not_found(),
+            UpdateLabelError::RepositoryError => {
+                Problem::internal("Error occurred during saving label")
+            }
+        }
+    }
+}
+
+impl From<&DeleteLabelError> for Problem {
+    fn from(error: &DeleteLabelError) -> Self {
+        match error {
+            DeleteLabelError::LabelNotFound => label_not_found(),
+            DeleteLabelError::RepositoryError => {
+                Problem::internal("Error occurred during deleting label")
+            }
+        }
+    }
+}
+
+pub struct LabelController<'a, L: LabelRepository, T: TaskRepository> {
+    label_repository: &'a mut L,
+    task_repository: &'a mut T,
+}
+
+impl<'a, L: LabelRepository, T: TaskRepository> LabelController<'a, L, T> {
+    pub fn new(label_repository: &'a mut L, task_repository: &'a mut T) -> Self {
+        Self {
+            label_repository,
+            task_repository,
+        }
+    }
+
+    pub fn get(&self) -> Response {
+        let labels = match self.label_repository.list() {
+            Ok(labels) => labels,
+            Err(e) => {
+                return Problem::internal("Error occurred during loading labels")
+                    .with_cause(e)
+                    .into();
+            }
+        };
+
+        let payload = LabelListOutput {
+            data: labels.iter().map(LabelDTO::from).collect(),
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn get_by_id(&self, id: &str) -> Response {
+        let id = match LabelId::try_from(id) {
+            Ok(id) => id,
+            Err(_) => {
+                return label_not_found().into();
+            }
+        };
+        let label = match self.label_repository.get_by_id(&id) {
+            Ok(label) => label,
+            Err(LabelRepositoryError::NotFound) => {
+                return label_not_found().into();
+            }
+            Err(e) => {
+                return Problem::internal("Error occurred during loading label")
+                    .with_cause(e)
+                    .into();
+            }
+        };
+
+        let payload = LabelGetOutput {
+            data: LabelDTO::from(&label),
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn post(&mut self, request: &Request) -> Response {
+        let payload: LabelPostInput = match serde_json::from_str(request.body()) {
+            Ok(payload) => payload,
+            Err(_) => {
+                return Problem::invalid_request_body().into();
+            }
+        };
+
+        let command = RegisterLabelCommand::new(payload.name.as_str(), payload.color.as_str());
+        let mut use_case = RegisterLabel::new(self.label_repository);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let payload = LabelPostOutput {
+            data: LabelDTO {
+                id: result.id,
+                name: result.name,
+                color: result.color,
+            },
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn patch(&mut self, id: &str, request: &Request) -> Response {
+        let payload: LabelPatchInput = match serde_json::from_str(request.body()) {
+            Ok(payload) => payload,
+            Err(_) => {
+                return Problem::invalid_request_body().into();
+            }
+        };
+
+        let command =
+            match UpdateLabelCommand::new(id, payload.name.as_deref(), payload.color.as_deref()) {
+                Ok(command) => command,
+                Err(_) => {
+                    return label_not_found().into();
+                }
+            };
+        let mut use_case = UpdateLabel::new(self.label_repository);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let payload = LabelPatchOutput {
+            data: LabelDTO {
+                id: result.id,
+                name: result.name,
+                color: result.color,
+            },
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn delete(&mut self, id: &str) -> Response {
+        let command = match DeleteLabelCommand::new(id) {
+            Ok(command) => command,
+            Err(_) => {
+                return label_not_found().into();
+            }
+        };
+        let mut use_case = DeleteLabel::new(self.label_repository, self.task_repository);
+        if let Err(ref e) = use_case.execute(command) {
+            return Problem::from(e).into();
+        }
+
+        let payload = LabelDeleteOutput { id: id.to_string() };
+        Response::json(&payload, 200)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::controllers::http::{Header, Response};
+use serde::Serialize;
+use std::fmt;
+
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+const SERIALIZATION_FAILED_PROBLEM: &str = r#"{"type":"about:blank","title":"Internal Server Error","status":500,"detail":"Error occurred during serializing problem","code":"internal.error"}"#;
+
+#[derive(Debug, Serialize)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    problem_type: &'static str,
+    title: &'static str,
+    status: u16,
+    detail: String,
+    code: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<ProblemFieldError>,
+    #[serde(skip)]
+    cause: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProblemFieldError {
+    field: &'static str,
+    code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual_length: Option<usize>,
+}
+
+impl Problem {
+    pub fn new(status: u16, code: &'static str, detail: &str) -> Self {
+        Self {
+            problem_type: "about:blank",
+            title: title(status),
+            status,
+            detail: String::from(detail),
+            code,
+            errors: Vec::new(),
+            cause: None,
+        }
+    }
+
+    pub fn with_errors(mut self, errors: Vec<ProblemFieldError>) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    pub fn with_cause(mut self, cause: impl fmt::Display) -> Self {
+        self.cause = Some(cause.to_string());
+        self
+    }
+
+    pub fn bad_request(code: &'static str, detail: &str) -> Self {
+        Self::new(400, code, detail)
+    }
+
+    pub fn not_found(code: &'static str, detail: &str) -> Self {
+        Self::new(404, code, detail)
+    }
+
+    pub fn conflict(code: &'static str, detail: &str) -> Self {
+        Self::new(409, code, detail)
+    }
+
+    pub fn invalid_request_body() -> Self {
+        Self::bad_request("request.body.invalid", "Invalid request body")
+    }
+
+    pub fn internal(detail: &str) -> Self {
+        Self::new(500, "internal.error", detail)
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+}
+
+impl ProblemFieldError {
+    pub fn new(field: &'static str, code: &'static str) -> Self {
+        Self {
+            field,
+            code,
+            limit: None,
+            actual_length: None,
+        }
+    }
+
+    pub fn with_length(mut self, limit: usize, actual_length: usize) -> Self {
+        self.limit = Some(limit);
+        self.actual_length = Some(actual_length);
+        self
+    }
+}
+
+impl From<Problem> for Response {
+    fn from(problem: Problem) -> Self {
+        match serde_json::to_string(&problem) {
+            Ok(body) => Response::from_string(body).with_status_code(problem.status),
+            Err(_) => Response::from_string(String::from(SERIALIZATION_FAILED_PROBLEM))
+                .with_status_code(500),
+        }
+        .with_header(Header::new("Content-Type", PROBLEM_CONTENT_TYPE))
+        .with_cause(problem.cause)
+    }
+}
+
+fn title(status: u16) -> &'static str {
+    match status {
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        412 => "Precondition Failed",
+        424 => "Failed Dependency",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_when_problem_given_then_returns_problem_json_response() {
+        let response = Response::from(Problem::bad_request(
+            "task.title.too_long",
+            "Task title is too long",
+        ));
+        assert_eq!(response.status(), 400);
+        assert!(response.headers().iter().any(
+            |header| header.name() == "Content-Type" && header.value() == PROBLEM_CONTENT_TYPE
+        ));
+
+        let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+        assert_eq!(body["type"], "about:blank");
+        assert_eq!(body["title"], "Bad Request");
+        assert_eq!(body["status"], 400);
+        assert_eq!(body["detail"], "Task title is too long");
+        assert_eq!(body["code"], "task.title.too_long");
+        assert!(body.get("errors").is_none());
+    }
+
+    #[test]
+    fn from_when_problem_has_field_errors_then_lists_them() {
+        let response = Response::from(
+            Problem::bad_request("task.validation_failed", "Task input is invalid").with_errors(
+                vec![
+                    ProblemFieldError::new("title", "task.title.too_long").with_length(64, 65),
+                    ProblemFieldError::new("status", "task.status.invalid"),
+                ],
+            ),
+        );
+
+        let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+        assert_eq!(
+            body["errors"],
+            serde_json::json!([
+                {"field": "title", "code": "task.title.too_long", "limit": 64, "actual_length": 65},
+                {"field": "status", "code": "task.status.invalid"},
+            ])
+        );
+    }
+}
@@ -1,20 +1,80 @@
+use crate::controllers::http::{Header, Request, Response};
+use crate::controllers::problem::{Problem, ProblemFieldError};
+use crate::domain::clock::Clock;
+use crate::domain::label::label_repository::LabelRepository;
 use crate::domain::task::task::Task;
-use crate::domain::task::task_repository::TaskRepository;
-use crate::use_cases::task::delete_task::DeleteTask;
+use crate::domain::task::task_delete_policy::TaskDeletePolicy;
+use crate::domain::task::task_description::TaskDescriptionParseError;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_title::TaskTitleParseError;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::query_params::QueryParams;
+use crate::use_cases::task::add_task_dependency::{AddTaskDependency, AddTaskDependencyError};
+use crate::use_cases::task::archive_done_tasks::{ArchiveDoneTasks, ArchiveDoneTasksError};
+use crate::use_cases::task::archive_done_tasks_command::ArchiveDoneTasksCommand;
+use crate::use_cases::task::archive_task::{ArchiveTask, ArchiveTaskError};
+use crate::use_cases::task::archive_task_command::ArchiveTaskCommand;
+use crate::use_cases::task::attach_task_label::{AttachTaskLabel, AttachTaskLabelError};
+use crate::use_cases::task::batch_tasks::{BatchTaskOperationError, BatchTasks, BatchTasksError};
+use crate::use_cases::task::batch_tasks_command::{BatchTaskOperation, BatchTasksCommand};
+use crate::use_cases::task::batch_tasks_result::BatchTaskOutcome;
+use crate::use_cases::task::delete_task::{DeleteTask, DeleteTaskError};
 use crate::use_cases::task::delete_task_command::DeleteTaskCommand;
+use crate::use_cases::task::detach_task_label::{DetachTaskLabel, DetachTaskLabelError};
+use crate::use_cases::task::list_tasks::{ListTasks, ListTasksError};
+use crate::use_cases::task::list_tasks_command::ListTasksCommand;
+use crate::use_cases::task::list_tasks_result::ListTasksResult;
 use crate::use_cases::task::register_task::{RegisterTask, RegisterTaskError};
 use crate::use_cases::task::register_task_command::RegisterTaskCommand;
+use crate::use_cases::task::register_task_result::RegisterTaskResult;
+use crate::use_cases::task::remove_task_dependency::{
+    RemoveTaskDependency, RemoveTaskDependencyError,
+};
+use crate::use_cases::task::restore_task::{RestoreTask, RestoreTaskError};
+use crate::use_cases::task::restore_task_command::RestoreTaskCommand;
+use crate::use_cases::task::task_dependency_command::{
+    TaskDependencyCommand, TaskDependencyCommandError,
+};
+use crate::use_cases::task::task_field_error::TaskFieldError;
+use crate::use_cases::task::task_label_command::{TaskLabelCommand, TaskLabelCommandError};
 use crate::use_cases::task::update_task::{UpdateTask, UpdateTaskError};
 use crate::use_cases::task::update_task_command::UpdateTaskCommand;
-use serde::{Deserialize, Serialize};
-use tiny_http::{Request, Response, StatusCode};
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+const TASK_BATCH_MAX_OPERATIONS: usize = 100;
 
 #[derive(Debug, Serialize, Deserialize)]
-struct TaskDTO {
+pub struct TaskDTO {
     id: String,
     title: String,
     description: String,
     status: i32,
+    priority: i32,
+    due_at: Option<String>,
+    overdue: bool,
+    labels: Vec<String>,
+    parent_id: Option<String>,
+    blocked_by: Vec<String>,
+    recurrence: Option<String>,
+    created_at: String,
+    updated_at: String,
+    completed_at: Option<String>,
+    deleted_at: Option<String>,
+    archived_at: Option<String>,
+    version: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TaskTreeDTO {
+    #[serde(flatten)]
+    task: TaskDTO,
+    children: Vec<TaskTreeDTO>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -22,6 +82,10 @@ struct TaskPostInput {
     title: String,
     description: String,
     status: i32,
+    priority: Option<i32>,
+    due_at: Option<String>,
+    parent_id: Option<String>,
+    recurrence: Option<String>,
 }
 #[derive(Serialize, Deserialize)]
 struct TaskPostOutput {
@@ -31,77 +95,788 @@ struct TaskPostOutput {
 #[derive(Serialize, Deserialize)]
 struct TaskListOutput {
     data: Vec<TaskDTO>,
+    meta: TaskListMeta,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskTreeListOutput {
+    data: Vec<TaskTreeDTO>,
+    meta: TaskListMeta,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskListMeta {
+    total: usize,
+    next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskGetOutput {
+    data: TaskDTO,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct TaskPatchInput {
-    id: String,
     title: Option<String>,
     description: Option<String>,
     status: Option<i32>,
+    priority: Option<i32>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    due_at: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    parent_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    recurrence: Option<Option<String>>,
 }
 #[derive(Debug, Serialize, Deserialize)]
 struct TaskPatchOutput {
     data: TaskDTO,
 }
 #[derive(Serialize, Deserialize)]
-struct TaskDeleteInput {
+struct TaskDeleteOutput {
     id: String,
 }
+
+#[derive(Deserialize)]
+struct TaskBatchInput {
+    operations: Vec<TaskBatchOperationInput>,
+}
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum TaskBatchOperationInput {
+    Create {
+        task: TaskPostInput,
+    },
+    Update {
+        id: String,
+        version: Option<u64>,
+        task: TaskPatchInput,
+    },
+    Delete {
+        id: String,
+        version: Option<u64>,
+    },
+}
+#[derive(Serialize)]
+struct TaskBatchOutput {
+    data: Vec<TaskBatchItemOutput>,
+}
+#[derive(Serialize)]
+struct TaskBatchItemOutput {
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<TaskDTO>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<Problem>,
+}
+
 #[derive(Serialize, Deserialize)]
-struct TaskDeleteOutput {
-    id: String,
+struct TaskArchiveDoneInput {
+    older_than_days: i64,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskArchiveDoneOutput {
+    data: TaskArchiveDoneDTO,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskArchiveDoneDTO {
+    archived: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskEventDTO {
+    kind: String,
+    actor: Option<String>,
+    occurred_at: String,
+    changes: Vec<TaskFieldChangeDTO>,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskFieldChangeDTO {
+    field: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+#[derive(Serialize, Deserialize)]
+struct TaskHistoryOutput {
+    data: Vec<TaskEventDTO>,
+}
+
+impl TaskDTO {
+    pub fn from(task: &Task, now: DateTime<Utc>) -> Self {
+        Self {
+            id: task.id.to_string(),
+            title: task.title.to_string(),
+            description: task.description.to_string(),
+            status: task.status.to_int(),
+            priority: task.priority.to_int(),
+            due_at: task.due_at.as_ref().map(|due_at| due_at.to_string()),
+            overdue: task.is_overdue(now),
+            labels: task.labels.iter().map(|label| label.to_string()).collect(),
+            parent_id: task
+                .parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.to_string()),
+            blocked_by: task.blocked_by.iter().map(|id| id.to_string()).collect(),
+            recurrence: task
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+            created_at: task.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            updated_at: task.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            completed_at: task
+                .completed_at
+                .map(|completed_at| completed_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            deleted_at: task
+                .deleted_at
+                .map(|deleted_at| deleted_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            archived_at: task
+                .archived_at
+                .map(|archived_at| archived_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            version: task.version,
+        }
+    }
+}
+
+impl From<RegisterTaskResult> for TaskDTO {
+    fn from(result: RegisterTaskResult) -> Self {
+        Self {
+            id: result.id,
+            title: result.title,
+            description: result.description,
+            status: result.status,
+            priority: result.priority,
+            due_at: result.due_at,
+            overdue: result.overdue,
+            labels: result.labels,
+            parent_id: result.parent_id,
+            blocked_by: result.blocked_by,
+            recurrence: result.recurrence,
+            created_at: result.created_at,
+            updated_at: result.updated_at,
+            completed_at: result.completed_at,
+            deleted_at: result.deleted_at,
+            archived_at: result.archived_at,
+            version: result.version,
+        }
+    }
+}
+
+impl From<UpdateTaskResult> for TaskDTO {
+    fn from(result: UpdateTaskResult) -> Self {
+        Self {
+            id: result.id,
+            title: result.title,
+            description: result.description,
+            status: result.status,
+            priority: result.priority,
+            due_at: result.due_at,
+            overdue: result.overdue,
+            labels: result.labels,
+            parent_id: result.parent_id,
+            blocked_by: result.blocked_by,
+            recurrence: result.recurrence,
+            created_at: result.created_at,
+            updated_at: result.updated_at,
+            completed_at: result.completed_at,
+            deleted_at: result.deleted_at,
+            archived_at: result.archived_at,
+            version: result.version,
+        }
+    }
+}
+
+impl TaskBatchItemOutput {
+    fn from(outcome: BatchTaskOutcome) -> Self {
+        let (data, id) = match outcome {
+            BatchTaskOutcome::Registered(result) => (Some(result.into()), None),
+            BatchTaskOutcome::Updated(result) => (Some(result.into()), None),
+            BatchTaskOutcome::Deleted(id) => (None, Some(id)),
+        };
+        Self {
+            status: 200,
+            data,
+            id,
+            error: None,
+        }
+    }
+
+    fn failed(problem: Problem) -> Self {
+        Self {
+            status: problem.status(),
+            data: None,
+            id: None,
+            error: Some(problem),
+        }
+    }
+}
+
+impl TaskTreeDTO {
+    fn from(task: &Task, children: &HashMap<TaskId, Vec<Task>>, now: DateTime<Utc>) -> Self {
+        Self {
+            task: TaskDTO::from(task, now),
+            children: children
+                .get(&task.id)
+                .map(|tasks| {
+                    tasks
+                        .iter()
+                        .map(|child| TaskTreeDTO::from(child, children, now))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TaskEventDTO {
+    fn from(event: &TaskEvent) -> Self {
+        Self {
+            kind: event.kind.to_string(),
+            actor: event.actor.clone(),
+            occurred_at: event.occurred_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            changes: event
+                .changes
+                .iter()
+                .map(|change| TaskFieldChangeDTO {
+                    field: change.field.clone(),
+                    before: change.before.clone(),
+                    after: change.after.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn if_match(request: &Request) -> Result<Option<u64>, ()> {
+    let Some(value) = request.header("If-Match") else {
+        return Ok(None);
+    };
+
+    let value = value.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    value
+        .trim_start_matches("W/")
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(|version| version.parse().ok())
+        .map(Some)
+        .ok_or(())
+}
+
+fn etag(version: u64) -> Header {
+    Header::new("ETag", &format!("\"{}\"", version))
+}
+
+fn invalid_if_match() -> Problem {
+    Problem::new(400, "header.if_match.invalid", "Invalid If-Match header")
+}
+
+fn precondition_failed() -> Problem {
+    Problem::new(
+        412,
+        "task.version_mismatch",
+        "Task version does not match If-Match",
+    )
+}
+
+fn task_not_found() -> Problem {
+    Problem::not_found("task.not_found", "Task not found")
+}
+
+fn blocker_not_found() -> Problem {
+    Problem::not_found("task.blocker.not_found", "Blocking task not found")
+}
+
+fn label_not_found() -> Problem {
+    Problem::not_found("label.not_found", "Label not found")
+}
+
+fn task_conflict() -> Problem {
+    Problem::conflict("task.conflict", "Task was modified concurrently")
+}
+
+fn invalid_query(code: &'static str, detail: &str) -> Response {
+    Problem::bad_request(code, detail).into()
+}
+
+impl From<&TaskFieldError> for ProblemFieldError {
+    fn from(error: &TaskFieldError) -> Self {
+        match error {
+            TaskFieldError::Title(TaskTitleParseError::Empty) => {
+                ProblemFieldError::new("title", "task.title.empty")
+            }
+            TaskFieldError::Title(TaskTitleParseError::TooLong { limit, actual }) => {
+                ProblemFieldError::new("title", "task.title.too_long").with_length(*limit, *actual)
+            }
+            TaskFieldError::Description(TaskDescriptionParseError::Empty) => {
+                ProblemFieldError::new("description", "task.description.empty")
+            }
+            TaskFieldError::Description(TaskDescriptionParseError::TooLong { limit, actual }) => {
+                ProblemFieldError::new("description", "task.description.too_long")
+                    .with_length(*limit, *actual)
+            }
+            TaskFieldError::Status(_) => ProblemFieldError::new("status", "task.status.invalid"),
+        }
+    }
+}
+
+fn invalid_fields(errors: &[TaskFieldError]) -> Problem {
+    Problem::bad_request("task.validation_failed", "Task input is invalid")
+        .with_errors(errors.iter().map(ProblemFieldError::from).collect())
+}
+
+impl From<&TaskRepositoryError> for Problem {
+    fn from(error: &TaskRepositoryError) -> Self {
+        match error {
+            TaskRepositoryError::NotFound => task_not_found(),
+            TaskRepositoryError::Conflict => task_conflict(),
+            TaskRepositoryError::AlreadyExists => {
+                Problem::conflict("task.already_exists", "Task already exists")
+            }
+            TaskRepositoryError::TransactionState | TaskRepositoryError::DbError(_) => {
+                Problem::internal("Error occurred in task repository").with_cause(error)
+            }
+        }
+    }
+}
+
+impl From<&RegisterTaskError> for Problem {
+    fn from(error: &RegisterTaskError) -> Self {
+        match error {
+            RegisterTaskError::InvalidFields(errors) => invalid_fields(errors),
+            RegisterTaskError::InvalidPriority => {
+                Problem::bad_request("task.priority.invalid", "Invalid task priority input")
+            }
+            RegisterTaskError::InvalidDueDate => {
+                Problem::bad_request("task.due_at.invalid", "Invalid task due date input")
+            }
+            RegisterTaskError::InvalidParent => {
+                Problem::bad_request("task.parent_id.invalid", "Invalid task parent input")
+            }
+            RegisterTaskError::MaxDepthExceeded => {
+                Problem::bad_request("task.hierarchy.too_deep", "Task hierarchy is too deep")
+            }
+            RegisterTaskError::InvalidRecurrence => {
+                Problem::bad_request("task.recurrence.invalid", "Invalid task recurrence input")
+            }
+            RegisterTaskError::RecurrenceRequiresDueDate => Problem::bad_request(
+                "task.recurrence.due_at_required",
+                "Task recurrence requires a due date",
+            ),
+            RegisterTaskError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&UpdateTaskError> for Problem {
+    fn from(error: &UpdateTaskError) -> Self {
+        match error {
+            UpdateTaskError::InvalidFields(errors) => invalid_fields(errors),
+            UpdateTaskError::InvalidPriority => {
+                Problem::bad_request("task.priority.invalid", "Invalid task priority input")
+            }
+            UpdateTaskError::InvalidDueDate => {
+                Problem::bad_request("task.due_at.invalid", "Invalid task due date input")
+            }
+            UpdateTaskError::InvalidParent => {
+                Problem::bad_request("task.parent_id.invalid", "Invalid task parent input")
+            }
+            UpdateTaskError::HierarchyCycle => Problem::conflict(
+                "task.hierarchy.cycle",
+                "Task hierarchy would contain a cycle",
+            ),
+            UpdateTaskError::MaxDepthExceeded => {
+                Problem::bad_request("task.hierarchy.too_deep", "Task hierarchy is too deep")
+            }
+            UpdateTaskError::InvalidTransition => Problem::conflict(
+                "task.status.transition_not_allowed",
+                "Task status transition is not allowed",
+            ),
+            UpdateTaskError::BlockedByUnfinishedTasks => {
+                Problem::conflict("task.blocked", "Task is blocked by unfinished tasks")
+            }
+            UpdateTaskError::InvalidRecurrence => {
+                Problem::bad_request("task.recurrence.invalid", "Invalid task recurrence input")
+            }
+            UpdateTaskError::RecurrenceRequiresDueDate => Problem::bad_request(
+                "task.recurrence.due_at_required",
+                "Task recurrence requires a due date",
+            ),
+            UpdateTaskError::TaskNotFound => task_not_found(),
+            UpdateTaskError::PreconditionFailed => precondition_failed(),
+            UpdateTaskError::Conflict => task_conflict(),
+            UpdateTaskError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&DeleteTaskError> for Problem {
+    fn from(error: &DeleteTaskError) -> Self {
+        match error {
+            DeleteTaskError::TaskNotFound => task_not_found(),
+            DeleteTaskError::HasChildren => {
+                Problem::conflict("task.has_children", "Task has child tasks")
+            }
+            DeleteTaskError::PreconditionFailed => precondition_failed(),
+            DeleteTaskError::Conflict => task_conflict(),
+            DeleteTaskError::RepositoryError => {
+                Problem::internal("Error occurred during deleting task")
+            }
+        }
+    }
+}
+
+impl From<&RestoreTaskError> for Problem {
+    fn from(error: &RestoreTaskError) -> Self {
+        match error {
+            RestoreTaskError::TaskNotFound => task_not_found(),
+            RestoreTaskError::NotTrashed => {
+                Problem::conflict("task.not_trashed", "Task is not in the trash")
+            }
+            RestoreTaskError::ParentTrashed => {
+                Problem::conflict("task.parent_trashed", "Parent task is in the trash")
+            }
+            RestoreTaskError::Conflict => task_conflict(),
+            RestoreTaskError::RepositoryError => {
+                Problem::internal("Error occurred during restoring task")
+            }
+        }
+    }
+}
+
+impl From<&ArchiveTaskError> for Problem {
+    fn from(error: &ArchiveTaskError) -> Self {
+        match error {
+            ArchiveTaskError::TaskNotFound => task_not_found(),
+            ArchiveTaskError::NotDone => Problem::conflict("task.not_done", "Task is not done"),
+            ArchiveTaskError::AlreadyArchived => {
+                Problem::conflict("task.already_archived", "Task is already archived")
+            }
+            ArchiveTaskError::Conflict => task_conflict(),
+            ArchiveTaskError::RepositoryError => {
+                Problem::internal("Error occurred during archiving task")
+            }
+        }
+    }
+}
+
+impl From<&AttachTaskLabelError> for Problem {
+    fn from(error: &AttachTaskLabelError) -> Self {
+        match error {
+            AttachTaskLabelError::TaskNotFound => task_not_found(),
+            AttachTaskLabelError::LabelNotFound => label_not_found(),
+            AttachTaskLabelError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&DetachTaskLabelError> for Problem {
+    fn from(error: &DetachTaskLabelError) -> Self {
+        match error {
+            DetachTaskLabelError::TaskNotFound => task_not_found(),
+            DetachTaskLabelError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&AddTaskDependencyError> for Problem {
+    fn from(error: &AddTaskDependencyError) -> Self {
+        match error {
+            AddTaskDependencyError::TaskNotFound => task_not_found(),
+            AddTaskDependencyError::BlockerNotFound => blocker_not_found(),
+            AddTaskDependencyError::CycleDetected => Problem::conflict(
+                "task.dependency.cycle",
+                "Task dependencies would contain a cycle",
+            ),
+            AddTaskDependencyError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&RemoveTaskDependencyError> for Problem {
+    fn from(error: &RemoveTaskDependencyError) -> Self {
+        match error {
+            RemoveTaskDependencyError::TaskNotFound => task_not_found(),
+            RemoveTaskDependencyError::RepositoryError => {
+                Problem::internal("Error occurred during saving task")
+            }
+        }
+    }
+}
+
+impl From<&BatchTaskOperationError> for Problem {
+    fn from(error: &BatchTaskOperationError) -> Self {
+        match error {
+            BatchTaskOperationError::Register(e) => e.into(),
+            BatchTaskOperationError::Update(e) => e.into(),
+            BatchTaskOperationError::Delete(e) => e.into(),
+        }
+    }
+}
+
+fn batch_failed(count: usize, index: usize, problem: Problem) -> Response {
+    let status = problem.status();
+    let mut data: Vec<TaskBatchItemOutput> = (0..count)
+        .map(|_| {
+            TaskBatchItemOutput::failed(Problem::new(424, "batch.not_executed", "Not executed"))
+        })
+        .collect();
+    if let Some(item) = data.get_mut(index) {
+        *item = TaskBatchItemOutput::failed(problem);
+    }
+    let payload = TaskBatchOutput { data };
+    Response::json(&payload, status)
+}
+
+fn actor(request: &Request) -> Option<String> {
+    request
+        .header("X-Actor")
+        .map(|actor| actor.trim().to_string())
+        .filter(|actor| !actor.is_empty())
+}
+
+fn deserialize_nullable<'de, D, V>(deserializer: D) -> Result<Option<Option<V>>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    Option::<V>::deserialize(deserializer).map(Some)
 }
 
 impl TaskListOutput {
-    fn from(task_list: &Vec<Task>) -> Self {
-        let mut list = Vec::new();
-        for task in task_list {
-            list.push(TaskDTO {
-                id: task.id.to_string(),
-                title: task.title.to_string(),
-                description: task.description.to_string(),
-                status: task.status.to_int(),
-            })
+    fn from(result: &ListTasksResult, now: DateTime<Utc>) -> Self {
+        Self {
+            data: result
+                .tasks
+                .iter()
+                .map(|task| TaskDTO::from(task, now))
+                .collect(),
+            meta: TaskListMeta {
+                total: result.total,
+                next_cursor: result.next_cursor.clone(),
+            },
         }
+    }
+}
 
-        Self { data: list }
+impl TaskTreeListOutput {
+    fn from(result: &ListTasksResult, now: DateTime<Utc>) -> Self {
+        Self {
+            data: result
+                .tasks
+                .iter()
+                .map(|task| TaskTreeDTO::from(task, &result.children, now))
+                .collect(),
+            meta: TaskListMeta {
+                total: result.total,
+                next_cursor: result.next_cursor.clone(),
+            },
+        }
     }
 }
 
 #[derive(Debug)]
-pub struct TaskController<'a, T: TaskRepository> {
+pub struct TaskController<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
     repository: &'a mut T,
+    workflow: &'a TaskWorkflow,
+    clock: &'a C,
 }
 
-impl<'a, T: TaskRepository> TaskController<'a, T> {
-    pub fn new(repository: &'a mut T) -> Self {
-        Self { repository }
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> TaskController<'a, T, C> {
+    pub fn new(repository: &'a mut T, workflow: &'a TaskWorkflow, clock: &'a C) -> Self {
+        Self {
+            repository,
+            workflow,
+            clock,
+        }
+    }
+
+    pub fn get(&self, request: &Request) -> Response {
+        self.list(request, None, false)
+    }
+
+    pub fn get_children(&self, id: &str, request: &Request) -> Response {
+        self.list(request, Some(id), false)
+    }
+
+    pub fn get_trash(&self, request: &Request) -> Response {
+        self.list(request, None, true)
+    }
+
+    fn list(&self, request: &Request, parent: Option<&str>, trashed: bool) -> Response {
+        let params = QueryParams::from_url(request.url());
+        let status = match params.get("status").map(str::parse::<i32>).transpose() {
+            Ok(status) => status,
+            Err(_) => {
+                return invalid_query("query.status.invalid", "Invalid task status input");
+            }
+        };
+        let priority = match params.get("priority").map(str::parse::<i32>).transpose() {
+            Ok(priority) => priority,
+            Err(_) => {
+                return invalid_query("query.priority.invalid", "Invalid task priority input");
+            }
+        };
+        let overdue = match params.get("overdue").map(str::parse::<bool>).transpose() {
+            Ok(overdue) => overdue,
+            Err(_) => {
+                return invalid_query("query.overdue.invalid", "Invalid overdue input");
+            }
+        };
+        let limit = match params.get("limit").map(str::parse::<usize>).transpose() {
+            Ok(limit) => limit,
+            Err(_) => {
+                return invalid_query("query.limit.invalid", "Invalid limit input");
+            }
+        };
+        let tree = match params.get("tree").map(str::parse::<bool>).transpose() {
+            Ok(tree) => tree.unwrap_or_default(),
+            Err(_) => {
+                return invalid_query("query.tree.invalid", "Invalid tree input");
+            }
+        };
+        let include_archived = match params
+            .get("include_archived")
+            .map(str::parse::<bool>)
+            .transpose()
+        {
+            Ok(include_archived) => include_archived.unwrap_or_default(),
+            Err(_) => {
+                return invalid_query(
+                    "query.include_archived.invalid",
+                    "Invalid include_archived input",
+                );
+            }
+        };
+
+        let command = ListTasksCommand {
+            status,
+            priority,
+            label: params.get("label").map(String::from),
+            parent: parent.or(params.get("parent")).map(String::from),
+            due_before: params.get("due_before").map(String::from),
+            overdue,
+            sort: params.get("sort").map(String::from),
+            order: params.get("order").map(String::from),
+            limit,
+            cursor: params.get("cursor").map(String::from),
+            tree,
+            trashed,
+            include_archived,
+        };
+        let use_case = ListTasks::new(self.repository, self.workflow, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => {
+                let (code, message) = match e {
+                    ListTasksError::InvalidStatus => {
+                        ("query.status.invalid", "Invalid task status input")
+                    }
+                    ListTasksError::InvalidPriority => {
+                        ("query.priority.invalid", "Invalid task priority input")
+                    }
+                    ListTasksError::InvalidLabel => ("query.label.invalid", "Invalid label input"),
+                    ListTasksError::InvalidParent | ListTasksError::ParentNotFound
+                        if parent.is_some() =>
+                    {
+                        return task_not_found().into();
+                    }
+                    ListTasksError::InvalidParent | ListTasksError::ParentNotFound => {
+                        ("query.parent.invalid", "Invalid parent input")
+                    }
+                    ListTasksError::InvalidDueBefore => {
+                        ("query.due_before.invalid", "Invalid due_before input")
+                    }
+                    ListTasksError::InvalidSort => ("query.sort.invalid", "Invalid sort input"),
+                    ListTasksError::InvalidOrder => ("query.order.invalid", "Invalid order input"),
+                    ListTasksError::InvalidLimit => ("query.limit.invalid", "Invalid limit input"),
+                    ListTasksError::InvalidCursor => {
+                        ("query.cursor.invalid", "Invalid cursor input")
+                    }
+                    ListTasksError::RepositoryError => {
+                        return Problem::internal("Error occurred during loading tasks").into();
+                    }
+                };
+                return invalid_query(code, message);
+            }
+        };
+        if tree {
+            Response::json(&TaskTreeListOutput::from(&result, self.clock.now()), 200)
+        } else {
+            Response::json(&TaskListOutput::from(&result, self.clock.now()), 200)
+        }
     }
 
-    pub fn get(&self) -> Response<std::io::Cursor<Vec<u8>>> {
-        let tasks = match self.repository.list() {
-            Ok(tasks) => tasks,
+    pub fn get_history(&self, id: &str) -> Response {
+        let id = match TaskId::try_from(id) {
+            Ok(id) => id,
             Err(_) => {
-                return Response::from_string(String::from("Error occurred during saving task"))
-                    .with_status_code(StatusCode::from(500));
+                return task_not_found().into();
+            }
+        };
+        let events = match self.repository.history(&id) {
+            Ok(events) => events,
+            Err(ref e) => {
+                return Problem::from(e).into();
             }
         };
-        let task_list_output = TaskListOutput::from(&tasks);
-        let json = serde_json::to_string(&task_list_output).unwrap();
+        if events.is_empty() {
+            match self.repository.get_by_id(&id) {
+                Ok(_) => {}
+                Err(TaskRepositoryError::NotFound) => {
+                    return task_not_found().into();
+                }
+                Err(ref e) => {
+                    return Problem::from(e).into();
+                }
+            }
+        }
 
-        Response::from_string(String::from(json)).with_status_code(200)
+        let payload = TaskHistoryOutput {
+            data: events.iter().map(TaskEventDTO::from).collect(),
+        };
+        Response::json(&payload, 200)
     }
 
-    pub fn post(&mut self, request: &mut Request) -> Response<std::io::Cursor<Vec<u8>>> {
-        let mut body = String::new();
-        request.as_reader().read_to_string(&mut body).unwrap();
+    pub fn get_by_id(&self, id: &str) -> Response {
+        let id = match TaskId::try_from(id) {
+            Ok(id) => id,
+            Err(_) => {
+                return task_not_found().into();
+            }
+        };
+        let task = match self.repository.get_by_id(&id) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return task_not_found().into();
+            }
+            Err(ref e) => {
+                return Problem::from(e).into();
+            }
+        };
+
+        let payload = TaskGetOutput {
+            data: TaskDTO::from(&task, self.clock.now()),
+        };
+        Response::json(&payload, 200).with_header(etag(task.version))
+    }
 
-        let payload: TaskPostInput = match serde_json::from_str(body.as_str()) {
+    pub fn post(&mut self, request: &Request) -> Response {
+        let payload: TaskPostInput = match serde_json::from_str(request.body()) {
             Ok(payload) => payload,
             Err(_) => {
-                return Response::from_string(String::from("Invalid request body"))
-                    .with_status_code(StatusCode::from(400));
+                return Problem::invalid_request_body().into();
             }
         };
 
@@ -109,118 +884,516 @@ impl<'a, T: TaskRepository> TaskController<'a, T> {
             payload.title.as_str(),
             payload.description.as_str(),
             payload.status,
+            payload.priority,
+            payload.due_at.as_deref(),
+            payload.parent_id.as_deref(),
+            payload.recurrence.as_deref(),
+            actor(request).as_deref(),
         );
-        let mut use_case = RegisterTask::new(self.repository);
+        let mut use_case = RegisterTask::new(self.repository, self.workflow, self.clock);
         let result = match use_case.execute(command) {
             Ok(result) => result,
-            Err(ref e) => {
-                return match e {
-                    RegisterTaskError::InvalidTitle => {
-                        Response::from_string(String::from("Invalid task title input"))
-                            .with_status_code(StatusCode::from(400))
-                    }
-                    RegisterTaskError::InvalidDescription => {
-                        Response::from_string(String::from("Invalid task description input"))
-                            .with_status_code(StatusCode::from(400))
-                    }
-                    RegisterTaskError::InvalidStatus => {
-                        Response::from_string(String::from("Invalid task status input"))
-                            .with_status_code(StatusCode::from(400))
-                    }
-                    RegisterTaskError::RepositoryError => {
-                        Response::from_string(String::from("Error occurred during saving task"))
-                            .with_status_code(StatusCode::from(500))
-                    }
-                };
-            }
+            Err(ref e) => return Problem::from(e).into(),
         };
 
+        let version = result.version;
         let payload = TaskPostOutput {
-            data: TaskDTO {
-                id: result.id,
-                title: result.title,
-                description: result.description,
-                status: result.status,
-            },
+            data: result.into(),
         };
-        Response::from_string(serde_json::to_string(&payload).unwrap()).with_status_code(200)
+        Response::json(&payload, 200).with_header(etag(version))
     }
 
-    pub fn patch(&mut self, request: &mut Request) -> Response<std::io::Cursor<Vec<u8>>> {
-        let mut body = String::new();
-        request.as_reader().read_to_string(&mut body).unwrap();
-
-        let payload: TaskPatchInput = match serde_json::from_str(body.as_str()) {
+    pub fn patch(&mut self, id: &str, request: &Request) -> Response {
+        let Ok(expected_version) = if_match(request) else {
+            return invalid_if_match().into();
+        };
+        let payload: TaskPatchInput = match serde_json::from_str(request.body()) {
             Ok(payload) => payload,
             Err(_) => {
-                return Response::from_string(String::from("Invalid request body"))
-                    .with_status_code(StatusCode::from(400));
+                return Problem::invalid_request_body().into();
             }
         };
 
         let command = match UpdateTaskCommand::new(
-            payload.id.as_str(),
+            id,
             payload.title.as_deref(),
             payload.description.as_deref(),
             payload.status,
+            payload.priority,
+            payload.due_at.as_ref().map(|due_at| due_at.as_deref()),
+            payload
+                .parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.as_deref()),
+            payload
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.as_deref()),
+            actor(request).as_deref(),
+            expected_version,
         ) {
             Ok(command) => command,
-            Err(_) => return Response::from_string(String::from("Invalid request payload")),
+            Err(_) => {
+                return task_not_found().into();
+            }
         };
-        let mut use_case = UpdateTask::new(self.repository);
+        let mut use_case = UpdateTask::new(self.repository, self.workflow, self.clock);
         let result = match use_case.execute(command) {
             Ok(result) => result,
-            Err(ref e) => {
-                return match e {
-                    UpdateTaskError::InvalidTitle => {
-                        return Response::from_string(String::from("Invalid task title input"));
-                    }
-                    UpdateTaskError::InvalidDescription => {
-                        Response::from_string(String::from("Invalid task description input"))
-                    }
-                    UpdateTaskError::InvalidStatus => {
-                        Response::from_string(String::from("Invalid task status input"))
-                    }
-                    UpdateTaskError::TaskNotFound => {
-                        Response::from_string(String::from("Task not found"))
-                    }
-                };
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let version = result.version;
+        let payload = TaskPatchOutput {
+            data: result.into(),
+        };
+        Response::json(&payload, 200).with_header(etag(version))
+    }
+
+    pub fn delete(&mut self, id: &str, request: &Request, policy: TaskDeletePolicy) -> Response {
+        let Ok(expected_version) = if_match(request) else {
+            return invalid_if_match().into();
+        };
+        let command = match DeleteTaskCommand::new(id, actor(request).as_deref(), expected_version)
+        {
+            Ok(command) => command,
+            Err(_) => {
+                return task_not_found().into();
+            }
+        };
+        let mut use_case = DeleteTask::new(self.repository, policy, self.clock);
+        if let Err(ref e) = use_case.execute(command) {
+            return Problem::from(e).into();
+        }
+
+        let payload = TaskDeleteOutput { id: id.to_string() };
+        Response::json(&payload, 200)
+    }
+
+    pub fn restore(&mut self, id: &str, request: &Request) -> Response {
+        let command = match RestoreTaskCommand::new(id, actor(request).as_deref()) {
+            Ok(command) => command,
+            Err(_) => {
+                return task_not_found().into();
             }
         };
+        let mut use_case = RestoreTask::new(self.repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
 
+        let version = result.version;
         let payload = TaskPatchOutput {
-            data: TaskDTO {
-                id: result.id,
-                title: result.title,
-                description: result.description,
-                status: result.status,
-            },
+            data: result.into(),
+        };
+        Response::json(&payload, 200).with_header(etag(version))
+    }
+
+    pub fn archive(&mut self, id: &str, request: &Request) -> Response {
+        let command = match ArchiveTaskCommand::new(id, actor(request).as_deref()) {
+            Ok(command) => command,
+            Err(_) => {
+                return task_not_found().into();
+            }
+        };
+        let mut use_case = ArchiveTask::new(self.repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let version = result.version;
+        let payload = TaskPatchOutput {
+            data: result.into(),
         };
-        Response::from_string(serde_json::to_string(&payload).unwrap()).with_status_code(200)
+        Response::json(&payload, 200).with_header(etag(version))
     }
 
-    pub fn delete(&mut self, request: &mut Request) -> Response<std::io::Cursor<Vec<u8>>> {
-        let mut body = String::new();
-        request.as_reader().read_to_string(&mut body).unwrap();
+    pub fn archive_done(&mut self, request: &Request) -> Response {
+        let payload: TaskArchiveDoneInput = match serde_json::from_str(request.body()) {
+            Ok(payload) => payload,
+            Err(_) => {
+                return Problem::invalid_request_body().into();
+            }
+        };
+
+        let command = match ArchiveDoneTasksCommand::new(
+            payload.older_than_days,
+            actor(request).as_deref(),
+        ) {
+            Ok(command) => command,
+            Err(_) => {
+                return Problem::bad_request(
+                    "task.older_than_days.invalid",
+                    "Invalid older_than_days input",
+                )
+                .into();
+            }
+        };
+        let mut use_case = ArchiveDoneTasks::new(self.repository, self.clock);
+        let archived = match use_case.execute(command) {
+            Ok(archived) => archived,
+            Err(ArchiveDoneTasksError::RepositoryError) => {
+                return Problem::internal("Error occurred during archiving tasks").into();
+            }
+        };
+
+        let payload = TaskArchiveDoneOutput {
+            data: TaskArchiveDoneDTO { archived },
+        };
+        Response::json(&payload, 200)
+    }
 
-        let payload: TaskDeleteInput = match serde_json::from_str(body.as_str()) {
+    pub fn batch(&mut self, request: &Request, policy: TaskDeletePolicy) -> Response {
+        let payload: TaskBatchInput = match serde_json::from_str(request.body()) {
             Ok(payload) => payload,
             Err(_) => {
-                return Response::from_string(String::from("Invalid request body"))
-                    .with_status_code(StatusCode::from(400));
+                return Problem::invalid_request_body().into();
             }
         };
+        if payload.operations.is_empty() || payload.operations.len() > TASK_BATCH_MAX_OPERATIONS {
+            return Problem::bad_request(
+                "batch.size.invalid",
+                &format!(
+                    "Batch must contain 1 to {} operations",
+                    TASK_BATCH_MAX_OPERATIONS
+                ),
+            )
+            .into();
+        }
+
+        let actor = actor(request);
+        let count = payload.operations.len();
+        let mut operations = Vec::with_capacity(count);
+        for (index, operation) in payload.operations.into_iter().enumerate() {
+            let operation = match operation {
+                TaskBatchOperationInput::Create { task } => {
+                    BatchTaskOperation::Register(RegisterTaskCommand::new(
+                        task.title.as_str(),
+                        task.description.as_str(),
+                        task.status,
+                        task.priority,
+                        task.due_at.as_deref(),
+                        task.parent_id.as_deref(),
+                        task.recurrence.as_deref(),
+                        actor.as_deref(),
+                    ))
+                }
+                TaskBatchOperationInput::Update { id, version, task } => {
+                    match UpdateTaskCommand::new(
+                        id.as_str(),
+                        task.title.as_deref(),
+                        task.description.as_deref(),
+                        task.status,
+                        task.priority,
+                        task.due_at.as_ref().map(|due_at| due_at.as_deref()),
+                        task.parent_id
+                            .as_ref()
+                            .map(|parent_id| parent_id.as_deref()),
+                        task.recurrence
+                            .as_ref()
+                            .map(|recurrence| recurrence.as_deref()),
+                        actor.as_deref(),
+                        version,
+                    ) {
+                        Ok(command) => BatchTaskOperation::Update(command),
+                        Err(_) => return batch_failed(count, index, task_not_found()),
+                    }
+                }
+                TaskBatchOperationInput::Delete { id, version } => {
+                    match DeleteTaskCommand::new(id.as_str(), actor.as_deref(), version) {
+                        Ok(command) => BatchTaskOperation::Delete(command),
+                        Err(_) => return batch_failed(count, index, task_not_found()),
+                    }
+                }
+            };
+            operations.push(operation);
+        }
 
-        let command = match DeleteTaskCommand::new(payload.id.as_str()) {
+        let mut use_case = BatchTasks::new(self.repository, self.workflow, policy, self.clock);
+        let outcomes = match use_case.execute(BatchTasksCommand::new(operations)) {
+            Ok(outcomes) => outcomes,
+            Err(BatchTasksError::OperationFailed { index, ref error }) => {
+                return batch_failed(count, index, error.into());
+            }
+            Err(BatchTasksError::RepositoryError) => {
+                return Problem::internal("Error occurred during saving tasks").into();
+            }
+        };
+
+        let payload = TaskBatchOutput {
+            data: outcomes
+                .into_iter()
+                .map(TaskBatchItemOutput::from)
+                .collect(),
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn attach_label<L: LabelRepository>(
+        &mut self,
+        id: &str,
+        label_id: &str,
+        label_repository: &L,
+    ) -> Response {
+        let command = match TaskLabelCommand::new(id, label_id) {
             Ok(command) => command,
-            Err(_) => return Response::from_string(String::from("Invalid request payload")),
+            Err(TaskLabelCommandError::InvalidFormatTaskId) => {
+                return task_not_found().into();
+            }
+            Err(TaskLabelCommandError::InvalidFormatLabelId) => {
+                return label_not_found().into();
+            }
+        };
+        let mut use_case = AttachTaskLabel::new(self.repository, label_repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
         };
-        let mut use_case = DeleteTask::new(self.repository);
-        use_case.execute(command).unwrap();
 
-        let payload = TaskDeleteOutput {
-            id: payload.id.to_string(),
+        let payload = TaskPatchOutput {
+            data: result.into(),
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn detach_label(&mut self, id: &str, label_id: &str) -> Response {
+        let command = match TaskLabelCommand::new(id, label_id) {
+            Ok(command) => command,
+            Err(TaskLabelCommandError::InvalidFormatTaskId) => {
+                return task_not_found().into();
+            }
+            Err(TaskLabelCommandError::InvalidFormatLabelId) => {
+                return label_not_found().into();
+            }
+        };
+        let mut use_case = DetachTaskLabel::new(self.repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let payload = TaskPatchOutput {
+            data: result.into(),
+        };
+        Response::json(&payload, 200)
+    }
+
+    pub fn add_dependency(&mut self, id: &str, blocker_id: &str) -> Response {
+        let command = match TaskDependencyCommand::new(id, blocker_id) {
+            Ok(command) => command,
+            Err(TaskDependencyCommandError::InvalidFormatTaskId) => {
+                return task_not_found().into();
+            }
+            Err(TaskDependencyCommandError::InvalidFormatBlockerId) => {
+                return blocker_not_found().into();
+            }
+        };
+        let mut use_case = AddTaskDependency::new(self.repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let payload = TaskPatchOutput {
+            data: result.into(),
         };
-        Response::from_string(serde_json::to_string(&payload).unwrap()).with_status_code(200)
+        Response::json(&payload, 200)
+    }
+
+    pub fn remove_dependency(&mut self, id: &str, blocker_id: &str) -> Response {
+        let command = match TaskDependencyCommand::new(id, blocker_id) {
+            Ok(command) => command,
+            Err(TaskDependencyCommandError::InvalidFormatTaskId) => {
+                return task_not_found().into();
+            }
+            Err(TaskDependencyCommandError::InvalidFormatBlockerId) => {
+                return blocker_not_found().into();
+            }
+        };
+        let mut use_case = RemoveTaskDependency::new(self.repository, self.clock);
+        let result = match use_case.execute(command) {
+            Ok(result) => result,
+            Err(ref e) => return Problem::from(e).into(),
+        };
+
+        let payload = TaskPatchOutput {
+            data: result.into(),
+        };
+        Response::json(&payload, 200)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_due_date::TaskDueDate;
+    use crate::repositories::task::task_failing_repository::{
+        TaskFailingOperation, TaskFailingRepository,
+    };
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn request(body: &str) -> Request {
+        Request::new("/api/v1/tasks", Vec::new(), String::from(body))
+    }
+
+    fn problem(response: Response) -> (u16, serde_json::Value) {
+        let status = response.status();
+        assert!(
+            response
+                .headers()
+                .iter()
+                .any(|header| header.name() == "Content-Type"
+                    && header.value() == "application/problem+json")
+        );
+        (status, serde_json::from_str(&response.into_body()).unwrap())
+    }
+
+    #[test]
+    fn post_when_several_fields_are_invalid_then_returns_all_field_errors() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut controller = TaskController::new(&mut repository, &workflow, &clock);
+        let body = format!(
+            r#"{{"title":"{}","description":"","status":9}}"#,
+            "A".repeat(65)
+        );
+
+        let (status, body) = problem(controller.post(&request(&body)));
+        assert_eq!(status, 400);
+        assert_eq!(body["status"], 400);
+        assert_eq!(body["code"], "task.validation_failed");
+        assert_eq!(
+            body["errors"],
+            serde_json::json!([
+                {"field": "title", "code": "task.title.too_long", "limit": 64, "actual_length": 65},
+                {"field": "description", "code": "task.description.empty"},
+                {"field": "status", "code": "task.status.invalid"},
+            ])
+        );
+    }
+
+    #[test]
+    fn patch_when_fields_are_invalid_then_returns_bad_request() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut controller = TaskController::new(&mut repository, &workflow, &clock);
+        let response = controller.post(&request(
+            r#"{"title":"Task Title","description":"Task Description","status":1}"#,
+        ));
+        let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+        let id = body["data"]["id"].as_str().unwrap().to_string();
+
+        let (status, body) = problem(controller.patch(&id, &request(r#"{"title":"","status":9}"#)));
+        assert_eq!(status, 400);
+        assert_eq!(body["code"], "task.validation_failed");
+        assert_eq!(
+            body["errors"],
+            serde_json::json!([
+                {"field": "title", "code": "task.title.empty"},
+                {"field": "status", "code": "task.status.invalid"},
+            ])
+        );
+    }
+
+    #[test]
+    fn patch_when_repository_fails_then_returns_internal_server_error() {
+        let mut inner = TaskInMemoryRepository::new();
+        let ids = inner.register_test_data();
+        let mut repository = TaskFailingRepository::new(inner, TaskFailingOperation::GetById);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut controller = TaskController::new(&mut repository, &workflow, &clock);
+
+        let (status, body) = problem(controller.patch(
+            &ids.first().unwrap().to_string(),
+            &request(r#"{"title":"Task Title"}"#),
+        ));
+        assert_eq!(status, 500);
+        assert_eq!(body["code"], "internal.error");
+    }
+
+    #[test]
+    fn patch_when_task_does_not_exist_then_returns_not_found() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut controller = TaskController::new(&mut repository, &workflow, &clock);
+
+        let (status, body) = problem(controller.patch(
+            &TaskId::new().to_string(),
+            &request(r#"{"title":"Task Title"}"#),
+        ));
+        assert_eq!(status, 404);
+        assert_eq!(body["code"], "task.not_found");
+    }
+
+    #[test]
+    fn patch_when_if_match_is_malformed_then_returns_bad_request() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut controller = TaskController::new(&mut repository, &workflow, &clock);
+        let request = Request::new(
+            "/api/v1/tasks",
+            vec![(String::from("If-Match"), String::from("version-1"))],
+            String::from(r#"{"title":"Task Title"}"#),
+        );
+
+        let (status, body) = problem(controller.patch(&ids[0].to_string(), &request));
+        assert_eq!(status, 400);
+        assert_eq!(body["code"], "header.if_match.invalid");
+    }
+
+    #[test]
+    fn get_history_when_task_has_no_events_then_returns_empty_list() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let controller = TaskController::new(&mut repository, &workflow, &clock);
+
+        let response = controller.get_history(&ids[0].to_string());
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+        assert_eq!(body["data"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn get_history_when_task_does_not_exist_then_returns_not_found() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let controller = TaskController::new(&mut repository, &workflow, &clock);
+
+        let (status, body) = problem(controller.get_history(&TaskId::new().to_string()));
+        assert_eq!(status, 404);
+        assert_eq!(body["code"], "task.not_found");
+    }
+
+    #[test]
+    fn get_by_id_when_due_date_passed_on_clock_then_marks_task_overdue() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        let due_at = TaskDueDate::try_from("2026-10-19").unwrap();
+        repository.update(task.change_due_at(Some(due_at))).unwrap();
+        let workflow = TaskWorkflow::default();
+
+        for (now, overdue) in [
+            ("2026-10-18T09:00:00Z", false),
+            ("2026-10-20T09:00:00Z", true),
+        ] {
+            let clock = FixedClock::new(now);
+            let controller = TaskController::new(&mut repository, &workflow, &clock);
+            let response = controller.get_by_id(&ids[0].to_string());
+            let body: serde_json::Value = serde_json::from_str(&response.into_body()).unwrap();
+            assert_eq!(body["data"]["overdue"], overdue);
+        }
     }
 }
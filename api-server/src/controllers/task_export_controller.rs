@@ -0,0 +1,131 @@
+use crate::controllers::http::{Request, Response, to_json};
+use crate::controllers::problem::Problem;
+use crate::controllers::task_controller::TaskDTO;
+use crate::domain::clock::Clock;
+use crate::domain::task::task_async_repository::TaskAsyncRepository;
+use crate::query_params::QueryParams;
+use crate::use_cases::task::export_tasks::{ExportTasks, ExportTasksError};
+use crate::use_cases::task::export_tasks_command::ExportTasksCommand;
+use futures_util::{Stream, stream};
+use std::sync::Arc;
+
+pub const TASK_EXPORT_CONTENT_TYPE: &str = "application/x-ndjson";
+
+pub struct TaskExportController<'a, T: TaskAsyncRepository, C: Clock> {
+    repository: Arc<T>,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskAsyncRepository + 'static, C: Clock> TaskExportController<'a, T, C> {
+    pub fn new(repository: Arc<T>, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn export(
+        &self,
+        request: &Request,
+    ) -> Result<impl Stream<Item = Result<String, Response>> + Send + 'static, Response> {
+        let params = QueryParams::from_url(request.url());
+        let include_archived = match params
+            .get("include_archived")
+            .map(str::parse::<bool>)
+            .transpose()
+        {
+            Ok(include_archived) => include_archived.unwrap_or_default(),
+            Err(_) => {
+                return Err(Problem::bad_request(
+                    "query.include_archived.invalid",
+                    "Invalid include_archived input",
+                )
+                .into());
+            }
+        };
+
+        let command = ExportTasksCommand { include_archived };
+        let use_case = ExportTasks::new(self.repository.clone(), command);
+        let now = self.clock.now();
+        Ok(stream::try_unfold(
+            use_case,
+            move |mut use_case| async move {
+                let Some(tasks) = use_case
+                    .next_page()
+                    .await
+                    .map_err(|e| Response::from(Problem::from(&e)))?
+                else {
+                    return Ok(None);
+                };
+                let mut lines = String::new();
+                for task in &tasks {
+                    lines.push_str(&to_json(&TaskDTO::from(task, now))?);
+                    lines.push('\n');
+                }
+                Ok(Some((lines, use_case)))
+            },
+        ))
+    }
+}
+
+impl From<&ExportTasksError> for Problem {
+    fn from(error: &ExportTasksError) -> Self {
+        match error {
+            ExportTasksError::RepositoryError => {
+                Problem::internal("Error occurred during exporting tasks")
+                    .with_cause(format!("{:?}", error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use crate::repositories::task::task_locked_repository::TaskLockedRepository;
+    use futures_util::StreamExt;
+    use std::pin::pin;
+
+    fn controller(
+        clock: &FixedClock,
+    ) -> TaskExportController<'_, TaskLockedRepository, FixedClock> {
+        let mut repository = TaskInMemoryRepository::new();
+        repository.register_test_data();
+        TaskExportController::new(Arc::new(TaskLockedRepository::new(repository)), clock)
+    }
+
+    #[tokio::test]
+    async fn export_when_tasks_exist_then_streams_one_json_line_per_task() {
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let request = Request::new("/api/v1/tasks:export", Vec::new(), String::new());
+        let Ok(lines) = controller(&clock).export(&request) else {
+            panic!("export was rejected");
+        };
+
+        let mut lines = pin!(lines);
+        let mut body = String::new();
+        while let Some(chunk) = lines.next().await {
+            body.push_str(&chunk.unwrap());
+        }
+        let titles: Vec<String> = body
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .map(|task| task["title"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(titles.len(), 3);
+        assert!(titles.contains(&String::from("AAA")));
+    }
+
+    #[test]
+    fn export_when_include_archived_is_invalid_then_returns_bad_request() {
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let request = Request::new(
+            "/api/v1/tasks:export?include_archived=maybe",
+            Vec::new(),
+            String::new(),
+        );
+        let Err(response) = controller(&clock).export(&request) else {
+            panic!("export was accepted");
+        };
+        assert_eq!(response.status(), 400);
+    }
+}
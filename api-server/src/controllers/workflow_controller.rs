@@ -0,0 +1,68 @@
+use crate::controllers::http::Response;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct WorkflowStatusDTO {
+    id: i32,
+    name: String,
+    category: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorkflowTransitionDTO {
+    from: i32,
+    to: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorkflowDTO {
+    statuses: Vec<WorkflowStatusDTO>,
+    transitions: Vec<WorkflowTransitionDTO>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorkflowGetOutput {
+    data: WorkflowDTO,
+}
+
+impl WorkflowDTO {
+    fn from(workflow: &TaskWorkflow) -> Self {
+        Self {
+            statuses: workflow
+                .statuses()
+                .iter()
+                .map(|definition| WorkflowStatusDTO {
+                    id: definition.status.to_int(),
+                    name: definition.name.clone(),
+                    category: definition.status.category().to_string(),
+                })
+                .collect(),
+            transitions: workflow
+                .transitions()
+                .iter()
+                .map(|(from, to)| WorkflowTransitionDTO {
+                    from: *from,
+                    to: *to,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct WorkflowController<'a> {
+    workflow: &'a TaskWorkflow,
+}
+
+impl<'a> WorkflowController<'a> {
+    pub fn new(workflow: &'a TaskWorkflow) -> Self {
+        Self { workflow }
+    }
+
+    pub fn get(&self) -> Response {
+        let payload = WorkflowGetOutput {
+            data: WorkflowDTO::from(self.workflow),
+        };
+        Response::json(&payload, 200)
+    }
+}
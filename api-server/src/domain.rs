@@ -1 +1,3 @@
+pub mod clock;
+pub mod label;
 pub mod task;
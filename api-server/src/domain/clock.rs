@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(DateTime<Utc>);
+
+#[cfg(test)]
+impl FixedClock {
+    pub fn new(now: &str) -> Self {
+        Self(
+            DateTime::parse_from_rfc3339(now)
+                .unwrap()
+                .with_timezone(&Utc),
+        )
+    }
+}
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod label;
+pub mod label_color;
+pub mod label_id;
+pub mod label_name;
+pub mod label_repository;
@@ -0,0 +1,24 @@
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+
+#[derive(Clone)]
+pub struct Label {
+    pub id: LabelId,
+    pub name: LabelName,
+    pub color: LabelColor,
+}
+
+impl Label {
+    pub fn new(id: LabelId, name: LabelName, color: LabelColor) -> Label {
+        Label { id, name, color }
+    }
+
+    pub fn change_name(self, name: LabelName) -> Self {
+        Self { name, ..self }
+    }
+
+    pub fn change_color(self, color: LabelColor) -> Self {
+        Self { color, ..self }
+    }
+}
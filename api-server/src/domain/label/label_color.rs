@@ -0,0 +1,63 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LabelColorParseError {
+    InvalidFormat,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelColor {
+    value: String,
+}
+
+impl TryFrom<&str> for LabelColor {
+    type Error = LabelColorParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        let hex = match value.strip_prefix('#') {
+            Some(hex) => hex,
+            None => return Err(LabelColorParseError::InvalidFormat),
+        };
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(LabelColorParseError::InvalidFormat);
+        }
+
+        Ok(LabelColor {
+            value: value.to_ascii_lowercase(),
+        })
+    }
+}
+
+impl fmt::Display for LabelColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_when_valid_value_then_returns_normalized_instance() {
+        let label_color = LabelColor::try_from("#FFaa00").unwrap();
+        assert_eq!(label_color.value, "#ffaa00");
+    }
+
+    #[test]
+    fn try_from_when_value_has_no_hash_then_returns_error() {
+        let err = LabelColor::try_from("ffaa00").unwrap_err();
+        assert_eq!(err, LabelColorParseError::InvalidFormat);
+    }
+
+    #[test]
+    fn try_from_when_value_is_not_hex_then_returns_error() {
+        let err = LabelColor::try_from("#ggaa00").unwrap_err();
+        assert_eq!(err, LabelColorParseError::InvalidFormat);
+
+        let err = LabelColor::try_from("#fff").unwrap_err();
+        assert_eq!(err, LabelColorParseError::InvalidFormat);
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum LabelIdParseError {
+    InvalidIdString,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct LabelId {
+    value: Uuid,
+}
+
+impl TryFrom<&str> for LabelId {
+    type Error = LabelIdParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = match Uuid::parse_str(value) {
+            Ok(value) => value,
+            Err(_) => return Err(LabelIdParseError::InvalidIdString),
+        };
+
+        Ok(Self { value })
+    }
+}
+
+impl LabelId {
+    pub fn new() -> Self {
+        Self {
+            value: Uuid::new_v4(),
+        }
+    }
+
+    pub fn from(value: Uuid) -> Self {
+        Self { value }
+    }
+
+    pub fn to_uuid(&self) -> Uuid {
+        self.value
+    }
+}
+
+impl fmt::Display for LabelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_when_valid_uuid_then_returns_instance() {
+        let uuid = Uuid::new_v4();
+        let label_id = LabelId::try_from(uuid.to_string().as_str()).unwrap();
+        assert_eq!(label_id.value, uuid);
+    }
+
+    #[test]
+    fn try_from_when_invalid_uuid_then_returns_error() {
+        let label_id = LabelId::try_from("sample-invalid-uuid");
+        assert!(label_id.is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+const LABEL_NAME_MAX_LENGTH: usize = 32;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LabelNameParseError {
+    Empty,
+    TooLong,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelName {
+    value: String,
+}
+
+impl TryFrom<&str> for LabelName {
+    type Error = LabelNameParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return Err(LabelNameParseError::Empty);
+        }
+        if value.len() > LABEL_NAME_MAX_LENGTH {
+            return Err(LabelNameParseError::TooLong);
+        }
+
+        Ok(LabelName {
+            value: String::from(value),
+        })
+    }
+}
+
+impl fmt::Display for LabelName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_when_valid_value_then_returns_instance() {
+        let label_name = LabelName::try_from(" bug ").unwrap();
+        assert_eq!(label_name.value, "bug");
+
+        let value = String::from("A").repeat(LABEL_NAME_MAX_LENGTH);
+        let label_name = LabelName::try_from(value.as_str()).unwrap();
+        assert_eq!(label_name.value, value);
+    }
+
+    #[test]
+    fn try_from_when_value_is_empty_then_returns_error() {
+        let err = LabelName::try_from("  ").unwrap_err();
+        assert_eq!(err, LabelNameParseError::Empty);
+    }
+
+    #[test]
+    fn try_from_when_value_is_too_long_then_returns_error() {
+        let value = String::from("A").repeat(LABEL_NAME_MAX_LENGTH + 1);
+        let err = LabelName::try_from(value.as_str()).unwrap_err();
+        assert_eq!(err, LabelNameParseError::TooLong);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_id::LabelId;
+use std::fmt;
+
+pub trait LabelRepository {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError>;
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError>;
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError>;
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError>;
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum LabelRepositoryError {
+    AlreadyExists,
+    NotFound,
+    DbError(String),
+}
+
+impl fmt::Display for LabelRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LabelRepositoryError::AlreadyExists => write!(f, "label already exists"),
+            LabelRepositoryError::NotFound => write!(f, "label not found"),
+            LabelRepositoryError::DbError(message) => write!(f, "db error: {}", message),
+        }
+    }
+}
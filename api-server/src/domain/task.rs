@@ -1,6 +1,19 @@
+#[allow(clippy::module_inception)]
 pub mod task;
+pub mod task_async_repository;
+pub mod task_cursor;
+pub mod task_delete_policy;
+pub mod task_dependency;
 pub mod task_description;
+pub mod task_due_date;
+pub mod task_event;
+pub mod task_event_repository;
+pub mod task_hierarchy;
 pub mod task_id;
+pub mod task_priority;
+pub mod task_query;
+pub mod task_recurrence;
 pub mod task_repository;
 pub mod task_status;
 pub mod task_title;
+pub mod task_workflow;
@@ -1,7 +1,12 @@
+use crate::domain::label::label_id::LabelId;
 use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
 use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_recurrence::TaskRecurrence;
 use crate::domain::task::task_status::TaskStatus;
 use crate::domain::task::task_title::TaskTitle;
+use chrono::{DateTime, Utc};
 
 #[derive(Clone)]
 pub struct Task {
@@ -9,6 +14,18 @@ pub struct Task {
     pub title: TaskTitle,
     pub description: TaskDescription,
     pub status: TaskStatus,
+    pub priority: TaskPriority,
+    pub due_at: Option<TaskDueDate>,
+    pub labels: Vec<LabelId>,
+    pub parent_id: Option<TaskId>,
+    pub blocked_by: Vec<TaskId>,
+    pub recurrence: Option<TaskRecurrence>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub version: u64,
 }
 
 impl Task {
@@ -17,12 +34,25 @@ impl Task {
         title: TaskTitle,
         description: TaskDescription,
         status: TaskStatus,
+        created_at: DateTime<Utc>,
     ) -> Task {
         Task {
             id,
             title,
             description,
             status,
+            priority: TaskPriority::default(),
+            due_at: None,
+            labels: Vec::new(),
+            parent_id: None,
+            blocked_by: Vec::new(),
+            recurrence: None,
+            created_at,
+            updated_at: created_at,
+            completed_at: None,
+            deleted_at: None,
+            archived_at: None,
+            version: 1,
         }
     }
 
@@ -40,4 +70,170 @@ impl Task {
     pub fn change_status(self, status: TaskStatus) -> Self {
         Self { status, ..self }
     }
+
+    pub fn change_priority(self, priority: TaskPriority) -> Self {
+        Self { priority, ..self }
+    }
+
+    pub fn change_due_at(self, due_at: Option<TaskDueDate>) -> Self {
+        Self { due_at, ..self }
+    }
+
+    pub fn change_labels(self, labels: Vec<LabelId>) -> Self {
+        Self { labels, ..self }
+    }
+
+    pub fn attach_label(mut self, label_id: LabelId) -> Self {
+        if !self.labels.contains(&label_id) {
+            self.labels.push(label_id);
+        }
+        self
+    }
+
+    pub fn detach_label(mut self, label_id: &LabelId) -> Self {
+        self.labels.retain(|id| id != label_id);
+        self
+    }
+
+    pub fn change_parent(self, parent_id: Option<TaskId>) -> Self {
+        Self { parent_id, ..self }
+    }
+
+    pub fn change_blocked_by(self, blocked_by: Vec<TaskId>) -> Self {
+        Self { blocked_by, ..self }
+    }
+
+    pub fn add_blocker(mut self, blocker_id: TaskId) -> Self {
+        if !self.blocked_by.contains(&blocker_id) {
+            self.blocked_by.push(blocker_id);
+        }
+        self
+    }
+
+    pub fn remove_blocker(mut self, blocker_id: &TaskId) -> Self {
+        self.blocked_by.retain(|id| id != blocker_id);
+        self
+    }
+
+    pub fn change_recurrence(self, recurrence: Option<TaskRecurrence>) -> Self {
+        Self { recurrence, ..self }
+    }
+
+    pub fn change_updated_at(self, updated_at: DateTime<Utc>) -> Self {
+        Self { updated_at, ..self }
+    }
+
+    pub fn change_completed_at(self, completed_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            completed_at,
+            ..self
+        }
+    }
+
+    pub fn change_deleted_at(self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        Self { deleted_at, ..self }
+    }
+
+    pub fn change_archived_at(self, archived_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            archived_at,
+            ..self
+        }
+    }
+
+    pub fn change_version(self, version: u64) -> Self {
+        Self { version, ..self }
+    }
+
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        match &self.due_at {
+            Some(due_at) => !self.status.is_done() && due_at.is_passed(now),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_id::TaskId;
+    use crate::domain::task::task_status::TaskStatusCategory;
+
+    fn task(status: TaskStatus, due_at: Option<&str>) -> Task {
+        Task::new(
+            TaskId::new(),
+            TaskTitle::try_from("Task Title").unwrap(),
+            TaskDescription::try_from("Task Description").unwrap(),
+            status,
+            DateTime::UNIX_EPOCH,
+        )
+        .change_due_at(due_at.map(|due_at| TaskDueDate::try_from(due_at).unwrap()))
+    }
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn is_overdue_when_due_date_passed_then_returns_true() {
+        let task = task(
+            TaskStatus::new(2, TaskStatusCategory::InProgress),
+            Some("2026-10-18"),
+        );
+        assert!(task.is_overdue(utc("2026-10-19T00:00:00Z")));
+    }
+
+    #[test]
+    fn is_overdue_when_due_date_not_passed_then_returns_false() {
+        let task = task(
+            TaskStatus::new(1, TaskStatusCategory::Todo),
+            Some("2026-10-18"),
+        );
+        assert!(!task.is_overdue(utc("2026-10-18T12:00:00Z")));
+    }
+
+    #[test]
+    fn is_overdue_when_task_is_done_then_returns_false() {
+        let task = task(
+            TaskStatus::new(3, TaskStatusCategory::Done),
+            Some("2026-10-18"),
+        );
+        assert!(!task.is_overdue(utc("2026-10-20T00:00:00Z")));
+    }
+
+    #[test]
+    fn attach_label_when_already_attached_then_keeps_single_entry() {
+        let label_id = LabelId::new();
+        let task = task(TaskStatus::new(1, TaskStatusCategory::Todo), None)
+            .attach_label(label_id.clone())
+            .attach_label(label_id.clone());
+        assert_eq!(task.labels, vec![label_id]);
+    }
+
+    #[test]
+    fn detach_label_when_attached_then_removes_label() {
+        let label_id = LabelId::new();
+        let other_id = LabelId::new();
+        let task = task(TaskStatus::new(1, TaskStatusCategory::Todo), None)
+            .attach_label(label_id.clone())
+            .attach_label(other_id.clone())
+            .detach_label(&label_id);
+        assert_eq!(task.labels, vec![other_id]);
+    }
+
+    #[test]
+    fn is_overdue_when_no_due_date_then_returns_false() {
+        let task = task(TaskStatus::new(1, TaskStatusCategory::Todo), None);
+        assert!(!task.is_overdue(utc("2026-10-20T00:00:00Z")));
+    }
 }
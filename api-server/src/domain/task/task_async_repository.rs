@@ -0,0 +1,9 @@
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_repository::TaskRepositoryError;
+
+pub trait TaskAsyncRepository: Send + Sync {
+    fn search(
+        &self,
+        query: &TaskQuery,
+    ) -> impl Future<Output = Result<TaskPage, TaskRepositoryError>> + Send;
+}
@@ -0,0 +1,128 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{SortOrder, TaskSortKey};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, SecondsFormat, Utc};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskCursorParseError {
+    InvalidCursor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskCursor {
+    pub sort: TaskSortKey,
+    pub order: SortOrder,
+    pub title: String,
+    pub status: i32,
+    pub priority: TaskPriority,
+    pub due_at: Option<DateTime<Utc>>,
+    pub id: TaskId,
+}
+
+impl TaskCursor {
+    pub fn new(task: &Task, sort: TaskSortKey, order: SortOrder) -> Self {
+        Self {
+            sort,
+            order,
+            title: task.title.to_string(),
+            status: task.status.to_int(),
+            priority: task.priority.clone(),
+            due_at: task.due_at.as_ref().map(|due_at| due_at.deadline()),
+            id: task.id.clone(),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let due_at = self
+            .due_at
+            .map(|due_at| due_at.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            .unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.sort,
+            self.order,
+            self.status,
+            self.priority.to_int(),
+            due_at,
+            self.id,
+            self.title
+        ))
+    }
+}
+
+impl TryFrom<&str> for TaskCursor {
+    type Error = TaskCursorParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .ok_or(TaskCursorParseError::InvalidCursor)?;
+        let fields: Vec<&str> = decoded.splitn(7, '\n').collect();
+        let [sort, order, status, priority, due_at, id, title] = fields[..] else {
+            return Err(TaskCursorParseError::InvalidCursor);
+        };
+
+        let invalid = |_| TaskCursorParseError::InvalidCursor;
+        Ok(Self {
+            sort: TaskSortKey::try_from(sort).map_err(invalid)?,
+            order: SortOrder::try_from(order).map_err(invalid)?,
+            title: title.to_string(),
+            status: status
+                .parse()
+                .map_err(|_| TaskCursorParseError::InvalidCursor)?,
+            priority: priority
+                .parse::<i32>()
+                .ok()
+                .and_then(|priority| TaskPriority::try_from(priority).ok())
+                .ok_or(TaskCursorParseError::InvalidCursor)?,
+            due_at: match due_at {
+                "" => None,
+                due_at => Some(
+                    DateTime::parse_from_rfc3339(due_at)
+                        .map_err(|_| TaskCursorParseError::InvalidCursor)?
+                        .with_timezone(&Utc),
+                ),
+            },
+            id: TaskId::try_from(id).map_err(|_| TaskCursorParseError::InvalidCursor)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_description::TaskDescription;
+    use crate::domain::task::task_due_date::TaskDueDate;
+    use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+    use crate::domain::task::task_title::TaskTitle;
+
+    #[test]
+    fn try_from_when_encoded_cursor_then_returns_same_cursor() {
+        let task = Task::new(
+            TaskId::new(),
+            TaskTitle::try_from("Line\nbreak").unwrap(),
+            TaskDescription::try_from("Task Description").unwrap(),
+            TaskStatus::new(1, TaskStatusCategory::Todo),
+            DateTime::UNIX_EPOCH,
+        )
+        .change_due_at(Some(TaskDueDate::try_from("2026-10-18").unwrap()));
+        let cursor = TaskCursor::new(&task, TaskSortKey::DueAt, SortOrder::Desc);
+
+        assert_eq!(TaskCursor::try_from(cursor.encode().as_str()), Ok(cursor));
+    }
+
+    #[test]
+    fn try_from_when_cursor_is_not_encoded_then_returns_error() {
+        for value in ["2", "not a cursor", &URL_SAFE_NO_PAD.encode("id\nasc")] {
+            assert_eq!(
+                TaskCursor::try_from(value),
+                Err(TaskCursorParseError::InvalidCursor)
+            );
+        }
+    }
+}
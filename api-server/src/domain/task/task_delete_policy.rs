@@ -0,0 +1,52 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskDeletePolicyParseError {
+    UnknownPolicy,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskDeletePolicy {
+    Cascade,
+    Reparent,
+    #[default]
+    Reject,
+}
+
+impl TryFrom<&str> for TaskDeletePolicy {
+    type Error = TaskDeletePolicyParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "cascade" => Ok(TaskDeletePolicy::Cascade),
+            "reparent" => Ok(TaskDeletePolicy::Reparent),
+            "reject" => Ok(TaskDeletePolicy::Reject),
+            _ => Err(TaskDeletePolicyParseError::UnknownPolicy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_when_valid_value_then_returns_instance() {
+        assert_eq!(
+            TaskDeletePolicy::try_from("cascade").unwrap(),
+            TaskDeletePolicy::Cascade
+        );
+        assert_eq!(
+            TaskDeletePolicy::try_from("reparent").unwrap(),
+            TaskDeletePolicy::Reparent
+        );
+        assert_eq!(
+            TaskDeletePolicy::try_from("reject").unwrap(),
+            TaskDeletePolicy::Reject
+        );
+    }
+
+    #[test]
+    fn try_from_when_invalid_value_then_returns_error() {
+        let err = TaskDeletePolicy::try_from("orphan").unwrap_err();
+        assert_eq!(err, TaskDeletePolicyParseError::UnknownPolicy);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use std::collections::HashSet;
+
+pub fn creates_cycle<T: TaskRepository>(
+    repository: &T,
+    id: &TaskId,
+    blocker_id: &TaskId,
+) -> Result<bool, TaskRepositoryError> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![blocker_id.clone()];
+    while let Some(current_id) = stack.pop() {
+        if &current_id == id {
+            return Ok(true);
+        }
+        if !visited.insert(current_id.clone()) {
+            continue;
+        }
+
+        match repository.get_by_id(&current_id) {
+            Ok(task) => stack.extend(task.blocked_by),
+            Err(TaskRepositoryError::NotFound) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(false)
+}
+
+pub fn unfinished_blockers<T: TaskRepository>(
+    repository: &T,
+    task: &Task,
+) -> Result<Vec<TaskId>, TaskRepositoryError> {
+    let mut blockers = Vec::new();
+    for blocker_id in &task.blocked_by {
+        match repository.get_by_id(blocker_id) {
+            Ok(blocker) if !blocker.status.is_done() && !blocker.is_trashed() => {
+                blockers.push(blocker.id)
+            }
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(blockers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn block(repository: &TaskInMemoryRepository, id: &TaskId, blocker_id: &TaskId) {
+        let task = repository.get_by_id(id).unwrap();
+        repository
+            .update(task.add_blocker(blocker_id.clone()))
+            .unwrap();
+    }
+
+    #[test]
+    fn creates_cycle_when_blocker_depends_on_task_then_returns_true() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        block(&repository, &ids[1], &ids[0]);
+        block(&repository, &ids[2], &ids[1]);
+
+        assert!(creates_cycle(&repository, &ids[0], &ids[2]).unwrap());
+        assert!(creates_cycle(&repository, &ids[0], &ids[0]).unwrap());
+        assert!(!creates_cycle(&repository, &ids[2], &ids[0]).unwrap());
+    }
+
+    #[test]
+    fn unfinished_blockers_when_blocker_is_done_then_excludes_it() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        block(&repository, &ids[2], &ids[0]);
+        block(&repository, &ids[2], &ids[1]);
+        let blocker = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(blocker.change_status(TaskStatus::new(3, TaskStatusCategory::Done)))
+            .unwrap();
+
+        let task = repository.get_by_id(&ids[2]).unwrap();
+        let blockers = unfinished_blockers(&repository, &task).unwrap();
+        assert_eq!(blockers, vec![ids[1].clone()]);
+    }
+}
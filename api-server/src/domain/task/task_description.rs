@@ -1,12 +1,14 @@
+use std::fmt;
+
 const TASK_DESCRIPTION_MAX_LENGTH: usize = 256;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum TaskDescriptionParseError {
     Empty,
-    TooLong,
+    TooLong { limit: usize, actual: usize },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TaskDescription {
     value: String,
 }
@@ -20,8 +22,12 @@ impl TryFrom<&str> for TaskDescription {
         if value.is_empty() {
             return Err(TaskDescriptionParseError::Empty);
         }
-        if value.len() > TASK_DESCRIPTION_MAX_LENGTH {
-            return Err(TaskDescriptionParseError::TooLong);
+        let length = value.chars().count();
+        if length > TASK_DESCRIPTION_MAX_LENGTH {
+            return Err(TaskDescriptionParseError::TooLong {
+                limit: TASK_DESCRIPTION_MAX_LENGTH,
+                actual: length,
+            });
         }
 
         Ok(Self {
@@ -30,9 +36,9 @@ impl TryFrom<&str> for TaskDescription {
     }
 }
 
-impl TaskDescription {
-    pub fn to_string(&self) -> String {
-        self.value.to_string()
+impl fmt::Display for TaskDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
     }
 }
 
@@ -61,6 +67,28 @@ mod tests {
     fn _try_from_when_value_is_too_long_then_returns_error() {
         let value = String::from("A").repeat(TASK_DESCRIPTION_MAX_LENGTH + 1);
         let err = TaskDescription::try_from(value.as_str()).unwrap_err();
-        assert_eq!(err, TaskDescriptionParseError::TooLong);
+        assert_eq!(
+            err,
+            TaskDescriptionParseError::TooLong {
+                limit: TASK_DESCRIPTION_MAX_LENGTH,
+                actual: TASK_DESCRIPTION_MAX_LENGTH + 1,
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_when_value_has_multibyte_characters_then_counts_characters() {
+        let value = String::from("é").repeat(TASK_DESCRIPTION_MAX_LENGTH);
+        assert!(TaskDescription::try_from(value.as_str()).is_ok());
+
+        let value = String::from("é").repeat(TASK_DESCRIPTION_MAX_LENGTH + 1);
+        let err = TaskDescription::try_from(value.as_str()).unwrap_err();
+        assert_eq!(
+            err,
+            TaskDescriptionParseError::TooLong {
+                limit: TASK_DESCRIPTION_MAX_LENGTH,
+                actual: TASK_DESCRIPTION_MAX_LENGTH + 1,
+            }
+        );
     }
 }
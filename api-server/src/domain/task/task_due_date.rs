@@ -0,0 +1,139 @@
+use chrono::{DateTime, Days, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Utc};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskDueDateParseError {
+    InvalidFormat,
+    MissingTimezone,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskDueDate {
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+impl TryFrom<&str> for TaskDueDate {
+    type Error = TaskDueDateParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Ok(TaskDueDate::Date(date));
+        }
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Ok(TaskDueDate::DateTime(date_time.with_timezone(&Utc)));
+        }
+        if value.contains('T')
+            && NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+        {
+            return Err(TaskDueDateParseError::MissingTimezone);
+        }
+
+        Err(TaskDueDateParseError::InvalidFormat)
+    }
+}
+
+impl fmt::Display for TaskDueDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskDueDate::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            TaskDueDate::DateTime(date_time) => {
+                write!(
+                    f,
+                    "{}",
+                    date_time.to_rfc3339_opts(SecondsFormat::Secs, true)
+                )
+            }
+        }
+    }
+}
+
+impl TaskDueDate {
+    pub fn from_deadline(deadline: DateTime<Utc>, all_day: bool) -> Self {
+        if all_day {
+            TaskDueDate::Date(deadline.date_naive() - Days::new(1))
+        } else {
+            TaskDueDate::DateTime(deadline)
+        }
+    }
+
+    pub fn is_all_day(&self) -> bool {
+        matches!(self, TaskDueDate::Date(_))
+    }
+
+    pub fn deadline(&self) -> DateTime<Utc> {
+        match self {
+            TaskDueDate::Date(date) => (*date + Days::new(1)).and_time(NaiveTime::MIN).and_utc(),
+            TaskDueDate::DateTime(date_time) => *date_time,
+        }
+    }
+
+    pub fn is_passed(&self, now: DateTime<Utc>) -> bool {
+        now >= self.deadline()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn try_from_when_valid_date_then_returns_instance() {
+        let due_date = TaskDueDate::try_from("2026-10-18").unwrap();
+        assert_eq!(
+            due_date,
+            TaskDueDate::Date(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+        );
+        assert_eq!(due_date.to_string(), "2026-10-18");
+    }
+
+    #[test]
+    fn try_from_when_valid_date_time_then_returns_normalized_instance() {
+        let due_date = TaskDueDate::try_from("2026-10-18T09:30:00+09:00").unwrap();
+        assert_eq!(due_date.to_string(), "2026-10-18T00:30:00Z");
+    }
+
+    #[test]
+    fn try_from_when_date_time_has_no_timezone_then_returns_error() {
+        let err = TaskDueDate::try_from("2026-10-18T09:30:00").unwrap_err();
+        assert_eq!(err, TaskDueDateParseError::MissingTimezone);
+    }
+
+    #[test]
+    fn try_from_when_invalid_value_then_returns_error() {
+        let err = TaskDueDate::try_from("2026-02-30").unwrap_err();
+        assert_eq!(err, TaskDueDateParseError::InvalidFormat);
+
+        let err = TaskDueDate::try_from("tomorrow").unwrap_err();
+        assert_eq!(err, TaskDueDateParseError::InvalidFormat);
+    }
+
+    #[test]
+    fn deadline_when_date_then_returns_end_of_day() {
+        let due_date = TaskDueDate::try_from("2026-10-18").unwrap();
+        assert_eq!(due_date.deadline(), utc("2026-10-19T00:00:00Z"));
+        assert_eq!(
+            TaskDueDate::from_deadline(due_date.deadline(), true),
+            due_date
+        );
+    }
+
+    #[test]
+    fn is_passed_when_deadline_reached_then_returns_true() {
+        let due_date = TaskDueDate::try_from("2026-10-18").unwrap();
+        assert!(!due_date.is_passed(utc("2026-10-18T23:59:59Z")));
+        assert!(due_date.is_passed(utc("2026-10-19T00:00:00Z")));
+
+        let due_date = TaskDueDate::try_from("2026-10-18T12:00:00Z").unwrap();
+        assert!(!due_date.is_passed(utc("2026-10-18T11:59:59Z")));
+        assert!(due_date.is_passed(utc("2026-10-18T12:00:00Z")));
+    }
+}
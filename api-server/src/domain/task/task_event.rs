@@ -0,0 +1,288 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_id::TaskId;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+const TASK_EVENT_FIELDS: [&str; 9] = [
+    "title",
+    "description",
+    "status",
+    "priority",
+    "due_at",
+    "labels",
+    "parent_id",
+    "blocked_by",
+    "recurrence",
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskEventKindParseError {
+    InvalidKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEventKind {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+    Archived,
+    Purged,
+}
+
+impl TryFrom<&str> for TaskEventKind {
+    type Error = TaskEventKindParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "created" => Ok(TaskEventKind::Created),
+            "updated" => Ok(TaskEventKind::Updated),
+            "deleted" => Ok(TaskEventKind::Deleted),
+            "restored" => Ok(TaskEventKind::Restored),
+            "archived" => Ok(TaskEventKind::Archived),
+            "purged" => Ok(TaskEventKind::Purged),
+            _ => Err(TaskEventKindParseError::InvalidKind),
+        }
+    }
+}
+
+impl fmt::Display for TaskEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskEventKind::Created => write!(f, "created"),
+            TaskEventKind::Updated => write!(f, "updated"),
+            TaskEventKind::Deleted => write!(f, "deleted"),
+            TaskEventKind::Restored => write!(f, "restored"),
+            TaskEventKind::Archived => write!(f, "archived"),
+            TaskEventKind::Purged => write!(f, "purged"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskEvent {
+    pub task_id: TaskId,
+    pub kind: TaskEventKind,
+    pub changes: Vec<TaskFieldChange>,
+    pub actor: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEvent {
+    pub fn created(task: &Task, actor: Option<&str>, occurred_at: DateTime<Utc>) -> Self {
+        Self::new(
+            task,
+            TaskEventKind::Created,
+            None,
+            Some(task),
+            actor,
+            occurred_at,
+        )
+    }
+
+    pub fn updated(
+        before: &Task,
+        after: &Task,
+        actor: Option<&str>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(
+            after,
+            TaskEventKind::Updated,
+            Some(before),
+            Some(after),
+            actor,
+            occurred_at,
+        )
+    }
+
+    pub fn deleted(task: &Task, actor: Option<&str>, occurred_at: DateTime<Utc>) -> Self {
+        Self::new(
+            task,
+            TaskEventKind::Deleted,
+            Some(task),
+            None,
+            actor,
+            occurred_at,
+        )
+    }
+
+    pub fn restored(task: &Task, actor: Option<&str>, occurred_at: DateTime<Utc>) -> Self {
+        Self::new(
+            task,
+            TaskEventKind::Restored,
+            None,
+            Some(task),
+            actor,
+            occurred_at,
+        )
+    }
+
+    pub fn archived(task: &Task, actor: Option<&str>, occurred_at: DateTime<Utc>) -> Self {
+        Self::new(
+            task,
+            TaskEventKind::Archived,
+            None,
+            None,
+            actor,
+            occurred_at,
+        )
+    }
+
+    pub fn purged(task: &Task, occurred_at: DateTime<Utc>) -> Self {
+        Self::new(task, TaskEventKind::Purged, None, None, None, occurred_at)
+    }
+
+    fn new(
+        task: &Task,
+        kind: TaskEventKind,
+        before: Option<&Task>,
+        after: Option<&Task>,
+        actor: Option<&str>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let before = before.map(fields).unwrap_or_default();
+        let after = after.map(fields).unwrap_or_default();
+        let changes = TASK_EVENT_FIELDS
+            .into_iter()
+            .filter_map(|field| {
+                let before = value_of(&before, field);
+                let after = value_of(&after, field);
+                (before != after).then(|| TaskFieldChange {
+                    field: String::from(field),
+                    before,
+                    after,
+                })
+            })
+            .collect();
+
+        Self {
+            task_id: task.id.clone(),
+            kind,
+            changes,
+            actor: actor.map(String::from),
+            occurred_at,
+        }
+    }
+}
+
+fn fields(task: &Task) -> Vec<(&'static str, Option<String>)> {
+    let join = |values: Vec<String>| (!values.is_empty()).then(|| values.join(","));
+
+    vec![
+        ("title", Some(task.title.to_string())),
+        ("description", Some(task.description.to_string())),
+        ("status", Some(task.status.to_int().to_string())),
+        ("priority", Some(task.priority.to_int().to_string())),
+        (
+            "due_at",
+            task.due_at.as_ref().map(|due_at| due_at.to_string()),
+        ),
+        (
+            "labels",
+            join(task.labels.iter().map(|label| label.to_string()).collect()),
+        ),
+        (
+            "parent_id",
+            task.parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.to_string()),
+        ),
+        (
+            "blocked_by",
+            join(task.blocked_by.iter().map(|id| id.to_string()).collect()),
+        ),
+        (
+            "recurrence",
+            task.recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+        ),
+    ]
+}
+
+fn value_of(fields: &[(&'static str, Option<String>)], field: &str) -> Option<String> {
+    fields
+        .iter()
+        .find(|(name, _)| *name == field)
+        .and_then(|(_, value)| value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_description::TaskDescription;
+    use crate::domain::task::task_priority::TaskPriority;
+    use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+    use crate::domain::task::task_title::TaskTitle;
+
+    fn task() -> Task {
+        Task::new(
+            TaskId::new(),
+            TaskTitle::try_from("Task Title").unwrap(),
+            TaskDescription::try_from("Task Description").unwrap(),
+            TaskStatus::new(1, TaskStatusCategory::Todo),
+            DateTime::UNIX_EPOCH,
+        )
+    }
+
+    fn change(field: &str, before: Option<&str>, after: Option<&str>) -> TaskFieldChange {
+        TaskFieldChange {
+            field: String::from(field),
+            before: before.map(String::from),
+            after: after.map(String::from),
+        }
+    }
+
+    #[test]
+    fn created_when_called_then_records_all_set_fields() {
+        let event = TaskEvent::created(&task(), Some("alice"), DateTime::UNIX_EPOCH);
+        assert_eq!(event.kind, TaskEventKind::Created);
+        assert_eq!(event.actor, Some(String::from("alice")));
+        assert_eq!(
+            event.changes,
+            vec![
+                change("title", None, Some("Task Title")),
+                change("description", None, Some("Task Description")),
+                change("status", None, Some("1")),
+                change("priority", None, Some("2")),
+            ]
+        );
+    }
+
+    #[test]
+    fn updated_when_fields_changed_then_records_only_diffs() {
+        let before = task();
+        let after = before
+            .clone()
+            .change_title(TaskTitle::try_from("New Title").unwrap())
+            .change_priority(TaskPriority::try_from(4).unwrap());
+        let event = TaskEvent::updated(&before, &after, None, DateTime::UNIX_EPOCH);
+        assert_eq!(event.kind, TaskEventKind::Updated);
+        assert_eq!(
+            event.changes,
+            vec![
+                change("title", Some("Task Title"), Some("New Title")),
+                change("priority", Some("2"), Some("4")),
+            ]
+        );
+
+        let event = TaskEvent::updated(&before, &before, None, DateTime::UNIX_EPOCH);
+        assert!(event.changes.is_empty());
+    }
+
+    #[test]
+    fn deleted_when_called_then_records_previous_values() {
+        let event = TaskEvent::deleted(&task(), None, DateTime::UNIX_EPOCH);
+        assert_eq!(event.kind, TaskEventKind::Deleted);
+        assert_eq!(event.changes[0], change("title", Some("Task Title"), None));
+        assert_eq!(event.changes.len(), 4);
+    }
+}
@@ -0,0 +1,8 @@
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::TaskRepositoryError;
+
+pub trait TaskEventRepository {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError>;
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError>;
+}
@@ -0,0 +1,163 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_query::{TASK_QUERY_MAX_LIMIT, TaskQuery};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+
+pub const TASK_MAX_DEPTH: usize = 5;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskHierarchyError {
+    ParentNotFound,
+    CycleDetected,
+    MaxDepthExceeded,
+    RepositoryError,
+}
+
+pub fn children<T: TaskRepository>(
+    repository: &T,
+    id: &TaskId,
+) -> Result<Vec<Task>, TaskRepositoryError> {
+    let mut query = TaskQuery {
+        parent: Some(Some(id.clone())),
+        include_archived: true,
+        limit: TASK_QUERY_MAX_LIMIT,
+        ..TaskQuery::default()
+    };
+
+    let mut children = Vec::new();
+    loop {
+        let page = repository.search(&query)?;
+        let next_offset = query.next_offset(page.total);
+        children.extend(page.tasks);
+        match next_offset {
+            Some(offset) => query.offset = offset,
+            None => return Ok(children),
+        }
+    }
+}
+
+pub fn validate_parent<T: TaskRepository>(
+    repository: &T,
+    id: &TaskId,
+    parent_id: &TaskId,
+) -> Result<(), TaskHierarchyError> {
+    let mut depth = 0;
+    let mut ancestor_id = Some(parent_id.clone());
+    while let Some(current_id) = ancestor_id {
+        if &current_id == id {
+            return Err(TaskHierarchyError::CycleDetected);
+        }
+        if depth == TASK_MAX_DEPTH {
+            return Err(TaskHierarchyError::MaxDepthExceeded);
+        }
+
+        let ancestor = match repository.get_by_id(&current_id) {
+            Ok(ancestor) if depth > 0 || !ancestor.is_trashed() => ancestor,
+            Ok(_) => return Err(TaskHierarchyError::ParentNotFound),
+            Err(TaskRepositoryError::NotFound) if depth == 0 => {
+                return Err(TaskHierarchyError::ParentNotFound);
+            }
+            Err(_) => return Err(TaskHierarchyError::RepositoryError),
+        };
+        depth += 1;
+        ancestor_id = ancestor.parent_id;
+    }
+
+    let height = match subtree_height(repository, id) {
+        Ok(height) => height,
+        Err(_) => return Err(TaskHierarchyError::RepositoryError),
+    };
+    if depth + height > TASK_MAX_DEPTH {
+        return Err(TaskHierarchyError::MaxDepthExceeded);
+    }
+
+    Ok(())
+}
+
+fn subtree_height<T: TaskRepository>(
+    repository: &T,
+    id: &TaskId,
+) -> Result<usize, TaskRepositoryError> {
+    let mut height = 1;
+    for child in children(repository, id)? {
+        height = height.max(1 + subtree_height(repository, &child.id)?);
+    }
+
+    Ok(height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn chain(repository: &TaskInMemoryRepository, ids: &[TaskId]) {
+        for pair in ids.windows(2) {
+            let task = repository.get_by_id(&pair[1]).unwrap();
+            repository
+                .update(task.change_parent(Some(pair[0].clone())))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn children_when_parent_has_children_then_returns_them() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        chain(&repository, &ids[0..2]);
+
+        let children = children(&repository, &ids[0]).unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].id, ids[1]);
+    }
+
+    #[test]
+    fn children_when_child_is_archived_then_returns_it() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        chain(&repository, &ids[0..2]);
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(task.change_archived_at(Some(chrono::Utc::now())))
+            .unwrap();
+
+        let children = children(&repository, &ids[0]).unwrap();
+        assert_eq!(children.len(), 1);
+        assert!(children[0].is_archived());
+    }
+
+    #[test]
+    fn validate_parent_when_parent_is_descendant_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        chain(&repository, &ids);
+
+        let err = validate_parent(&repository, &ids[0], &ids[2]).unwrap_err();
+        assert_eq!(err, TaskHierarchyError::CycleDetected);
+
+        let err = validate_parent(&repository, &ids[0], &ids[0]).unwrap_err();
+        assert_eq!(err, TaskHierarchyError::CycleDetected);
+    }
+
+    #[test]
+    fn validate_parent_when_parent_not_found_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let err = validate_parent(&repository, &ids[0], &TaskId::new()).unwrap_err();
+        assert_eq!(err, TaskHierarchyError::ParentNotFound);
+    }
+
+    #[test]
+    fn validate_parent_when_tree_becomes_too_deep_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let mut ids = repository.register_test_data();
+        ids.extend(repository.register_test_data());
+        chain(&repository, &ids[0..4]);
+        chain(&repository, &ids[4..6]);
+
+        assert!(validate_parent(&repository, &ids[4], &ids[2]).is_ok());
+        let err = validate_parent(&repository, &ids[4], &ids[3]).unwrap_err();
+        assert_eq!(err, TaskHierarchyError::MaxDepthExceeded);
+    }
+}
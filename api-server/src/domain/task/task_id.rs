@@ -1,3 +1,4 @@
+use std::fmt;
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -34,8 +35,14 @@ impl TaskId {
         Self { value }
     }
 
-    pub fn to_string(&self) -> String {
-        self.value.to_string()
+    pub fn to_uuid(&self) -> Uuid {
+        self.value
+    }
+}
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
     }
 }
 
@@ -0,0 +1,65 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum PriorityParseError {
+    InvalidPriority,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+pub enum TaskPriority {
+    Low = 1,
+    #[default]
+    Normal = 2,
+    High = 3,
+    Urgent = 4,
+}
+
+impl TaskPriority {
+    pub fn to_int(&self) -> i32 {
+        match self {
+            TaskPriority::Low => 1,
+            TaskPriority::Normal => 2,
+            TaskPriority::High => 3,
+            TaskPriority::Urgent => 4,
+        }
+    }
+}
+
+impl TryFrom<i32> for TaskPriority {
+    type Error = PriorityParseError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(TaskPriority::Low),
+            2 => Ok(TaskPriority::Normal),
+            3 => Ok(TaskPriority::High),
+            4 => Ok(TaskPriority::Urgent),
+            _ => Err(PriorityParseError::InvalidPriority),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_when_valid_value_then_returns_instance() {
+        assert_eq!(TaskPriority::try_from(1).unwrap(), TaskPriority::Low);
+        assert_eq!(TaskPriority::try_from(2).unwrap(), TaskPriority::Normal);
+        assert_eq!(TaskPriority::try_from(3).unwrap(), TaskPriority::High);
+        assert_eq!(TaskPriority::try_from(4).unwrap(), TaskPriority::Urgent);
+    }
+
+    #[test]
+    fn try_from_when_invalid_value_then_returns_error() {
+        let err = TaskPriority::try_from(0).unwrap_err();
+        assert_eq!(err, PriorityParseError::InvalidPriority);
+    }
+
+    #[test]
+    fn cmp_when_compared_then_orders_by_urgency() {
+        assert!(TaskPriority::Low < TaskPriority::Normal);
+        assert!(TaskPriority::Normal < TaskPriority::High);
+        assert!(TaskPriority::High < TaskPriority::Urgent);
+        assert_eq!(TaskPriority::default(), TaskPriority::Normal);
+    }
+}
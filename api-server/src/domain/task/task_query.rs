@@ -0,0 +1,367 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_cursor::TaskCursor;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_status::TaskStatus;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::fmt;
+
+pub const TASK_QUERY_DEFAULT_LIMIT: usize = 50;
+pub const TASK_QUERY_MAX_LIMIT: usize = 100;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskQueryParseError {
+    UnknownSortKey,
+    UnknownSortOrder,
+    LimitOutOfRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskSortKey {
+    Id,
+    Title,
+    Status,
+    Priority,
+    DueAt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskTrashFilter {
+    #[default]
+    Exclude,
+    Only,
+    Include,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskQuery {
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+    pub label: Option<LabelId>,
+    pub parent: Option<Option<TaskId>>,
+    pub due_before: Option<DateTime<Utc>>,
+    pub overdue_at: Option<DateTime<Utc>>,
+    pub trash: TaskTrashFilter,
+    pub deleted_before: Option<DateTime<Utc>>,
+    pub include_archived: bool,
+    pub completed_before: Option<DateTime<Utc>>,
+    pub sort: TaskSortKey,
+    pub order: SortOrder,
+    pub limit: usize,
+    pub offset: usize,
+    pub after: Option<TaskCursor>,
+}
+
+pub struct TaskPage {
+    pub tasks: Vec<Task>,
+    pub total: usize,
+}
+
+impl TryFrom<&str> for TaskSortKey {
+    type Error = TaskQueryParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "id" => Ok(TaskSortKey::Id),
+            "title" => Ok(TaskSortKey::Title),
+            "status" => Ok(TaskSortKey::Status),
+            "priority" => Ok(TaskSortKey::Priority),
+            "due_at" => Ok(TaskSortKey::DueAt),
+            _ => Err(TaskQueryParseError::UnknownSortKey),
+        }
+    }
+}
+
+impl TryFrom<&str> for SortOrder {
+    type Error = TaskQueryParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(TaskQueryParseError::UnknownSortOrder),
+        }
+    }
+}
+
+impl fmt::Display for TaskSortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskSortKey::Id => write!(f, "id"),
+            TaskSortKey::Title => write!(f, "title"),
+            TaskSortKey::Status => write!(f, "status"),
+            TaskSortKey::Priority => write!(f, "priority"),
+            TaskSortKey::DueAt => write!(f, "due_at"),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl Default for TaskQuery {
+    fn default() -> Self {
+        Self {
+            status: None,
+            priority: None,
+            label: None,
+            parent: None,
+            due_before: None,
+            overdue_at: None,
+            trash: TaskTrashFilter::default(),
+            deleted_before: None,
+            include_archived: false,
+            completed_before: None,
+            sort: TaskSortKey::Id,
+            order: SortOrder::Asc,
+            limit: TASK_QUERY_DEFAULT_LIMIT,
+            offset: 0,
+            after: None,
+        }
+    }
+}
+
+impl TaskQuery {
+    pub fn with_limit(self, limit: usize) -> Result<Self, TaskQueryParseError> {
+        if limit == 0 || limit > TASK_QUERY_MAX_LIMIT {
+            return Err(TaskQueryParseError::LimitOutOfRange);
+        }
+
+        Ok(Self { limit, ..self })
+    }
+
+    pub fn next_offset(&self, total: usize) -> Option<usize> {
+        let next = self.offset + self.limit;
+        if next < total { Some(next) } else { None }
+    }
+
+    pub fn matches(&self, task: &Task) -> bool {
+        match self.trash {
+            TaskTrashFilter::Exclude if task.is_trashed() => return false,
+            TaskTrashFilter::Only if !task.is_trashed() => return false,
+            _ => {}
+        }
+        if let Some(deleted_before) = self.deleted_before {
+            match task.deleted_at {
+                Some(deleted_at) if deleted_at <= deleted_before => {}
+                _ => return false,
+            }
+        }
+        if !self.include_archived && task.is_archived() {
+            return false;
+        }
+        if let Some(completed_before) = self.completed_before {
+            match task.completed_at {
+                Some(completed_at) if completed_at <= completed_before => {}
+                _ => return false,
+            }
+        }
+        if let Some(status) = &self.status
+            && task.status != *status
+        {
+            return false;
+        }
+        if let Some(priority) = &self.priority
+            && task.priority != *priority
+        {
+            return false;
+        }
+        if let Some(label) = &self.label
+            && !task.labels.contains(label)
+        {
+            return false;
+        }
+        if let Some(parent) = &self.parent
+            && task.parent_id != *parent
+        {
+            return false;
+        }
+        if let Some(due_before) = self.due_before {
+            match &task.due_at {
+                Some(due_at) if due_at.deadline() <= due_before => {}
+                _ => return false,
+            }
+        }
+        if let Some(overdue_at) = self.overdue_at
+            && !task.is_overdue(overdue_at)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn compare(&self, a: &Task, b: &Task) -> Ordering {
+        compare_positions(
+            &TaskCursor::new(a, self.sort, self.order),
+            &TaskCursor::new(b, self.sort, self.order),
+        )
+    }
+
+    pub fn is_after_cursor(&self, task: &Task) -> bool {
+        match &self.after {
+            Some(after) => {
+                compare_positions(&TaskCursor::new(task, after.sort, after.order), after)
+                    == Ordering::Greater
+            }
+            None => true,
+        }
+    }
+}
+
+fn compare_positions(a: &TaskCursor, b: &TaskCursor) -> Ordering {
+    let by_id = || directed(a.id.to_uuid().cmp(&b.id.to_uuid()), a.order);
+
+    match a.sort {
+        TaskSortKey::Id => by_id(),
+        TaskSortKey::Title => directed(a.title.cmp(&b.title), a.order).then_with(by_id),
+        TaskSortKey::Status => directed(a.status.cmp(&b.status), a.order).then_with(by_id),
+        TaskSortKey::Priority => directed(a.priority.cmp(&b.priority), a.order)
+            .then_with(|| compare_due_at(a.due_at, b.due_at, SortOrder::Asc))
+            .then_with(|| a.id.to_uuid().cmp(&b.id.to_uuid())),
+        TaskSortKey::DueAt => compare_due_at(a.due_at, b.due_at, a.order).then_with(by_id),
+    }
+}
+
+fn compare_due_at(
+    a: Option<DateTime<Utc>>,
+    b: Option<DateTime<Utc>>,
+    order: SortOrder,
+) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => directed(a.cmp(&b), order),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn directed(ordering: Ordering, order: SortOrder) -> Ordering {
+    match order {
+        SortOrder::Asc => ordering,
+        SortOrder::Desc => ordering.reverse(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_description::TaskDescription;
+    use crate::domain::task::task_status::TaskStatusCategory;
+    use crate::domain::task::task_title::TaskTitle;
+
+    #[test]
+    fn try_from_when_valid_sort_key_then_returns_instance() {
+        assert_eq!(TaskSortKey::try_from("title").unwrap(), TaskSortKey::Title);
+        assert_eq!(
+            TaskSortKey::try_from("status").unwrap(),
+            TaskSortKey::Status
+        );
+        assert_eq!(
+            TaskSortKey::try_from("priority").unwrap(),
+            TaskSortKey::Priority
+        );
+        assert_eq!(TaskSortKey::try_from("due_at").unwrap(), TaskSortKey::DueAt);
+    }
+
+    #[test]
+    fn try_from_when_invalid_sort_key_then_returns_error() {
+        let err = TaskSortKey::try_from("description").unwrap_err();
+        assert_eq!(err, TaskQueryParseError::UnknownSortKey);
+    }
+
+    #[test]
+    fn try_from_when_invalid_sort_order_then_returns_error() {
+        let err = SortOrder::try_from("up").unwrap_err();
+        assert_eq!(err, TaskQueryParseError::UnknownSortOrder);
+    }
+
+    #[test]
+    fn with_limit_when_out_of_range_then_returns_error() {
+        let err = TaskQuery::default().with_limit(0).unwrap_err();
+        assert_eq!(err, TaskQueryParseError::LimitOutOfRange);
+
+        let err = TaskQuery::default()
+            .with_limit(TASK_QUERY_MAX_LIMIT + 1)
+            .unwrap_err();
+        assert_eq!(err, TaskQueryParseError::LimitOutOfRange);
+    }
+
+    #[test]
+    fn next_offset_when_last_page_then_returns_none() {
+        let query = TaskQuery {
+            offset: 2,
+            ..TaskQuery::default()
+        };
+        assert_eq!(query.next_offset(3), None);
+    }
+
+    #[test]
+    fn matches_when_task_is_trashed_then_only_trash_queries_match() {
+        let task = Task::new(
+            TaskId::new(),
+            TaskTitle::try_from("Task Title").unwrap(),
+            TaskDescription::try_from("Task Description").unwrap(),
+            TaskStatus::new(1, TaskStatusCategory::Todo),
+            DateTime::UNIX_EPOCH,
+        )
+        .change_deleted_at(Some(DateTime::UNIX_EPOCH));
+
+        assert!(!TaskQuery::default().matches(&task));
+        for trash in [TaskTrashFilter::Only, TaskTrashFilter::Include] {
+            let query = TaskQuery {
+                trash,
+                ..TaskQuery::default()
+            };
+            assert!(query.matches(&task));
+        }
+
+        let query = TaskQuery {
+            trash: TaskTrashFilter::Only,
+            deleted_before: Some(DateTime::UNIX_EPOCH - chrono::Duration::days(1)),
+            ..TaskQuery::default()
+        };
+        assert!(!query.matches(&task));
+    }
+
+    #[test]
+    fn matches_when_task_is_archived_then_only_archive_aware_queries_match() {
+        let task = Task::new(
+            TaskId::new(),
+            TaskTitle::try_from("Task Title").unwrap(),
+            TaskDescription::try_from("Task Description").unwrap(),
+            TaskStatus::new(5, TaskStatusCategory::Done),
+            DateTime::UNIX_EPOCH,
+        )
+        .change_completed_at(Some(DateTime::UNIX_EPOCH))
+        .change_archived_at(Some(DateTime::UNIX_EPOCH));
+
+        assert!(!TaskQuery::default().matches(&task));
+        let query = TaskQuery {
+            include_archived: true,
+            ..TaskQuery::default()
+        };
+        assert!(query.matches(&task));
+
+        let query = TaskQuery {
+            include_archived: true,
+            completed_before: Some(DateTime::UNIX_EPOCH - chrono::Duration::days(1)),
+            ..TaskQuery::default()
+        };
+        assert!(!query.matches(&task));
+    }
+}
@@ -0,0 +1,517 @@
+use crate::domain::task::task_due_date::TaskDueDate;
+use chrono::{
+    DateTime, Datelike, Days, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+use std::fmt;
+
+const TASK_RECURRENCE_MAX_INTERVAL: u32 = 999;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskRecurrenceParseError {
+    InvalidFormat,
+    MissingFrequency,
+    UnknownFrequency,
+    UnsupportedPart,
+    InvalidInterval,
+    InvalidByDay,
+    InvalidByMonthDay,
+    InvalidCount,
+    InvalidUntil,
+    CountWithUntil,
+    UnknownTimeZone,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskRecurrence {
+    frequency: RecurrenceFrequency,
+    interval: u32,
+    by_day: Vec<Weekday>,
+    by_month_day: Option<i32>,
+    count: Option<u32>,
+    until: Option<TaskDueDate>,
+    time_zone: Option<Tz>,
+}
+
+impl TryFrom<&str> for TaskRecurrence {
+    type Error = TaskRecurrenceParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut recurrence = TaskRecurrence {
+            frequency: RecurrenceFrequency::Daily,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: None,
+            count: None,
+            until: None,
+            time_zone: None,
+        };
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = match part.split_once('=') {
+                Some((key, value)) if !value.is_empty() => (key.to_ascii_uppercase(), value),
+                _ => return Err(TaskRecurrenceParseError::InvalidFormat),
+            };
+            match key.as_str() {
+                "FREQ" => frequency = Some(parse_frequency(value)?),
+                "INTERVAL" => {
+                    recurrence.interval = match value.parse::<u32>() {
+                        Ok(interval) if (1..=TASK_RECURRENCE_MAX_INTERVAL).contains(&interval) => {
+                            interval
+                        }
+                        _ => return Err(TaskRecurrenceParseError::InvalidInterval),
+                    }
+                }
+                "BYDAY" => {
+                    let mut by_day = value
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    by_day.sort_by_key(|weekday| weekday.num_days_from_monday());
+                    by_day.dedup();
+                    recurrence.by_day = by_day;
+                }
+                "BYMONTHDAY" => {
+                    recurrence.by_month_day = match value.parse::<i32>() {
+                        Ok(day) if (1..=31).contains(&day) || (-31..=-1).contains(&day) => {
+                            Some(day)
+                        }
+                        _ => return Err(TaskRecurrenceParseError::InvalidByMonthDay),
+                    }
+                }
+                "COUNT" => {
+                    recurrence.count = match value.parse::<u32>() {
+                        Ok(count) if count > 0 => Some(count),
+                        _ => return Err(TaskRecurrenceParseError::InvalidCount),
+                    }
+                }
+                "UNTIL" => recurrence.until = Some(parse_until(value)?),
+                "TZID" => {
+                    recurrence.time_zone = match value.parse::<Tz>() {
+                        Ok(time_zone) => Some(time_zone),
+                        Err(_) => return Err(TaskRecurrenceParseError::UnknownTimeZone),
+                    }
+                }
+                _ => return Err(TaskRecurrenceParseError::UnsupportedPart),
+            }
+        }
+
+        recurrence.frequency = frequency.ok_or(TaskRecurrenceParseError::MissingFrequency)?;
+        if !recurrence.by_day.is_empty() && recurrence.frequency != RecurrenceFrequency::Weekly {
+            return Err(TaskRecurrenceParseError::InvalidByDay);
+        }
+        if recurrence.by_month_day.is_some() && recurrence.frequency != RecurrenceFrequency::Monthly
+        {
+            return Err(TaskRecurrenceParseError::InvalidByMonthDay);
+        }
+        if recurrence.count.is_some() && recurrence.until.is_some() {
+            return Err(TaskRecurrenceParseError::CountWithUntil);
+        }
+
+        Ok(recurrence)
+    }
+}
+
+impl fmt::Display for TaskRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            RecurrenceFrequency::Daily => "DAILY",
+            RecurrenceFrequency::Weekly => "WEEKLY",
+            RecurrenceFrequency::Monthly => "MONTHLY",
+            RecurrenceFrequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let by_day: Vec<&str> = self.by_day.iter().map(|day| weekday_code(*day)).collect();
+            write!(f, ";BYDAY={}", by_day.join(","))?;
+        }
+        if let Some(by_month_day) = self.by_month_day {
+            write!(f, ";BYMONTHDAY={}", by_month_day)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        match &self.until {
+            Some(TaskDueDate::Date(date)) => write!(f, ";UNTIL={}", date.format("%Y%m%d"))?,
+            Some(TaskDueDate::DateTime(date_time)) => {
+                write!(f, ";UNTIL={}", date_time.format("%Y%m%dT%H%M%SZ"))?
+            }
+            None => {}
+        }
+        if let Some(time_zone) = self.time_zone {
+            write!(f, ";TZID={}", time_zone.name())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaskRecurrence {
+    pub fn next_occurrence(&self, due_at: &TaskDueDate) -> Option<(TaskDueDate, TaskRecurrence)> {
+        if self.count == Some(1) {
+            return None;
+        }
+
+        let (next_due_at, anchor_day) = match due_at {
+            TaskDueDate::Date(date) => (TaskDueDate::Date(self.next_date(*date)), date.day()),
+            TaskDueDate::DateTime(date_time) => match self.time_zone {
+                Some(time_zone) => {
+                    let local = date_time.with_timezone(&time_zone).naive_local();
+                    let next = self.next_date(local.date()).and_time(local.time());
+                    (
+                        TaskDueDate::DateTime(resolve_local(&time_zone, next)),
+                        local.day(),
+                    )
+                }
+                None => {
+                    let next = self.next_date(date_time.date_naive());
+                    (
+                        TaskDueDate::DateTime(next.and_time(date_time.time()).and_utc()),
+                        date_time.day(),
+                    )
+                }
+            },
+        };
+        if let Some(until) = &self.until
+            && next_due_at.deadline() > until.deadline()
+        {
+            return None;
+        }
+
+        let mut next_recurrence = self.clone();
+        next_recurrence.count = self.count.map(|count| count - 1);
+        if self.frequency == RecurrenceFrequency::Monthly && self.by_month_day.is_none() {
+            next_recurrence.by_month_day = Some(anchor_day as i32);
+        }
+
+        Some((next_due_at, next_recurrence))
+    }
+
+    fn next_date(&self, date: NaiveDate) -> NaiveDate {
+        match self.frequency {
+            RecurrenceFrequency::Daily => date + Days::new(self.interval as u64),
+            RecurrenceFrequency::Weekly => {
+                let weekday = date.weekday().num_days_from_monday();
+                if let Some(next) = self
+                    .by_day
+                    .iter()
+                    .find(|day| day.num_days_from_monday() > weekday)
+                {
+                    return date + Days::new((next.num_days_from_monday() - weekday) as u64);
+                }
+
+                let week_start = date - Days::new(weekday as u64);
+                let first_day = self
+                    .by_day
+                    .first()
+                    .map(|day| day.num_days_from_monday())
+                    .unwrap_or(weekday);
+                week_start + Days::new(7 * self.interval as u64 + first_day as u64)
+            }
+            RecurrenceFrequency::Monthly => {
+                let months = date.year() * 12 + date.month0() as i32 + self.interval as i32;
+                let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+                let last_day = days_in_month(year, month);
+                let day = match self.by_month_day.unwrap_or(date.day() as i32) {
+                    day if day > 0 => (day as u32).min(last_day),
+                    day => (last_day as i32 + day + 1).max(1) as u32,
+                };
+                NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            }
+            RecurrenceFrequency::Yearly => {
+                let year = date.year() + self.interval as i32;
+                let day = date.day().min(days_in_month(year, date.month()));
+                NaiveDate::from_ymd_opt(year, date.month(), day).unwrap()
+            }
+        }
+    }
+}
+
+fn parse_frequency(value: &str) -> Result<RecurrenceFrequency, TaskRecurrenceParseError> {
+    match value.to_ascii_uppercase().as_str() {
+        "DAILY" => Ok(RecurrenceFrequency::Daily),
+        "WEEKLY" => Ok(RecurrenceFrequency::Weekly),
+        "MONTHLY" => Ok(RecurrenceFrequency::Monthly),
+        "YEARLY" => Ok(RecurrenceFrequency::Yearly),
+        _ => Err(TaskRecurrenceParseError::UnknownFrequency),
+    }
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, TaskRecurrenceParseError> {
+    match value.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(TaskRecurrenceParseError::InvalidByDay),
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_until(value: &str) -> Result<TaskDueDate, TaskRecurrenceParseError> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Ok(TaskDueDate::Date(date));
+    }
+    if let Some(value) = value.strip_suffix('Z')
+        && let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+    {
+        return Ok(TaskDueDate::DateTime(date_time.and_utc()));
+    }
+
+    Err(TaskRecurrenceParseError::InvalidUntil)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.day())
+        .unwrap()
+}
+
+fn resolve_local(time_zone: &Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    let resolved = match time_zone.from_local_datetime(&local) {
+        LocalResult::None => time_zone
+            .from_local_datetime(&(local + TimeDelta::hours(1)))
+            .earliest(),
+        result => result.earliest(),
+    };
+
+    resolved
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .unwrap_or_else(|| local.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(rule: &str, due_at: &str) -> Option<String> {
+        TaskRecurrence::try_from(rule)
+            .unwrap()
+            .next_occurrence(&TaskDueDate::try_from(due_at).unwrap())
+            .map(|(due_at, _)| due_at.to_string())
+    }
+
+    fn occurrences(rule: &str, due_at: &str, n: usize) -> Vec<String> {
+        let mut recurrence = TaskRecurrence::try_from(rule).unwrap();
+        let mut due_at = TaskDueDate::try_from(due_at).unwrap();
+        let mut result = Vec::new();
+        for _ in 0..n {
+            match recurrence.next_occurrence(&due_at) {
+                Some((next_due_at, next_recurrence)) => {
+                    result.push(next_due_at.to_string());
+                    due_at = next_due_at;
+                    recurrence = next_recurrence;
+                }
+                None => break,
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn try_from_when_valid_rule_then_returns_normalized_instance() {
+        let recurrence =
+            TaskRecurrence::try_from("RRULE:freq=weekly;INTERVAL=2;BYDAY=FR,MO,MO").unwrap();
+        assert_eq!(recurrence.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR");
+
+        let recurrence =
+            TaskRecurrence::try_from("FREQ=DAILY;UNTIL=20261231;TZID=Europe/Berlin").unwrap();
+        assert_eq!(
+            recurrence.to_string(),
+            "FREQ=DAILY;UNTIL=20261231;TZID=Europe/Berlin"
+        );
+    }
+
+    #[test]
+    fn try_from_when_invalid_rule_then_returns_error() {
+        let cases = [
+            ("", TaskRecurrenceParseError::MissingFrequency),
+            ("INTERVAL=2", TaskRecurrenceParseError::MissingFrequency),
+            ("FREQ=HOURLY", TaskRecurrenceParseError::UnknownFrequency),
+            (
+                "FREQ=DAILY;INTERVAL=0",
+                TaskRecurrenceParseError::InvalidInterval,
+            ),
+            (
+                "FREQ=DAILY;BYDAY=MO",
+                TaskRecurrenceParseError::InvalidByDay,
+            ),
+            (
+                "FREQ=WEEKLY;BYDAY=XX",
+                TaskRecurrenceParseError::InvalidByDay,
+            ),
+            (
+                "FREQ=MONTHLY;BYMONTHDAY=0",
+                TaskRecurrenceParseError::InvalidByMonthDay,
+            ),
+            (
+                "FREQ=WEEKLY;BYMONTHDAY=1",
+                TaskRecurrenceParseError::InvalidByMonthDay,
+            ),
+            ("FREQ=DAILY;COUNT=0", TaskRecurrenceParseError::InvalidCount),
+            (
+                "FREQ=DAILY;UNTIL=2026-12-31",
+                TaskRecurrenceParseError::InvalidUntil,
+            ),
+            (
+                "FREQ=DAILY;COUNT=2;UNTIL=20261231",
+                TaskRecurrenceParseError::CountWithUntil,
+            ),
+            (
+                "FREQ=DAILY;TZID=Mars/Olympus",
+                TaskRecurrenceParseError::UnknownTimeZone,
+            ),
+            (
+                "FREQ=DAILY;BYSETPOS=1",
+                TaskRecurrenceParseError::UnsupportedPart,
+            ),
+            ("FREQ", TaskRecurrenceParseError::InvalidFormat),
+        ];
+        for (rule, expected) in cases {
+            assert_eq!(
+                TaskRecurrence::try_from(rule).unwrap_err(),
+                expected,
+                "{}",
+                rule
+            );
+        }
+    }
+
+    #[test]
+    fn next_occurrence_when_daily_then_shifts_by_interval() {
+        assert_eq!(
+            next("FREQ=DAILY", "2026-12-31"),
+            Some(String::from("2027-01-01"))
+        );
+        assert_eq!(
+            next("FREQ=DAILY;INTERVAL=3", "2026-10-18T09:00:00Z"),
+            Some(String::from("2026-10-21T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_weekly_by_day_then_returns_next_listed_day() {
+        // 2026-10-19 is a Monday.
+        assert_eq!(
+            occurrences("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE", "2026-10-19", 3),
+            vec!["2026-10-21", "2026-11-02", "2026-11-04"]
+        );
+        assert_eq!(
+            next("FREQ=WEEKLY", "2026-10-22"),
+            Some(String::from("2026-10-29"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_monthly_from_month_end_then_clamps_without_drift() {
+        assert_eq!(
+            occurrences("FREQ=MONTHLY", "2026-01-31", 4),
+            vec!["2026-02-28", "2026-03-31", "2026-04-30", "2026-05-31"]
+        );
+        assert_eq!(
+            next("FREQ=MONTHLY", "2028-01-30"),
+            Some(String::from("2028-02-29"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_monthly_last_day_then_returns_last_day_of_month() {
+        assert_eq!(
+            occurrences("FREQ=MONTHLY;BYMONTHDAY=-1", "2026-11-30", 4),
+            vec!["2026-12-31", "2027-01-31", "2027-02-28", "2027-03-31"]
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_yearly_from_leap_day_then_clamps_to_february_end() {
+        assert_eq!(
+            next("FREQ=YEARLY", "2028-02-29"),
+            Some(String::from("2029-02-28"))
+        );
+        assert_eq!(
+            next("FREQ=YEARLY;INTERVAL=4", "2028-02-29"),
+            Some(String::from("2032-02-29"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_time_zone_given_then_keeps_local_time_across_dst() {
+        // Europe/Berlin switches to summer time on 2026-03-29 and back on 2026-10-25.
+        assert_eq!(
+            next("FREQ=DAILY;TZID=Europe/Berlin", "2026-03-28T08:00:00Z"),
+            Some(String::from("2026-03-29T07:00:00Z"))
+        );
+        assert_eq!(
+            next("FREQ=DAILY;TZID=Europe/Berlin", "2026-10-24T07:00:00Z"),
+            Some(String::from("2026-10-25T08:00:00Z"))
+        );
+        assert_eq!(
+            next("FREQ=DAILY", "2026-03-28T08:00:00Z"),
+            Some(String::from("2026-03-29T08:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_local_time_skipped_or_repeated_then_resolves_it() {
+        // 02:30 does not exist on 2026-03-29 in Berlin and occurs twice on 2026-10-25.
+        assert_eq!(
+            next("FREQ=DAILY;TZID=Europe/Berlin", "2026-03-28T01:30:00Z"),
+            Some(String::from("2026-03-29T01:30:00Z"))
+        );
+        assert_eq!(
+            next("FREQ=DAILY;TZID=Europe/Berlin", "2026-10-24T00:30:00Z"),
+            Some(String::from("2026-10-25T00:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn next_occurrence_when_count_or_until_reached_then_returns_none() {
+        assert_eq!(
+            occurrences("FREQ=DAILY;COUNT=3", "2026-10-18", 5),
+            vec!["2026-10-19", "2026-10-20"]
+        );
+        assert_eq!(
+            occurrences("FREQ=WEEKLY;UNTIL=20261101", "2026-10-18", 5),
+            vec!["2026-10-25", "2026-11-01"]
+        );
+        assert_eq!(
+            next("FREQ=DAILY;UNTIL=20261018T120000Z", "2026-10-18T09:00:00Z"),
+            None
+        );
+    }
+}
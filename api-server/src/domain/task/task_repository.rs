@@ -1,17 +1,56 @@
 use crate::domain::task::task::Task;
 use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use std::fmt;
 
 pub trait TaskRepository {
-    fn list(&self) -> Result<Vec<Task>, TaskRepositoryError>;
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError>;
     fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError>;
     fn register(&self, task: Task) -> Result<Task, TaskRepositoryError>;
     fn update(&self, task: Task) -> Result<Task, TaskRepositoryError>;
     fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError>;
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError>;
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError>;
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError>;
+}
+
+pub fn transaction<T: TaskRepository, R, E>(
+    repository: &T,
+    operation: impl FnOnce() -> Result<R, E>,
+) -> Result<Result<R, E>, TaskRepositoryError> {
+    repository.begin_transaction()?;
+    let result = operation();
+    let finished = match result {
+        Ok(_) => repository.commit_transaction(),
+        Err(_) => repository.rollback_transaction(),
+    };
+    if let Err(e) = finished {
+        let _ = repository.rollback_transaction();
+        return Err(e);
+    }
+
+    Ok(result)
 }
 
 #[derive(Debug)]
 pub enum TaskRepositoryError {
     AlreadyExists,
     NotFound,
+    Conflict,
+    TransactionState,
     DbError(String),
 }
+
+impl fmt::Display for TaskRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskRepositoryError::AlreadyExists => write!(f, "task already exists"),
+            TaskRepositoryError::NotFound => write!(f, "task not found"),
+            TaskRepositoryError::Conflict => write!(f, "task was modified concurrently"),
+            TaskRepositoryError::TransactionState => {
+                write!(f, "transaction was not in the expected state")
+            }
+            TaskRepositoryError::DbError(message) => write!(f, "db error: {}", message),
+        }
+    }
+}
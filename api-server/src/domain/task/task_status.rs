@@ -1,35 +1,62 @@
+use std::fmt;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum StatusParseError {
     InvalidStatus,
+    InvalidCategory,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TaskStatusCategory {
+    Todo,
+    InProgress,
+    Done,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum TaskStatus {
-    Todo = 1,
-    Doing = 2,
-    Done = 3,
+pub struct TaskStatus {
+    id: i32,
+    category: TaskStatusCategory,
 }
 
-impl TaskStatus {
-    pub fn to_int(&self) -> i32 {
+impl TryFrom<&str> for TaskStatusCategory {
+    type Error = StatusParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "todo" => Ok(TaskStatusCategory::Todo),
+            "in_progress" => Ok(TaskStatusCategory::InProgress),
+            "done" => Ok(TaskStatusCategory::Done),
+            _ => Err(StatusParseError::InvalidCategory),
+        }
+    }
+}
+
+impl fmt::Display for TaskStatusCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TaskStatus::Todo => 1,
-            TaskStatus::Doing => 2,
-            TaskStatus::Done => 3,
+            TaskStatusCategory::Todo => write!(f, "todo"),
+            TaskStatusCategory::InProgress => write!(f, "in_progress"),
+            TaskStatusCategory::Done => write!(f, "done"),
         }
     }
 }
 
-impl TryFrom<i32> for TaskStatus {
-    type Error = StatusParseError;
+impl TaskStatus {
+    pub fn new(id: i32, category: TaskStatusCategory) -> Self {
+        Self { id, category }
+    }
 
-    fn try_from(value: i32) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(TaskStatus::Todo),
-            2 => Ok(TaskStatus::Doing),
-            3 => Ok(TaskStatus::Done),
-            _ => Err(StatusParseError::InvalidStatus),
-        }
+    pub fn to_int(&self) -> i32 {
+        self.id
+    }
+
+    pub fn category(&self) -> TaskStatusCategory {
+        self.category
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.category == TaskStatusCategory::Done
     }
 }
 
@@ -38,20 +65,27 @@ mod tests {
     use super::*;
 
     #[test]
-    fn try_from_when_valid_value_then_returns_instance() {
-        let task_status = TaskStatus::try_from(1).unwrap();
-        assert_eq!(task_status, TaskStatus::Todo);
+    fn try_from_when_valid_category_then_returns_instance() {
+        let category = TaskStatusCategory::try_from("todo").unwrap();
+        assert_eq!(category, TaskStatusCategory::Todo);
 
-        let task_status = TaskStatus::try_from(2).unwrap();
-        assert_eq!(task_status, TaskStatus::Doing);
+        let category = TaskStatusCategory::try_from("in_progress").unwrap();
+        assert_eq!(category, TaskStatusCategory::InProgress);
 
-        let task_status = TaskStatus::try_from(3).unwrap();
-        assert_eq!(task_status, TaskStatus::Done);
+        let category = TaskStatusCategory::try_from("done").unwrap();
+        assert_eq!(category, TaskStatusCategory::Done);
+        assert_eq!(category.to_string(), "done");
+    }
+
+    #[test]
+    fn try_from_when_invalid_category_then_returns_error() {
+        let err = TaskStatusCategory::try_from("blocked").unwrap_err();
+        assert_eq!(err, StatusParseError::InvalidCategory);
     }
 
     #[test]
-    fn try_from_when_invalid_value_then_returns_error() {
-        let err = TaskStatus::try_from(10).unwrap_err();
-        assert_eq!(err, StatusParseError::InvalidStatus);
+    fn is_done_when_category_is_done_then_returns_true() {
+        assert!(TaskStatus::new(5, TaskStatusCategory::Done).is_done());
+        assert!(!TaskStatus::new(3, TaskStatusCategory::InProgress).is_done());
     }
 }
@@ -1,12 +1,14 @@
+use std::fmt;
+
 const TASK_TITLE_MAX_LENGTH: usize = 64;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum TaskTitleParseError {
     Empty,
-    TooLong,
+    TooLong { limit: usize, actual: usize },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TaskTitle {
     value: String,
 }
@@ -20,8 +22,12 @@ impl TryFrom<&str> for TaskTitle {
         if value.is_empty() {
             return Err(TaskTitleParseError::Empty);
         }
-        if value.len() > TASK_TITLE_MAX_LENGTH {
-            return Err(TaskTitleParseError::TooLong);
+        let length = value.chars().count();
+        if length > TASK_TITLE_MAX_LENGTH {
+            return Err(TaskTitleParseError::TooLong {
+                limit: TASK_TITLE_MAX_LENGTH,
+                actual: length,
+            });
         }
 
         Ok(TaskTitle {
@@ -30,9 +36,9 @@ impl TryFrom<&str> for TaskTitle {
     }
 }
 
-impl TaskTitle {
-    pub fn to_string(&self) -> String {
-        self.value.to_string()
+impl fmt::Display for TaskTitle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
     }
 }
 
@@ -61,6 +67,28 @@ mod tests {
     fn try_from_when_value_is_too_long_then_returns_error() {
         let value = String::from("A").repeat(TASK_TITLE_MAX_LENGTH + 1);
         let err = TaskTitle::try_from(value.as_str()).unwrap_err();
-        assert_eq!(err, TaskTitleParseError::TooLong);
+        assert_eq!(
+            err,
+            TaskTitleParseError::TooLong {
+                limit: TASK_TITLE_MAX_LENGTH,
+                actual: TASK_TITLE_MAX_LENGTH + 1,
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_when_value_has_multibyte_characters_then_counts_characters() {
+        let value = String::from("é").repeat(TASK_TITLE_MAX_LENGTH);
+        assert!(TaskTitle::try_from(value.as_str()).is_ok());
+
+        let value = String::from("é").repeat(TASK_TITLE_MAX_LENGTH + 1);
+        let err = TaskTitle::try_from(value.as_str()).unwrap_err();
+        assert_eq!(
+            err,
+            TaskTitleParseError::TooLong {
+                limit: TASK_TITLE_MAX_LENGTH,
+                actual: TASK_TITLE_MAX_LENGTH + 1,
+            }
+        );
     }
 }
@@ -0,0 +1,216 @@
+use crate::domain::task::task_status::{StatusParseError, TaskStatus, TaskStatusCategory};
+use std::collections::HashSet;
+
+const TASK_STATUS_NAME_MAX_LENGTH: usize = 32;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskWorkflowError {
+    NoStatuses,
+    DuplicateStatus,
+    InvalidStatusName,
+    UnknownTransitionStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskStatusDefinition {
+    pub status: TaskStatus,
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskWorkflow {
+    statuses: Vec<TaskStatusDefinition>,
+    transitions: Vec<(i32, i32)>,
+}
+
+impl TaskWorkflow {
+    pub fn new(
+        statuses: Vec<TaskStatusDefinition>,
+        transitions: Vec<(i32, i32)>,
+    ) -> Result<Self, TaskWorkflowError> {
+        if statuses.is_empty() {
+            return Err(TaskWorkflowError::NoStatuses);
+        }
+
+        let mut ids = HashSet::new();
+        for definition in &statuses {
+            let name = definition.name.trim();
+            if name.is_empty() || name.len() > TASK_STATUS_NAME_MAX_LENGTH {
+                return Err(TaskWorkflowError::InvalidStatusName);
+            }
+            if !ids.insert(definition.status.to_int()) {
+                return Err(TaskWorkflowError::DuplicateStatus);
+            }
+        }
+        if transitions
+            .iter()
+            .any(|(from, to)| !ids.contains(from) || !ids.contains(to))
+        {
+            return Err(TaskWorkflowError::UnknownTransitionStatus);
+        }
+
+        Ok(Self {
+            statuses,
+            transitions,
+        })
+    }
+
+    pub fn statuses(&self) -> &[TaskStatusDefinition] {
+        &self.statuses
+    }
+
+    pub fn transitions(&self) -> &[(i32, i32)] {
+        &self.transitions
+    }
+
+    pub fn status(&self, id: i32) -> Result<TaskStatus, StatusParseError> {
+        self.statuses
+            .iter()
+            .find(|definition| definition.status.to_int() == id)
+            .map(|definition| definition.status.clone())
+            .ok_or(StatusParseError::InvalidStatus)
+    }
+
+    pub fn done_statuses(&self) -> Vec<TaskStatus> {
+        self.statuses
+            .iter()
+            .filter(|definition| definition.status.is_done())
+            .map(|definition| definition.status.clone())
+            .collect()
+    }
+
+    pub fn initial_status(&self) -> TaskStatus {
+        self.statuses
+            .iter()
+            .find(|definition| definition.status.category() == TaskStatusCategory::Todo)
+            .unwrap_or(&self.statuses[0])
+            .status
+            .clone()
+    }
+
+    pub fn can_transition(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        from == to || self.transitions.contains(&(from.to_int(), to.to_int()))
+    }
+}
+
+impl Default for TaskWorkflow {
+    fn default() -> Self {
+        let statuses = [
+            (1, "ToDo", TaskStatusCategory::Todo),
+            (2, "Doing", TaskStatusCategory::InProgress),
+            (3, "Done", TaskStatusCategory::Done),
+        ]
+        .into_iter()
+        .map(|(id, name, category)| TaskStatusDefinition {
+            status: TaskStatus::new(id, category),
+            name: String::from(name),
+        })
+        .collect();
+        let transitions = vec![(1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2)];
+
+        Self {
+            statuses,
+            transitions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(id: i32, name: &str, category: TaskStatusCategory) -> TaskStatusDefinition {
+        TaskStatusDefinition {
+            status: TaskStatus::new(id, category),
+            name: String::from(name),
+        }
+    }
+
+    fn review_workflow() -> TaskWorkflow {
+        TaskWorkflow::new(
+            vec![
+                definition(1, "ToDo", TaskStatusCategory::Todo),
+                definition(2, "Doing", TaskStatusCategory::InProgress),
+                definition(3, "Review", TaskStatusCategory::InProgress),
+                definition(4, "Done", TaskStatusCategory::Done),
+                definition(5, "Won't Do", TaskStatusCategory::Done),
+            ],
+            vec![(1, 2), (2, 3), (3, 2), (3, 4), (1, 5)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn status_when_defined_then_returns_status_with_category() {
+        let workflow = review_workflow();
+        let status = workflow.status(3).unwrap();
+        assert_eq!(status.to_int(), 3);
+        assert_eq!(status.category(), TaskStatusCategory::InProgress);
+    }
+
+    #[test]
+    fn status_when_not_defined_then_returns_error() {
+        let err = review_workflow().status(6).unwrap_err();
+        assert_eq!(err, StatusParseError::InvalidStatus);
+    }
+
+    #[test]
+    fn can_transition_when_transition_defined_then_returns_true() {
+        let workflow = review_workflow();
+        let todo = workflow.status(1).unwrap();
+        let doing = workflow.status(2).unwrap();
+        let done = workflow.status(4).unwrap();
+        assert!(workflow.can_transition(&todo, &doing));
+        assert!(workflow.can_transition(&todo, &todo));
+        assert!(!workflow.can_transition(&todo, &done));
+        assert!(!workflow.can_transition(&doing, &todo));
+    }
+
+    #[test]
+    fn done_statuses_when_called_then_returns_done_category() {
+        let ids: Vec<i32> = review_workflow()
+            .done_statuses()
+            .iter()
+            .map(|status| status.to_int())
+            .collect();
+        assert_eq!(ids, vec![4, 5]);
+    }
+
+    #[test]
+    fn initial_status_when_called_then_returns_first_todo_status() {
+        assert_eq!(review_workflow().initial_status().to_int(), 1);
+
+        let workflow = TaskWorkflow::new(
+            vec![
+                definition(7, "Doing", TaskStatusCategory::InProgress),
+                definition(8, "Todo", TaskStatusCategory::Todo),
+            ],
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(workflow.initial_status().to_int(), 8);
+    }
+
+    #[test]
+    fn new_when_definition_is_invalid_then_returns_error() {
+        let err = TaskWorkflow::new(Vec::new(), Vec::new()).unwrap_err();
+        assert_eq!(err, TaskWorkflowError::NoStatuses);
+
+        let err = TaskWorkflow::new(
+            vec![
+                definition(1, "ToDo", TaskStatusCategory::Todo),
+                definition(1, "Doing", TaskStatusCategory::InProgress),
+            ],
+            Vec::new(),
+        )
+        .unwrap_err();
+        assert_eq!(err, TaskWorkflowError::DuplicateStatus);
+
+        let err = TaskWorkflow::new(
+            vec![definition(1, "ToDo", TaskStatusCategory::Todo)],
+            vec![(1, 2)],
+        )
+        .unwrap_err();
+        assert_eq!(err, TaskWorkflowError::UnknownTransitionStatus);
+    }
+}
@@ -1,12 +1,16 @@
 mod application_config;
 mod controllers;
 mod domain;
+mod migrate_command;
+mod query_params;
 mod repositories;
+mod router;
 mod server;
 mod use_cases;
 
 use crate::application_config::ApplicationConfig;
 use server::Server;
+use std::process;
 
 fn main() {
     match dotenvy::dotenv() {
@@ -16,5 +20,33 @@ fn main() {
         }
     }
 
-    Server::new(ApplicationConfig::new()).start();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => {
+            if let Err(e) = Server::new(load_config()).start() {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+        Some("migrate") => {
+            if let Err(e) = migrate_command::run(&load_config(), &args[1..]) {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+        Some(command) => {
+            eprintln!("error: unknown command: {}", command);
+            process::exit(2);
+        }
+    }
+}
+
+fn load_config() -> ApplicationConfig {
+    match ApplicationConfig::new() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use crate::application_config::{ApplicationConfig, StorageBackend};
+use crate::repositories::app_db::AppDb;
+use crate::repositories::mysql_migration::MysqlMigrator;
+
+const MIGRATE_USAGE: &str = "usage: api_server migrate <up|down|status>";
+
+pub fn run(config: &ApplicationConfig, args: &[String]) -> Result<(), String> {
+    let action = match args {
+        [action] => action.as_str(),
+        _ => return Err(String::from(MIGRATE_USAGE)),
+    };
+    let db_config = match (config.storage_config().backend(), config.db_config()) {
+        (StorageBackend::Mysql, Some(db_config)) => db_config,
+        _ => return Err(String::from("migrations require the mysql storage backend")),
+    };
+
+    let app_db = AppDb::connect(db_config).map_err(|e| e.to_string())?;
+    let migrator = MysqlMigrator::new(&app_db);
+    match action {
+        "up" => {
+            let applied = migrator.up().map_err(|e| e.to_string())?;
+            if applied.is_empty() {
+                println!("schema is up to date");
+            }
+            for migration in applied {
+                println!("applied {} {}", migration.version, migration.name);
+            }
+        }
+        "down" => match migrator.down().map_err(|e| e.to_string())? {
+            Some(migration) => println!("reverted {} {}", migration.version, migration.name),
+            None => println!("no migration to revert"),
+        },
+        "status" => {
+            for status in migrator.status().map_err(|e| e.to_string())? {
+                println!(
+                    "{:>4} {:<32} {}",
+                    status.migration.version,
+                    status.migration.name,
+                    status
+                        .applied_at
+                        .map(|applied_at| applied_at.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| String::from("pending")),
+                );
+            }
+        }
+        _ => return Err(String::from(MIGRATE_USAGE)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct QueryParams {
+    values: HashMap<String, String>,
+}
+
+impl QueryParams {
+    pub fn from_url(url: &str) -> Self {
+        let query = match url.split_once('?') {
+            Some((_, query)) => query,
+            None => return Self::default(),
+        };
+
+        let values = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) => (decode(key), decode(value)),
+                None => (decode(pair), String::new()),
+            })
+            .collect();
+
+        Self { values }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|value| value.as_str())
+    }
+}
+
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match byte {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_url_when_query_string_present_then_returns_values() {
+        let params = QueryParams::from_url("/api/v1/tasks?status=2&sort=title&order=desc");
+        assert_eq!(params.get("status"), Some("2"));
+        assert_eq!(params.get("sort"), Some("title"));
+        assert_eq!(params.get("order"), Some("desc"));
+        assert_eq!(params.get("limit"), None);
+    }
+
+    #[test]
+    fn from_url_when_no_query_string_then_returns_empty() {
+        let params = QueryParams::from_url("/api/v1/tasks");
+        assert_eq!(params.get("status"), None);
+    }
+
+    #[test]
+    fn from_url_when_values_are_encoded_then_decodes_them() {
+        let params = QueryParams::from_url("/api/v1/tasks?q=a+b%2Fc&flag");
+        assert_eq!(params.get("q"), Some("a b/c"));
+        assert_eq!(params.get("flag"), Some(""));
+    }
+}
@@ -1,2 +1,6 @@
 pub mod app_db;
+pub mod label;
+pub mod mysql_migration;
+pub mod postgres_db;
+pub mod sqlite_db;
 pub mod task;
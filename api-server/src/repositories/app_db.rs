@@ -1,4 +1,5 @@
 use crate::application_config::DbConfig;
+use crate::repositories::mysql_migration::{MigrationError, MysqlMigrator};
 use mysql::{Pool, PooledConn};
 use std::thread;
 use std::time::Duration;
@@ -8,7 +9,24 @@ pub struct AppDb {
 }
 
 impl AppDb {
-    pub fn new(db_config: &DbConfig) -> Result<Self, mysql::Error> {
+    pub fn new(db_config: &DbConfig) -> Result<Self, MigrationError> {
+        let app_db = Self::connect(db_config)?;
+        let migrator = MysqlMigrator::new(&app_db);
+        if db_config.migrate_on_startup() {
+            for migration in migrator.up()? {
+                println!(
+                    "info: applied migration {} ({})",
+                    migration.version, migration.name
+                );
+            }
+        } else {
+            migrator.verify()?;
+        }
+
+        Ok(app_db)
+    }
+
+    pub fn connect(db_config: &DbConfig) -> Result<Self, MigrationError> {
         let url = db_config.database_url("app_db");
 
         let mut last_err = None;
@@ -32,13 +50,71 @@ impl AppDb {
             thread::sleep(Duration::from_secs(backoff_sec));
         }
 
-        panic!(
-            "error: failed to connect to mysql after 10 attempts: {:?}",
-            last_err
-        );
+        Err(MigrationError::DbError(format!(
+            "failed to connect to mysql after 10 attempts: {}",
+            last_err.unwrap_or_default()
+        )))
     }
 
     pub fn get_conn(&self) -> PooledConn {
         self.pool.get_conn().unwrap()
     }
+
+    pub fn close(self) {
+        drop(self.pool);
+        println!("info: closed DB connections");
+    }
+}
+
+#[cfg(test)]
+pub struct MysqlTestDb {
+    pub db: AppDb,
+    admin: Pool,
+    database: String,
+}
+
+#[cfg(test)]
+impl MysqlTestDb {
+    pub fn new() -> Self {
+        use mysql::prelude::Queryable;
+
+        let url = std::env::var("TEST_MYSQL_URL")
+            .expect("TEST_MYSQL_URL must point to a local MySQL instance");
+        let admin = Pool::new(url.as_str()).unwrap();
+        let database = format!("test_{}", uuid::Uuid::new_v4().simple());
+        admin
+            .get_conn()
+            .unwrap()
+            .query_drop(format!("CREATE DATABASE {}", database))
+            .unwrap();
+
+        let opts = mysql::OptsBuilder::from_opts(mysql::Opts::from_url(&url).unwrap())
+            .db_name(Some(&database));
+        let db = AppDb {
+            pool: Pool::new(opts).unwrap(),
+        };
+
+        Self {
+            db,
+            admin,
+            database,
+        }
+    }
+
+    pub fn migrated() -> Self {
+        let test_db = Self::new();
+        MysqlMigrator::new(&test_db.db).up().unwrap();
+        test_db
+    }
+}
+
+#[cfg(test)]
+impl Drop for MysqlTestDb {
+    fn drop(&mut self) {
+        use mysql::prelude::Queryable;
+
+        if let Ok(mut conn) = self.admin.get_conn() {
+            let _ = conn.query_drop(format!("DROP DATABASE {}", self.database));
+        }
+    }
 }
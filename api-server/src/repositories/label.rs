@@ -0,0 +1,5 @@
+pub mod label_file_repository;
+pub mod label_in_memory_repository;
+pub mod label_mysql_repository;
+pub mod label_postgres_repository;
+pub mod label_sqlite_repository;
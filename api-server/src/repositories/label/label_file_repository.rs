@@ -0,0 +1,190 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+pub struct LabelFileRepository {
+    path: PathBuf,
+    labels: LabelInMemoryRepository,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LabelRecord {
+    id: String,
+    name: String,
+    color: String,
+}
+
+impl LabelFileRepository {
+    pub fn open(path: &Path) -> Result<Self, LabelRepositoryError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        let labels = LabelInMemoryRepository::new();
+        if path.exists() {
+            let records: Vec<LabelRecord> =
+                serde_json::from_reader(BufReader::new(File::open(path).map_err(io_error)?))
+                    .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+            for record in records {
+                labels.register(record.into_label()?)?;
+            }
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            labels,
+        })
+    }
+
+    fn save(&self) -> Result<(), LabelRepositoryError> {
+        let records: Vec<LabelRecord> = self.labels.list()?.iter().map(LabelRecord::from).collect();
+
+        let mut temporary_path = self.path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+        let temporary_path = PathBuf::from(temporary_path);
+        let mut file = File::create(&temporary_path).map_err(io_error)?;
+        serde_json::to_writer(&mut file, &records)
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+        file.sync_data().map_err(io_error)?;
+        fs::rename(&temporary_path, &self.path).map_err(io_error)
+    }
+}
+
+impl LabelRepository for LabelFileRepository {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError> {
+        self.labels.list()
+    }
+
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError> {
+        self.labels.get_by_id(id)
+    }
+
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let label = self.labels.register(label)?;
+        if let Err(e) = self.save() {
+            let _ = self.labels.delete(&label.id);
+            return Err(e);
+        }
+
+        Ok(label)
+    }
+
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let previous = self.labels.get_by_id(&label.id)?;
+        let label = self.labels.update(label)?;
+        if let Err(e) = self.save() {
+            let _ = self.labels.update(previous);
+            return Err(e);
+        }
+
+        Ok(label)
+    }
+
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError> {
+        let previous = self.labels.get_by_id(label_id)?;
+        self.labels.delete(label_id)?;
+        if let Err(e) = self.save() {
+            let _ = self.labels.register(previous);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+impl LabelRecord {
+    fn into_label(self) -> Result<Label, LabelRepositoryError> {
+        let invalid =
+            |field: &str| LabelRepositoryError::DbError(format!("invalid label {}", field));
+        Ok(Label::new(
+            LabelId::try_from(self.id.as_str()).map_err(|_| invalid("id"))?,
+            LabelName::try_from(self.name.as_str()).map_err(|_| invalid("name"))?,
+            LabelColor::try_from(self.color.as_str()).map_err(|_| invalid("color"))?,
+        ))
+    }
+}
+
+impl From<&Label> for LabelRecord {
+    fn from(label: &Label) -> Self {
+        Self {
+            id: label.id.to_string(),
+            name: label.name.to_string(),
+            color: label.color.to_string(),
+        }
+    }
+}
+
+fn io_error(e: std::io::Error) -> LabelRepositoryError {
+    LabelRepositoryError::DbError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    struct TemporaryFile {
+        directory: PathBuf,
+    }
+
+    impl TemporaryFile {
+        fn new() -> Self {
+            Self {
+                directory: std::env::temp_dir().join(format!("labels-{}", Uuid::new_v4())),
+            }
+        }
+
+        fn path(&self) -> PathBuf {
+            self.directory.join("labels.json")
+        }
+    }
+
+    impl Drop for TemporaryFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+    }
+
+    fn label(name: &str) -> Label {
+        Label::new(
+            LabelId::new(),
+            LabelName::try_from(name).unwrap(),
+            LabelColor::try_from("#d73a4a").unwrap(),
+        )
+    }
+
+    #[test]
+    fn open_when_labels_were_registered_then_restores_them() {
+        let file = TemporaryFile::new();
+        let repository = LabelFileRepository::open(&file.path()).unwrap();
+        let bug = repository.register(label("bug")).unwrap();
+        let feature = repository.register(label("feature")).unwrap();
+        repository
+            .update(feature.change_color(LabelColor::try_from("#000000").unwrap()))
+            .unwrap();
+        repository.delete(&bug.id).unwrap();
+
+        let repository = LabelFileRepository::open(&file.path()).unwrap();
+        let labels = repository.list().unwrap();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name.to_string(), "feature");
+        assert_eq!(labels[0].color.to_string(), "#000000");
+    }
+
+    #[test]
+    fn register_when_name_is_taken_then_returns_error() {
+        let file = TemporaryFile::new();
+        let repository = LabelFileRepository::open(&file.path()).unwrap();
+        repository.register(label("bug")).unwrap();
+
+        let result = repository.register(label("bug"));
+        assert!(matches!(result, Err(LabelRepositoryError::AlreadyExists)));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub struct LabelInMemoryRepository {
+    data: RefCell<HashMap<LabelId, Label>>,
+}
+
+impl LabelInMemoryRepository {
+    pub fn new() -> Self {
+        Self {
+            data: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn name_is_taken(data: &HashMap<LabelId, Label>, label: &Label) -> bool {
+        data.values()
+            .any(|other| other.id != label.id && other.name.to_string() == label.name.to_string())
+    }
+}
+
+impl LabelRepository for LabelInMemoryRepository {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError> {
+        let mut labels: Vec<Label> = self.data.borrow().values().cloned().collect();
+        labels.sort_by_key(|label| label.name.to_string());
+        Ok(labels)
+    }
+
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError> {
+        self.data
+            .borrow()
+            .get(id)
+            .cloned()
+            .ok_or(LabelRepositoryError::NotFound)
+    }
+
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut data = self.data.borrow_mut();
+
+        if data.contains_key(&label.id) || Self::name_is_taken(&data, &label) {
+            return Err(LabelRepositoryError::AlreadyExists);
+        }
+
+        data.insert(label.id.clone(), label.clone());
+        Ok(label)
+    }
+
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut data = self.data.borrow_mut();
+
+        if !data.contains_key(&label.id) {
+            return Err(LabelRepositoryError::NotFound);
+        }
+        if Self::name_is_taken(&data, &label) {
+            return Err(LabelRepositoryError::AlreadyExists);
+        }
+
+        data.insert(label.id.clone(), label.clone());
+        Ok(label)
+    }
+
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError> {
+        let mut data = self.data.borrow_mut();
+
+        data.remove(label_id)
+            .ok_or(LabelRepositoryError::NotFound)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl LabelInMemoryRepository {
+    pub fn register_test_data(&mut self) -> Vec<LabelId> {
+        use crate::domain::label::label_color::LabelColor;
+        use crate::domain::label::label_name::LabelName;
+
+        let mut ids = Vec::new();
+        let data = [("bug", "#d73a4a"), ("feature", "#a2eeef")];
+
+        let mut map = self.data.borrow_mut();
+
+        for (name, color) in data {
+            let label = Label::new(
+                LabelId::new(),
+                LabelName::try_from(name).unwrap(),
+                LabelColor::try_from(color).unwrap(),
+            );
+
+            ids.push(label.id.clone());
+            map.insert(label.id.clone(), label);
+        }
+
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::label::label_color::LabelColor;
+    use crate::domain::label::label_name::LabelName;
+
+    #[test]
+    fn list_when_labels_are_registered_then_returns_sorted_labels() {
+        let mut repository = LabelInMemoryRepository::new();
+        repository.register_test_data();
+
+        let labels = repository.list().unwrap();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].name.to_string(), "bug");
+    }
+
+    #[test]
+    fn register_when_name_is_taken_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        repository.register_test_data();
+
+        let label = Label::new(
+            LabelId::new(),
+            LabelName::try_from("bug").unwrap(),
+            LabelColor::try_from("#000000").unwrap(),
+        );
+        let result = repository.register(label);
+        assert!(matches!(result, Err(LabelRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    fn update_when_valid_value_then_returns_label() {
+        let mut repository = LabelInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let label = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_color(LabelColor::try_from("#000000").unwrap());
+        let label = repository.update(label).unwrap();
+        assert_eq!(label.color.to_string(), "#000000");
+    }
+
+    #[test]
+    fn delete_when_valid_id_then_removes_label() {
+        let mut repository = LabelInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        repository.delete(&ids[0]).unwrap();
+        assert!(repository.get_by_id(&ids[0]).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::repositories::app_db::AppDb;
+use mysql::params;
+use mysql::prelude::*;
+
+const ER_DUP_ENTRY: u16 = 1062;
+
+pub struct LabelMysqlRepository<'a> {
+    db: &'a AppDb,
+}
+
+impl<'a> LabelMysqlRepository<'a> {
+    pub fn new(db: &'a AppDb) -> Self {
+        Self { db }
+    }
+}
+
+impl<'a> LabelRepository for LabelMysqlRepository<'a> {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.query_map(
+            "SELECT id, name, color FROM labels ORDER BY name",
+            label_from_row,
+        )
+        .map_err(|e| LabelRepositoryError::DbError(e.to_string()))
+    }
+
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        let row: Option<(String, String, String)> = conn
+            .exec_first(
+                "SELECT id, name, color FROM labels WHERE id = :id",
+                params! {
+                    "id" => id.to_string(),
+                },
+            )
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+
+        row.map(label_from_row)
+            .ok_or(LabelRepositoryError::NotFound)
+    }
+
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.exec_drop(
+            "INSERT INTO labels (id, name, color) VALUES (:id, :name, :color)",
+            params! {
+                "id" => label.id.to_string(),
+                "name" => label.name.to_string(),
+                "color" => label.color.to_string(),
+            },
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.exec_drop(
+            "UPDATE labels SET name = :name, color = :color WHERE id = :id",
+            params! {
+                "id" => label.id.to_string(),
+                "name" => label.name.to_string(),
+                "color" => label.color.to_string(),
+            },
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.exec_drop(
+            "DELETE FROM labels WHERE id = :id",
+            params! {
+                "id" => label_id.to_string(),
+            },
+        )
+        .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+
+        if conn.affected_rows() == 0 {
+            return Err(LabelRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+fn label_from_row((id, name, color): (String, String, String)) -> Label {
+    Label::new(
+        LabelId::try_from(id.as_str()).unwrap(),
+        LabelName::try_from(name.as_str()).unwrap(),
+        LabelColor::try_from(color.as_str()).unwrap(),
+    )
+}
+
+fn map_write_error(e: mysql::Error) -> LabelRepositoryError {
+    match e {
+        mysql::Error::MySqlError(ref err) if err.code == ER_DUP_ENTRY => {
+            LabelRepositoryError::AlreadyExists
+        }
+        e => LabelRepositoryError::DbError(e.to_string()),
+    }
+}
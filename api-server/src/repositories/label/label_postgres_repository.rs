@@ -0,0 +1,169 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::repositories::postgres_db::PostgresDb;
+use postgres::Row;
+use postgres::error::SqlState;
+
+pub struct LabelPostgresRepository<'a> {
+    db: &'a PostgresDb,
+}
+
+impl<'a> LabelPostgresRepository<'a> {
+    pub fn new(db: &'a PostgresDb) -> Self {
+        Self { db }
+    }
+}
+
+impl<'a> LabelRepository for LabelPostgresRepository<'a> {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.query("SELECT id, name, color FROM labels ORDER BY name", &[])
+            .map(|rows| rows.iter().map(label_from_row).collect())
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))
+    }
+
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        let row = conn
+            .query_opt(
+                "SELECT id, name, color FROM labels WHERE id = $1",
+                &[&id.to_uuid()],
+            )
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+
+        row.as_ref()
+            .map(label_from_row)
+            .ok_or(LabelRepositoryError::NotFound)
+    }
+
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.execute(
+            "INSERT INTO labels (id, name, color) VALUES ($1, $2, $3)",
+            &[
+                &label.id.to_uuid(),
+                &label.name.to_string(),
+                &label.color.to_string(),
+            ],
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        conn.execute(
+            "UPDATE labels SET name = $2, color = $3 WHERE id = $1",
+            &[
+                &label.id.to_uuid(),
+                &label.name.to_string(),
+                &label.color.to_string(),
+            ],
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError> {
+        let mut conn = self.db.get_conn();
+
+        let affected_rows = conn
+            .execute("DELETE FROM labels WHERE id = $1", &[&label_id.to_uuid()])
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+
+        if affected_rows == 0 {
+            return Err(LabelRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+fn label_from_row(row: &Row) -> Label {
+    let name: String = row.get("name");
+    let color: String = row.get("color");
+
+    Label::new(
+        LabelId::from(row.get("id")),
+        LabelName::try_from(name.as_str()).unwrap(),
+        LabelColor::try_from(color.as_str()).unwrap(),
+    )
+}
+
+fn map_write_error(e: postgres::Error) -> LabelRepositoryError {
+    match e.code() {
+        Some(code) if *code == SqlState::UNIQUE_VIOLATION => LabelRepositoryError::AlreadyExists,
+        _ => LabelRepositoryError::DbError(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::postgres_db::PostgresTestDb;
+
+    fn register_test_data(repository: &LabelPostgresRepository) -> Vec<LabelId> {
+        [("feature", "#a2eeef"), ("bug", "#d73a4a")]
+            .into_iter()
+            .map(|(name, color)| {
+                let label = Label::new(
+                    LabelId::new(),
+                    LabelName::try_from(name).unwrap(),
+                    LabelColor::try_from(color).unwrap(),
+                );
+                repository.register(label).unwrap().id
+            })
+            .collect()
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn list_when_labels_are_registered_then_returns_sorted_labels() {
+        let test_db = PostgresTestDb::new();
+        let repository = LabelPostgresRepository::new(&test_db.db);
+        register_test_data(&repository);
+
+        let labels = repository.list().unwrap();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].name.to_string(), "bug");
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn register_when_name_is_taken_then_returns_error() {
+        let test_db = PostgresTestDb::new();
+        let repository = LabelPostgresRepository::new(&test_db.db);
+        register_test_data(&repository);
+
+        let label = Label::new(
+            LabelId::new(),
+            LabelName::try_from("bug").unwrap(),
+            LabelColor::try_from("#000000").unwrap(),
+        );
+        let result = repository.register(label);
+        assert!(matches!(result, Err(LabelRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn delete_when_valid_id_then_removes_label() {
+        let test_db = PostgresTestDb::new();
+        let repository = LabelPostgresRepository::new(&test_db.db);
+        let ids = register_test_data(&repository);
+
+        repository.delete(&ids[0]).unwrap();
+        assert!(matches!(
+            repository.get_by_id(&ids[0]),
+            Err(LabelRepositoryError::NotFound)
+        ));
+    }
+}
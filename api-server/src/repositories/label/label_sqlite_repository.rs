@@ -0,0 +1,196 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::repositories::sqlite_db::{SqliteDb, is_unique_violation};
+use rusqlite::{OptionalExtension, Row, named_params};
+
+pub struct LabelSqliteRepository<'a> {
+    db: &'a SqliteDb,
+}
+
+impl<'a> LabelSqliteRepository<'a> {
+    pub fn new(db: &'a SqliteDb) -> Self {
+        Self { db }
+    }
+}
+
+impl<'a> LabelRepository for LabelSqliteRepository<'a> {
+    fn list(&self) -> Result<Vec<Label>, LabelRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let mut statement = conn
+            .prepare("SELECT id, name, color FROM labels ORDER BY name")
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+        statement
+            .query_map([], label_from_row)
+            .and_then(|rows| rows.collect())
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))
+    }
+
+    fn get_by_id(&self, id: &LabelId) -> Result<Label, LabelRepositoryError> {
+        let conn = self.db.get_conn();
+
+        conn.query_row(
+            "SELECT id, name, color FROM labels WHERE id = :id",
+            named_params! {
+                ":id": id.to_string(),
+            },
+            label_from_row,
+        )
+        .optional()
+        .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?
+        .ok_or(LabelRepositoryError::NotFound)
+    }
+
+    fn register(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let conn = self.db.get_conn();
+
+        conn.execute(
+            "INSERT INTO labels (id, name, color) VALUES (:id, :name, :color)",
+            named_params! {
+                ":id": label.id.to_string(),
+                ":name": label.name.to_string(),
+                ":color": label.color.to_string(),
+            },
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn update(&self, label: Label) -> Result<Label, LabelRepositoryError> {
+        let conn = self.db.get_conn();
+
+        conn.execute(
+            "UPDATE labels SET name = :name, color = :color WHERE id = :id",
+            named_params! {
+                ":id": label.id.to_string(),
+                ":name": label.name.to_string(),
+                ":color": label.color.to_string(),
+            },
+        )
+        .map_err(map_write_error)?;
+
+        Ok(label)
+    }
+
+    fn delete(&self, label_id: &LabelId) -> Result<(), LabelRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let affected_rows = conn
+            .execute(
+                "DELETE FROM labels WHERE id = :id",
+                named_params! {
+                    ":id": label_id.to_string(),
+                },
+            )
+            .map_err(|e| LabelRepositoryError::DbError(e.to_string()))?;
+
+        if affected_rows == 0 {
+            return Err(LabelRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+fn label_from_row(row: &Row) -> Result<Label, rusqlite::Error> {
+    let id: String = row.get("id")?;
+    let name: String = row.get("name")?;
+    let color: String = row.get("color")?;
+
+    Ok(Label::new(
+        LabelId::try_from(id.as_str()).unwrap(),
+        LabelName::try_from(name.as_str()).unwrap(),
+        LabelColor::try_from(color.as_str()).unwrap(),
+    ))
+}
+
+fn map_write_error(e: rusqlite::Error) -> LabelRepositoryError {
+    if is_unique_violation(&e) {
+        LabelRepositoryError::AlreadyExists
+    } else {
+        LabelRepositoryError::DbError(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register_test_data(repository: &LabelSqliteRepository) -> Vec<LabelId> {
+        [("feature", "#a2eeef"), ("bug", "#d73a4a")]
+            .into_iter()
+            .map(|(name, color)| {
+                let label = Label::new(
+                    LabelId::new(),
+                    LabelName::try_from(name).unwrap(),
+                    LabelColor::try_from(color).unwrap(),
+                );
+                repository.register(label).unwrap().id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn list_when_labels_are_registered_then_returns_sorted_labels() {
+        let db = SqliteDb::open_in_memory();
+        let repository = LabelSqliteRepository::new(&db);
+        register_test_data(&repository);
+
+        let labels = repository.list().unwrap();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].name.to_string(), "bug");
+    }
+
+    #[test]
+    fn register_when_name_is_taken_then_returns_error() {
+        let db = SqliteDb::open_in_memory();
+        let repository = LabelSqliteRepository::new(&db);
+        register_test_data(&repository);
+
+        let label = Label::new(
+            LabelId::new(),
+            LabelName::try_from("bug").unwrap(),
+            LabelColor::try_from("#000000").unwrap(),
+        );
+        let result = repository.register(label);
+        assert!(matches!(result, Err(LabelRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    fn update_when_valid_value_then_returns_label() {
+        let db = SqliteDb::open_in_memory();
+        let repository = LabelSqliteRepository::new(&db);
+        let ids = register_test_data(&repository);
+
+        let label = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_color(LabelColor::try_from("#000000").unwrap());
+        repository.update(label).unwrap();
+        assert_eq!(
+            repository.get_by_id(&ids[0]).unwrap().color.to_string(),
+            "#000000"
+        );
+    }
+
+    #[test]
+    fn delete_when_valid_id_then_removes_label() {
+        let db = SqliteDb::open_in_memory();
+        let repository = LabelSqliteRepository::new(&db);
+        let ids = register_test_data(&repository);
+
+        repository.delete(&ids[0]).unwrap();
+        assert!(matches!(
+            repository.get_by_id(&ids[0]),
+            Err(LabelRepositoryError::NotFound)
+        ));
+        assert!(matches!(
+            repository.delete(&ids[0]),
+            Err(LabelRepositoryError::NotFound)
+        ));
+    }
+}
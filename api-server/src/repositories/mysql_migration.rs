@@ -0,0 +1,449 @@
+use crate::repositories::app_db::AppDb;
+use chrono::{NaiveDateTime, Utc};
+use mysql::prelude::*;
+use mysql::{PooledConn, params};
+use std::fmt;
+
+const MIGRATION_LOCK_NAME: &str = "app_db.schema_migrations";
+const MIGRATION_LOCK_TIMEOUT_SEC: u32 = 30;
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_schema",
+        up: include_str!("../../migrations/mysql/0001_create_schema.up.sql"),
+        down: include_str!("../../migrations/mysql/0001_create_schema.down.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "add_task_attributes",
+        up: include_str!("../../migrations/mysql/0002_add_task_attributes.up.sql"),
+        down: include_str!("../../migrations/mysql/0002_add_task_attributes.down.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "create_labels",
+        up: include_str!("../../migrations/mysql/0003_create_labels.up.sql"),
+        down: include_str!("../../migrations/mysql/0003_create_labels.down.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "create_task_dependencies",
+        up: include_str!("../../migrations/mysql/0004_create_task_dependencies.up.sql"),
+        down: include_str!("../../migrations/mysql/0004_create_task_dependencies.down.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "create_task_events",
+        up: include_str!("../../migrations/mysql/0005_create_task_events.up.sql"),
+        down: include_str!("../../migrations/mysql/0005_create_task_events.down.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "add_task_version",
+        up: include_str!("../../migrations/mysql/0006_add_task_version.up.sql"),
+        down: include_str!("../../migrations/mysql/0006_add_task_version.down.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "add_task_deleted_at",
+        up: include_str!("../../migrations/mysql/0007_add_task_deleted_at.up.sql"),
+        down: include_str!("../../migrations/mysql/0007_add_task_deleted_at.down.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "add_task_archived_at",
+        up: include_str!("../../migrations/mysql/0008_add_task_archived_at.up.sql"),
+        down: include_str!("../../migrations/mysql/0008_add_task_archived_at.down.sql"),
+    },
+];
+
+#[derive(Debug)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppliedMigration {
+    pub version: u32,
+    pub checksum: String,
+    pub applied_at: NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct MigrationStatus<'m> {
+    pub migration: &'m Migration,
+    pub applied_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MigrationError {
+    UnknownVersion(u32),
+    ChecksumMismatch(u32),
+    PendingMigrations(usize),
+    LockTimeout,
+    DbError(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::UnknownVersion(version) => write!(
+                f,
+                "migration {} is applied but unknown to this build",
+                version
+            ),
+            MigrationError::ChecksumMismatch(version) => {
+                write!(f, "migration {} was modified after being applied", version)
+            }
+            MigrationError::PendingMigrations(count) => {
+                write!(f, "{} migration(s) pending", count)
+            }
+            MigrationError::LockTimeout => write!(f, "timed out waiting for migration lock"),
+            MigrationError::DbError(message) => write!(f, "db error: {}", message),
+        }
+    }
+}
+
+impl Migration {
+    pub fn checksum(&self) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in self.up.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        format!("{:016x}", hash)
+    }
+}
+
+pub fn pending<'m>(
+    migrations: &'m [Migration],
+    applied: &[AppliedMigration],
+) -> Result<Vec<&'m Migration>, MigrationError> {
+    verify(migrations, applied)?;
+
+    Ok(migrations
+        .iter()
+        .filter(|migration| {
+            !applied
+                .iter()
+                .any(|applied| applied.version == migration.version)
+        })
+        .collect())
+}
+
+fn verify(migrations: &[Migration], applied: &[AppliedMigration]) -> Result<(), MigrationError> {
+    for applied in applied {
+        let migration = migrations
+            .iter()
+            .find(|migration| migration.version == applied.version)
+            .ok_or(MigrationError::UnknownVersion(applied.version))?;
+        if migration.checksum() != applied.checksum {
+            return Err(MigrationError::ChecksumMismatch(applied.version));
+        }
+    }
+
+    Ok(())
+}
+
+pub struct MysqlMigrator<'a> {
+    db: &'a AppDb,
+    migrations: &'a [Migration],
+}
+
+impl<'a> MysqlMigrator<'a> {
+    pub fn new(db: &'a AppDb) -> Self {
+        Self {
+            db,
+            migrations: MIGRATIONS,
+        }
+    }
+
+    pub fn status(&self) -> Result<Vec<MigrationStatus<'a>>, MigrationError> {
+        let mut conn = self.db.get_conn();
+        let applied = applied_migrations(&mut conn)?;
+        verify(self.migrations, &applied)?;
+
+        Ok(self
+            .migrations
+            .iter()
+            .map(|migration| MigrationStatus {
+                migration,
+                applied_at: applied
+                    .iter()
+                    .find(|applied| applied.version == migration.version)
+                    .map(|applied| applied.applied_at),
+            })
+            .collect())
+    }
+
+    pub fn verify(&self) -> Result<(), MigrationError> {
+        let mut conn = self.db.get_conn();
+        let applied = applied_migrations(&mut conn)?;
+
+        match pending(self.migrations, &applied)?.len() {
+            0 => Ok(()),
+            count => Err(MigrationError::PendingMigrations(count)),
+        }
+    }
+
+    pub fn up(&self) -> Result<Vec<&'a Migration>, MigrationError> {
+        let mut conn = self.db.get_conn();
+        with_lock(&mut conn, |conn| {
+            let applied = applied_migrations(conn)?;
+            let pending = pending(self.migrations, &applied)?;
+            for migration in &pending {
+                conn.query_drop(migration.up).map_err(db_error)?;
+                conn.exec_drop(
+                    "INSERT INTO schema_migrations (version, name, checksum, applied_at)
+                     VALUES (:version, :name, :checksum, :applied_at)",
+                    params! {
+                        "version" => migration.version,
+                        "name" => migration.name,
+                        "checksum" => migration.checksum(),
+                        "applied_at" => Utc::now().naive_utc(),
+                    },
+                )
+                .map_err(db_error)?;
+            }
+            Ok(pending)
+        })
+    }
+
+    pub fn down(&self) -> Result<Option<&'a Migration>, MigrationError> {
+        let mut conn = self.db.get_conn();
+        with_lock(&mut conn, |conn| {
+            let applied = applied_migrations(conn)?;
+            verify(self.migrations, &applied)?;
+            let Some(last) = applied.last() else {
+                return Ok(None);
+            };
+            let migration = self
+                .migrations
+                .iter()
+                .find(|migration| migration.version == last.version)
+                .ok_or(MigrationError::UnknownVersion(last.version))?;
+
+            conn.query_drop(migration.down).map_err(db_error)?;
+            conn.exec_drop(
+                "DELETE FROM schema_migrations WHERE version = :version",
+                params! {
+                    "version" => migration.version,
+                },
+            )
+            .map_err(db_error)?;
+            Ok(Some(migration))
+        })
+    }
+}
+
+fn applied_migrations(conn: &mut PooledConn) -> Result<Vec<AppliedMigration>, MigrationError> {
+    conn.query_drop(
+        "CREATE TABLE IF NOT EXISTS schema_migrations
+         (
+             version    INT          NOT NULL PRIMARY KEY,
+             name       VARCHAR(128) NOT NULL,
+             checksum   CHAR(16)     NOT NULL,
+             applied_at DATETIME     NOT NULL
+         )",
+    )
+    .map_err(db_error)?;
+
+    conn.query_map(
+        "SELECT version, checksum, applied_at FROM schema_migrations ORDER BY version",
+        |(version, checksum, applied_at)| AppliedMigration {
+            version,
+            checksum,
+            applied_at,
+        },
+    )
+    .map_err(db_error)
+}
+
+fn with_lock<T>(
+    conn: &mut PooledConn,
+    f: impl FnOnce(&mut PooledConn) -> Result<T, MigrationError>,
+) -> Result<T, MigrationError> {
+    let locked: Option<Option<i32>> = conn
+        .exec_first(
+            "SELECT GET_LOCK(:name, :timeout)",
+            params! {
+                "name" => MIGRATION_LOCK_NAME,
+                "timeout" => MIGRATION_LOCK_TIMEOUT_SEC,
+            },
+        )
+        .map_err(db_error)?;
+    if locked.flatten() != Some(1) {
+        return Err(MigrationError::LockTimeout);
+    }
+
+    let result = f(conn);
+    conn.exec_drop(
+        "SELECT RELEASE_LOCK(:name)",
+        params! {
+            "name" => MIGRATION_LOCK_NAME,
+        },
+    )
+    .map_err(db_error)?;
+    result
+}
+
+fn db_error(e: mysql::Error) -> MigrationError {
+    MigrationError::DbError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::app_db::MysqlTestDb;
+    use chrono::DateTime;
+
+    const BASELINE_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS tasks
+        (
+            id          VARCHAR(128) NOT NULL PRIMARY KEY,
+            title       VARCHAR(64)  NOT NULL,
+            description VARCHAR(256) NOT NULL,
+            status      INT          NOT NULL
+        )";
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            name: "create_tasks",
+            up: "CREATE TABLE tasks (id INT)",
+            down: "DROP TABLE tasks",
+        },
+        Migration {
+            version: 2,
+            name: "add_title",
+            up: "ALTER TABLE tasks ADD COLUMN title VARCHAR(64)",
+            down: "ALTER TABLE tasks DROP COLUMN title",
+        },
+    ];
+
+    fn applied(migration: &Migration) -> AppliedMigration {
+        AppliedMigration {
+            version: migration.version,
+            checksum: migration.checksum(),
+            applied_at: DateTime::UNIX_EPOCH.naive_utc(),
+        }
+    }
+
+    #[test]
+    fn pending_when_nothing_is_applied_then_returns_all_migrations() {
+        let pending = pending(TEST_MIGRATIONS, &[]).unwrap();
+        let versions: Vec<u32> = pending.iter().map(|migration| migration.version).collect();
+        assert_eq!(versions, vec![1, 2]);
+    }
+
+    #[test]
+    fn pending_when_some_are_applied_then_returns_remaining_migrations() {
+        let pending = pending(TEST_MIGRATIONS, &[applied(&TEST_MIGRATIONS[0])]).unwrap();
+        let versions: Vec<u32> = pending.iter().map(|migration| migration.version).collect();
+        assert_eq!(versions, vec![2]);
+    }
+
+    #[test]
+    fn pending_when_applied_version_is_unknown_then_returns_error() {
+        let mut unknown = applied(&TEST_MIGRATIONS[1]);
+        unknown.version = 3;
+
+        let result = pending(TEST_MIGRATIONS, &[applied(&TEST_MIGRATIONS[0]), unknown]);
+        assert_eq!(result.unwrap_err(), MigrationError::UnknownVersion(3));
+    }
+
+    #[test]
+    fn pending_when_applied_migration_was_modified_then_returns_error() {
+        let mut modified = applied(&TEST_MIGRATIONS[0]);
+        modified.checksum = String::from("0000000000000000");
+
+        let result = pending(TEST_MIGRATIONS, &[modified]);
+        assert_eq!(result.unwrap_err(), MigrationError::ChecksumMismatch(1));
+    }
+
+    #[test]
+    fn migrations_when_embedded_then_versions_are_increasing_and_reversible() {
+        assert!(
+            MIGRATIONS
+                .windows(2)
+                .all(|pair| pair[0].version < pair[1].version)
+        );
+        assert!(
+            MIGRATIONS
+                .iter()
+                .all(|migration| !migration.up.trim().is_empty()
+                    && !migration.down.trim().is_empty())
+        );
+    }
+
+    fn tables(conn: &mut PooledConn) -> Vec<String> {
+        conn.query(
+            "SELECT table_name FROM information_schema.tables
+             WHERE table_schema = DATABASE() ORDER BY table_name",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn up_when_baseline_schema_exists_then_migrates_to_head() {
+        let test_db = MysqlTestDb::new();
+        let mut conn = test_db.db.get_conn();
+        conn.query_drop(BASELINE_SCHEMA).unwrap();
+        conn.query_drop(
+            "INSERT INTO tasks (id, title, description, status)
+             VALUES ('9c3f8a52-1a7e-4f55-9a53-6bd1b7a0f6c1', 'Task Title', 'Task Description', 1)",
+        )
+        .unwrap();
+
+        let migrator = MysqlMigrator::new(&test_db.db);
+        let applied = migrator.up().unwrap();
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert_eq!(migrator.verify(), Ok(()));
+
+        let row: Option<(i32, Option<String>, u64, Option<NaiveDateTime>)> = conn
+            .query_first("SELECT priority, parent_id, version, archived_at FROM tasks")
+            .unwrap();
+        assert_eq!(row, Some((2, None, 1, None)));
+        assert_eq!(
+            tables(&mut conn),
+            vec![
+                "labels",
+                "schema_migrations",
+                "task_dependencies",
+                "task_events",
+                "task_labels",
+                "tasks"
+            ]
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn down_when_at_head_then_reverts_to_baseline_schema() {
+        let test_db = MysqlTestDb::new();
+        let migrator = MysqlMigrator::new(&test_db.db);
+        migrator.up().unwrap();
+
+        for migration in MIGRATIONS.iter().skip(1).rev() {
+            assert_eq!(
+                migrator.down().unwrap().map(|migration| migration.version),
+                Some(migration.version)
+            );
+        }
+
+        let mut conn = test_db.db.get_conn();
+        let columns: Vec<String> = conn
+            .query(
+                "SELECT column_name FROM information_schema.columns
+                 WHERE table_schema = DATABASE() AND table_name = 'tasks'
+                 ORDER BY ordinal_position",
+            )
+            .unwrap();
+        assert_eq!(columns, vec!["id", "title", "description", "status"]);
+        assert_eq!(tables(&mut conn), vec!["schema_migrations", "tasks"]);
+    }
+}
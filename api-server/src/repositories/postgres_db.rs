@@ -0,0 +1,119 @@
+use crate::application_config::DbConfig;
+use postgres::NoTls;
+use r2d2::{Pool, PooledConnection};
+use r2d2_postgres::PostgresConnectionManager;
+use std::thread;
+use std::time::Duration;
+
+const POSTGRES_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub type PostgresConn = PooledConnection<PostgresConnectionManager<NoTls>>;
+
+pub struct PostgresDb {
+    pool: Pool<PostgresConnectionManager<NoTls>>,
+}
+
+impl PostgresDb {
+    pub fn new(db_config: &DbConfig) -> Result<Self, String> {
+        let config: postgres::Config = db_config
+            .postgres_url("app_db")
+            .parse()
+            .map_err(|e: postgres::Error| e.to_string())?;
+
+        let mut last_err = None;
+        for attempt in 1..10 {
+            match Self::connect(config.clone()) {
+                Ok(db) => {
+                    println!("info: connected to DB (attempt {})", attempt);
+                    return Ok(db);
+                }
+                Err(e) => last_err = Some(e.to_string()),
+            }
+
+            let backoff_sec = 1 << attempt;
+            eprintln!(
+                "waiting for postgres... attempt {}/10, retry in {}s ({})",
+                attempt,
+                backoff_sec,
+                last_err.as_deref().unwrap_or("unknown error"),
+            );
+
+            thread::sleep(Duration::from_secs(backoff_sec));
+        }
+
+        Err(format!(
+            "failed to connect to postgres after 10 attempts: {}",
+            last_err.unwrap_or_default()
+        ))
+    }
+
+    fn connect(config: postgres::Config) -> Result<Self, r2d2::Error> {
+        let pool = Pool::builder()
+            .connection_timeout(POSTGRES_CONNECTION_TIMEOUT)
+            .build(PostgresConnectionManager::new(config, NoTls))?;
+
+        Ok(PostgresDb { pool })
+    }
+
+    pub fn get_conn(&self) -> PostgresConn {
+        self.pool.get().unwrap()
+    }
+
+    pub fn close(self) {
+        drop(self.pool);
+        println!("info: closed DB connections");
+    }
+}
+
+#[cfg(test)]
+pub struct PostgresTestDb {
+    pub db: PostgresDb,
+    admin: postgres::Client,
+    schema: String,
+    url: String,
+}
+
+#[cfg(test)]
+impl PostgresTestDb {
+    pub fn new() -> Self {
+        let url = std::env::var("TEST_POSTGRES_URL")
+            .expect("TEST_POSTGRES_URL must point to a local PostgreSQL instance");
+        let mut config: postgres::Config = url.parse().unwrap();
+        let mut admin = config.connect(NoTls).unwrap();
+        let schema = format!("test_{}", uuid::Uuid::new_v4().simple());
+        admin
+            .batch_execute(&format!("CREATE SCHEMA {}", schema))
+            .unwrap();
+
+        config.options(&format!("-c search_path={}", schema));
+        let db = PostgresDb::connect(config).unwrap();
+        let migration = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../settings/postgres/init/init.sql"),
+        )
+        .unwrap();
+        db.get_conn().batch_execute(&migration).unwrap();
+
+        Self {
+            db,
+            admin,
+            schema,
+            url,
+        }
+    }
+
+    pub fn async_config(&self) -> tokio_postgres::Config {
+        let mut config: tokio_postgres::Config = self.url.parse().unwrap();
+        config.options(format!("-c search_path={}", self.schema));
+        config
+    }
+}
+
+#[cfg(test)]
+impl Drop for PostgresTestDb {
+    fn drop(&mut self) {
+        let _ = self
+            .admin
+            .batch_execute(&format!("DROP SCHEMA {} CASCADE", self.schema));
+    }
+}
@@ -0,0 +1,142 @@
+use rusqlite::{Connection, ffi};
+use std::path::Path;
+use std::time::Duration;
+
+pub const SQLITE_MEMORY_PATH: &str = ":memory:";
+const SQLITE_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+const SQLITE_SCHEMA: &str = "
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS tasks
+(
+    id           TEXT    NOT NULL PRIMARY KEY,
+    title        TEXT    NOT NULL,
+    description  TEXT    NOT NULL,
+    status       INTEGER NOT NULL,
+    priority     INTEGER NOT NULL DEFAULT 2,
+    due_at       TEXT    NULL,
+    due_all_day  INTEGER NOT NULL DEFAULT 0,
+    parent_id    TEXT    NULL REFERENCES tasks (id),
+    recurrence   TEXT    NULL,
+    created_at   TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at   TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at TEXT    NULL,
+    deleted_at   TEXT    NULL,
+    archived_at  TEXT    NULL,
+    version      INTEGER NOT NULL DEFAULT 1
+);
+CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks (status);
+CREATE INDEX IF NOT EXISTS idx_tasks_priority ON tasks (priority, due_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_due_at ON tasks (due_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_parent_id ON tasks (parent_id);
+
+CREATE TABLE IF NOT EXISTS labels
+(
+    id    TEXT NOT NULL PRIMARY KEY,
+    name  TEXT NOT NULL UNIQUE,
+    color TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS task_labels
+(
+    task_id  TEXT NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    label_id TEXT NOT NULL REFERENCES labels (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, label_id)
+);
+CREATE INDEX IF NOT EXISTS idx_task_labels_label_id ON task_labels (label_id);
+
+CREATE TABLE IF NOT EXISTS task_dependencies
+(
+    task_id       TEXT NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    blocked_by_id TEXT NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, blocked_by_id)
+);
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_blocked_by_id ON task_dependencies (blocked_by_id);
+
+CREATE TABLE IF NOT EXISTS task_events
+(
+    id          INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    task_id     TEXT    NOT NULL,
+    kind        TEXT    NOT NULL,
+    actor       TEXT    NULL,
+    changes     TEXT    NOT NULL,
+    occurred_at TEXT    NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_task_events_task_id ON task_events (task_id, id);
+";
+
+pub struct SqliteDb {
+    conn: Connection,
+}
+
+impl SqliteDb {
+    pub fn open(path: &Path) -> Result<Self, rusqlite::Error> {
+        let conn = if path.as_os_str() == SQLITE_MEMORY_PATH {
+            Connection::open_in_memory()?
+        } else {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|_| rusqlite::Error::InvalidPath(path.to_path_buf()))?;
+            }
+            let conn = Connection::open(path)?;
+            conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+            conn
+        };
+        conn.busy_timeout(SQLITE_BUSY_TIMEOUT)?;
+        conn.execute_batch(SQLITE_SCHEMA)?;
+        ensure_column(&conn, "tasks", "version", "INTEGER NOT NULL DEFAULT 1")?;
+        ensure_column(&conn, "tasks", "deleted_at", "TEXT NULL")?;
+        ensure_column(&conn, "tasks", "archived_at", "TEXT NULL")?;
+        conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS idx_tasks_deleted_at ON tasks (deleted_at);
+             CREATE INDEX IF NOT EXISTS idx_tasks_archived_at ON tasks (archived_at);",
+        )?;
+
+        println!("info: opened SQLite DB at {}", path.display());
+        Ok(SqliteDb { conn })
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> Self {
+        Self::open(Path::new(SQLITE_MEMORY_PATH)).unwrap()
+    }
+
+    pub fn get_conn(&self) -> &Connection {
+        &self.conn
+    }
+
+    pub fn close(self) -> Result<(), rusqlite::Error> {
+        self.conn.close().map_err(|(_, e)| e)
+    }
+}
+
+fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), rusqlite::Error> {
+    let exists = conn
+        .prepare(&format!(
+            "SELECT 1 FROM pragma_table_info('{}') WHERE name = ?1",
+            table
+        ))?
+        .exists([column])?;
+    if !exists {
+        conn.execute_batch(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, definition
+        ))?;
+    }
+
+    Ok(())
+}
+
+pub fn is_unique_violation(e: &rusqlite::Error) -> bool {
+    matches!(
+        e,
+        rusqlite::Error::SqliteFailure(err, _)
+            if err.extended_code == ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+                || err.extended_code == ffi::SQLITE_CONSTRAINT_UNIQUE
+    )
+}
@@ -1,3 +1,13 @@
+pub mod task_event_sourced_repository;
 #[cfg(test)]
+pub mod task_failing_repository;
 pub mod task_in_memory_repository;
+pub mod task_keyset;
+#[cfg(test)]
+pub mod task_locked_repository;
 pub mod task_mysql_repository;
+pub mod task_postgres_async_repository;
+pub mod task_postgres_repository;
+#[cfg(test)]
+pub mod task_repository_behavior;
+pub mod task_sqlite_repository;
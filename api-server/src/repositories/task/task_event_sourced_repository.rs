@@ -0,0 +1,1150 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::{TaskEvent, TaskEventKind, TaskFieldChange};
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_recurrence::TaskRecurrence;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+
+pub const TASK_SNAPSHOT_DEFAULT_INTERVAL: usize = 100;
+
+pub struct TaskEventSourcedRepository {
+    workflow: TaskWorkflow,
+    snapshot_path: PathBuf,
+    snapshot_interval: usize,
+    discarded_bytes: usize,
+    state: RefCell<TaskEventSourcedState>,
+}
+
+struct TaskEventSourcedState {
+    tasks: HashMap<TaskId, Task>,
+    history: Vec<TaskEvent>,
+    sequence: u64,
+    since_snapshot: usize,
+    log: File,
+    transaction: Option<TaskEventSourcedTransaction>,
+}
+
+struct TaskEventSourcedTransaction {
+    tasks: HashMap<TaskId, Task>,
+    history: Vec<TaskEvent>,
+    events: Vec<TaskLogEvent>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskLogRecord {
+    sequence: u64,
+    events: Vec<TaskLogEvent>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TaskLogLine {
+    Record(TaskLogRecord),
+    Event(TaskLogEvent),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
+enum TaskLogEvent {
+    TaskRegistered {
+        task: Box<TaskRecord>,
+    },
+    TaskTitleChanged {
+        id: String,
+        title: String,
+    },
+    TaskDescriptionChanged {
+        id: String,
+        description: String,
+    },
+    TaskStatusChanged {
+        id: String,
+        status: i32,
+    },
+    TaskPriorityChanged {
+        id: String,
+        priority: i32,
+    },
+    TaskDueDateChanged {
+        id: String,
+        due_at: Option<String>,
+    },
+    TaskLabelsChanged {
+        id: String,
+        labels: Vec<String>,
+    },
+    TaskParentChanged {
+        id: String,
+        parent_id: Option<String>,
+    },
+    TaskBlockersChanged {
+        id: String,
+        blocked_by: Vec<String>,
+    },
+    TaskRecurrenceChanged {
+        id: String,
+        recurrence: Option<String>,
+    },
+    TaskTimestampsChanged {
+        id: String,
+        updated_at: String,
+        completed_at: Option<String>,
+    },
+    TaskVersionChanged {
+        id: String,
+        version: u64,
+    },
+    TaskTrashed {
+        id: String,
+        deleted_at: String,
+    },
+    TaskRestored {
+        id: String,
+    },
+    TaskArchived {
+        id: String,
+        archived_at: String,
+    },
+    TaskUnarchived {
+        id: String,
+    },
+    TaskDeleted {
+        id: String,
+    },
+    TaskHistoryRecorded {
+        event: TaskHistoryRecord,
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TaskRecord {
+    id: String,
+    title: String,
+    description: String,
+    status: i32,
+    priority: i32,
+    due_at: Option<String>,
+    labels: Vec<String>,
+    parent_id: Option<String>,
+    blocked_by: Vec<String>,
+    recurrence: Option<String>,
+    created_at: String,
+    updated_at: String,
+    completed_at: Option<String>,
+    #[serde(default)]
+    deleted_at: Option<String>,
+    #[serde(default)]
+    archived_at: Option<String>,
+    #[serde(default = "initial_version")]
+    version: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TaskHistoryRecord {
+    task_id: String,
+    kind: String,
+    actor: Option<String>,
+    changes: Vec<TaskFieldChangeRecord>,
+    occurred_at: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TaskFieldChangeRecord {
+    field: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskSnapshot {
+    sequence: u64,
+    tasks: Vec<TaskRecord>,
+    history: Vec<TaskHistoryRecord>,
+}
+
+impl TaskEventSourcedRepository {
+    pub fn open(
+        log_path: &Path,
+        snapshot_interval: usize,
+        workflow: &TaskWorkflow,
+    ) -> Result<Self, TaskRepositoryError> {
+        if let Some(parent) = log_path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut snapshot_path = log_path.as_os_str().to_owned();
+        snapshot_path.push(".snapshot");
+        let snapshot_path = PathBuf::from(snapshot_path);
+
+        let mut tasks = HashMap::new();
+        let mut history = Vec::new();
+        let mut sequence = 0;
+        if snapshot_path.exists() {
+            let snapshot: TaskSnapshot = serde_json::from_reader(BufReader::new(
+                File::open(&snapshot_path).map_err(io_error)?,
+            ))
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            for record in snapshot.tasks {
+                let task = record.into_task(workflow)?;
+                tasks.insert(task.id.clone(), task);
+            }
+            for record in snapshot.history {
+                history.push(record.into_event()?);
+            }
+            sequence = snapshot.sequence;
+        }
+
+        let mut since_snapshot = 0;
+        let content = match log_path.exists() {
+            true => fs::read(log_path).map_err(io_error)?,
+            false => Vec::new(),
+        };
+        let mut valid_length = 0;
+        for (index, line) in content.split_inclusive(|byte| *byte == b'\n').enumerate() {
+            if line.trim_ascii().is_empty() {
+                valid_length += line.len();
+                continue;
+            }
+            let is_last = valid_length + line.len() == content.len();
+            let record = match serde_json::from_slice::<TaskLogLine>(line) {
+                Ok(record) if line.ends_with(b"\n") => record.into_record(index as u64 + 1),
+                Err(e) if !is_last => {
+                    return Err(TaskRepositoryError::DbError(format!(
+                        "line {}: {}",
+                        index + 1,
+                        e
+                    )));
+                }
+                _ => break,
+            };
+            valid_length += line.len();
+            if record.sequence <= sequence {
+                continue;
+            }
+            for event in record.events {
+                apply(&mut tasks, &mut history, event, workflow)?;
+            }
+            sequence = record.sequence;
+            since_snapshot += 1;
+        }
+
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_path)
+            .map_err(io_error)?;
+        if valid_length < content.len() {
+            log.set_len(valid_length as u64)
+                .and_then(|_| log.sync_all())
+                .map_err(io_error)?;
+        }
+
+        Ok(Self {
+            workflow: workflow.clone(),
+            snapshot_path,
+            snapshot_interval: snapshot_interval.max(1),
+            discarded_bytes: content.len() - valid_length,
+            state: RefCell::new(TaskEventSourcedState {
+                tasks,
+                history,
+                sequence,
+                since_snapshot,
+                log,
+                transaction: None,
+            }),
+        })
+    }
+
+    pub fn discarded_bytes(&self) -> usize {
+        self.discarded_bytes
+    }
+
+    fn commit(&self, events: Vec<TaskLogEvent>) -> Result<(), TaskRepositoryError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        let record = TaskLogRecord {
+            sequence: state.sequence + 1,
+            events,
+        };
+        match &mut state.transaction {
+            Some(transaction) => transaction.events.extend(record.events.iter().cloned()),
+            None => write_record(&mut state.log, &record)?,
+        }
+
+        for event in record.events {
+            apply(&mut state.tasks, &mut state.history, event, &self.workflow)?;
+        }
+        if state.transaction.is_none() {
+            state.sequence += 1;
+            state.since_snapshot += 1;
+            self.snapshot_if_due(state)?;
+        }
+
+        Ok(())
+    }
+
+    fn snapshot_if_due(
+        &self,
+        state: &mut TaskEventSourcedState,
+    ) -> Result<(), TaskRepositoryError> {
+        if state.since_snapshot >= self.snapshot_interval {
+            self.write_snapshot(state)?;
+            state
+                .log
+                .set_len(0)
+                .and_then(|_| state.log.sync_all())
+                .map_err(io_error)?;
+            state.since_snapshot = 0;
+        }
+
+        Ok(())
+    }
+
+    fn write_snapshot(&self, state: &TaskEventSourcedState) -> Result<(), TaskRepositoryError> {
+        let snapshot = TaskSnapshot {
+            sequence: state.sequence,
+            tasks: state.tasks.values().map(TaskRecord::from).collect(),
+            history: state.history.iter().map(TaskHistoryRecord::from).collect(),
+        };
+
+        let mut temporary_path = self.snapshot_path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+        let temporary_path = PathBuf::from(temporary_path);
+        let mut file = File::create(&temporary_path).map_err(io_error)?;
+        serde_json::to_writer(&mut file, &snapshot)
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        file.sync_data().map_err(io_error)?;
+        fs::rename(&temporary_path, &self.snapshot_path).map_err(io_error)
+    }
+}
+
+impl TaskRepository for TaskEventSourcedRepository {
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        let state = self.state.borrow();
+        let mut tasks: Vec<Task> = state
+            .tasks
+            .values()
+            .filter(|task| query.matches(task))
+            .cloned()
+            .collect();
+        let total = tasks.len();
+        tasks.retain(|task| query.is_after_cursor(task));
+        tasks.sort_by(|a, b| query.compare(a, b));
+
+        let tasks = tasks
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect();
+
+        Ok(TaskPage { tasks, total })
+    }
+
+    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+        self.state
+            .borrow()
+            .tasks
+            .get(id)
+            .cloned()
+            .ok_or(TaskRepositoryError::NotFound)
+    }
+
+    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        if self.state.borrow().tasks.contains_key(&task.id) {
+            return Err(TaskRepositoryError::AlreadyExists);
+        }
+
+        self.commit(vec![TaskLogEvent::TaskRegistered {
+            task: Box::new(TaskRecord::from(&task)),
+        }])?;
+        Ok(task)
+    }
+
+    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        let current = self.get_by_id(&task.id)?;
+        if current.version != task.version {
+            return Err(TaskRepositoryError::Conflict);
+        }
+
+        let version = task.version + 1;
+        let task = task.change_version(version);
+        self.commit(changes(&current, &task))?;
+        Ok(task)
+    }
+
+    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
+        self.get_by_id(task_id)?;
+
+        self.commit(vec![TaskLogEvent::TaskDeleted {
+            id: task_id.to_string(),
+        }])
+    }
+
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut state = self.state.borrow_mut();
+        if state.transaction.is_some() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        state.transaction = Some(TaskEventSourcedTransaction {
+            tasks: state.tasks.clone(),
+            history: state.history.clone(),
+            events: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        let transaction = state
+            .transaction
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+
+        if transaction.events.is_empty() {
+            return Ok(());
+        }
+        let record = TaskLogRecord {
+            sequence: state.sequence + 1,
+            events: transaction.events,
+        };
+        if let Err(e) = write_record(&mut state.log, &record) {
+            state.tasks = transaction.tasks;
+            state.history = transaction.history;
+            return Err(e);
+        }
+        state.sequence += 1;
+        state.since_snapshot += 1;
+
+        self.snapshot_if_due(state)
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut state = self.state.borrow_mut();
+        let transaction = state
+            .transaction
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+
+        state.tasks = transaction.tasks;
+        state.history = transaction.history;
+        Ok(())
+    }
+}
+
+impl TaskEventRepository for TaskEventSourcedRepository {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        self.commit(vec![TaskLogEvent::TaskHistoryRecorded {
+            event: TaskHistoryRecord::from(&event),
+        }])
+    }
+
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        Ok(self
+            .state
+            .borrow()
+            .history
+            .iter()
+            .filter(|event| event.task_id == *task_id)
+            .cloned()
+            .collect())
+    }
+}
+
+fn changes(current: &Task, task: &Task) -> Vec<TaskLogEvent> {
+    let id = task.id.to_string();
+    let mut events = Vec::new();
+
+    if current.title != task.title {
+        events.push(TaskLogEvent::TaskTitleChanged {
+            id: id.clone(),
+            title: task.title.to_string(),
+        });
+    }
+    if current.description != task.description {
+        events.push(TaskLogEvent::TaskDescriptionChanged {
+            id: id.clone(),
+            description: task.description.to_string(),
+        });
+    }
+    if current.status != task.status {
+        events.push(TaskLogEvent::TaskStatusChanged {
+            id: id.clone(),
+            status: task.status.to_int(),
+        });
+    }
+    if current.priority != task.priority {
+        events.push(TaskLogEvent::TaskPriorityChanged {
+            id: id.clone(),
+            priority: task.priority.to_int(),
+        });
+    }
+    if current.due_at != task.due_at {
+        events.push(TaskLogEvent::TaskDueDateChanged {
+            id: id.clone(),
+            due_at: task.due_at.as_ref().map(|due_at| due_at.to_string()),
+        });
+    }
+    if current.labels != task.labels {
+        events.push(TaskLogEvent::TaskLabelsChanged {
+            id: id.clone(),
+            labels: task.labels.iter().map(|label| label.to_string()).collect(),
+        });
+    }
+    if current.parent_id != task.parent_id {
+        events.push(TaskLogEvent::TaskParentChanged {
+            id: id.clone(),
+            parent_id: task
+                .parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.to_string()),
+        });
+    }
+    if current.blocked_by != task.blocked_by {
+        events.push(TaskLogEvent::TaskBlockersChanged {
+            id: id.clone(),
+            blocked_by: task.blocked_by.iter().map(|id| id.to_string()).collect(),
+        });
+    }
+    if current.recurrence != task.recurrence {
+        events.push(TaskLogEvent::TaskRecurrenceChanged {
+            id: id.clone(),
+            recurrence: task
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+        });
+    }
+    if current.updated_at != task.updated_at || current.completed_at != task.completed_at {
+        events.push(TaskLogEvent::TaskTimestampsChanged {
+            id: id.clone(),
+            updated_at: task.updated_at.to_rfc3339(),
+            completed_at: task
+                .completed_at
+                .map(|completed_at| completed_at.to_rfc3339()),
+        });
+    }
+    if current.deleted_at != task.deleted_at {
+        events.push(match task.deleted_at {
+            Some(deleted_at) => TaskLogEvent::TaskTrashed {
+                id: id.clone(),
+                deleted_at: deleted_at.to_rfc3339(),
+            },
+            None => TaskLogEvent::TaskRestored { id: id.clone() },
+        });
+    }
+    if current.archived_at != task.archived_at {
+        events.push(match task.archived_at {
+            Some(archived_at) => TaskLogEvent::TaskArchived {
+                id: id.clone(),
+                archived_at: archived_at.to_rfc3339(),
+            },
+            None => TaskLogEvent::TaskUnarchived { id: id.clone() },
+        });
+    }
+    if current.version != task.version {
+        events.push(TaskLogEvent::TaskVersionChanged {
+            id,
+            version: task.version,
+        });
+    }
+
+    events
+}
+
+fn apply(
+    tasks: &mut HashMap<TaskId, Task>,
+    history: &mut Vec<TaskEvent>,
+    event: TaskLogEvent,
+    workflow: &TaskWorkflow,
+) -> Result<(), TaskRepositoryError> {
+    match event {
+        TaskLogEvent::TaskRegistered { task } => {
+            let task = task.into_task(workflow)?;
+            tasks.insert(task.id.clone(), task);
+            Ok(())
+        }
+        TaskLogEvent::TaskTitleChanged { id, title } => modify(tasks, &id, |task| {
+            Ok(task.change_title(parse_title(&title)?))
+        }),
+        TaskLogEvent::TaskDescriptionChanged { id, description } => modify(tasks, &id, |task| {
+            Ok(task.change_description(parse_description(&description)?))
+        }),
+        TaskLogEvent::TaskStatusChanged { id, status } => modify(tasks, &id, |task| {
+            Ok(task.change_status(resolve_status(workflow, status)))
+        }),
+        TaskLogEvent::TaskPriorityChanged { id, priority } => modify(tasks, &id, |task| {
+            Ok(task.change_priority(parse_priority(priority)?))
+        }),
+        TaskLogEvent::TaskDueDateChanged { id, due_at } => modify(tasks, &id, |task| {
+            Ok(task.change_due_at(parse_due_at(due_at.as_deref())?))
+        }),
+        TaskLogEvent::TaskLabelsChanged { id, labels } => modify(tasks, &id, |task| {
+            Ok(task.change_labels(parse_labels(&labels)?))
+        }),
+        TaskLogEvent::TaskParentChanged { id, parent_id } => modify(tasks, &id, |task| {
+            let parent_id = parent_id.as_deref().map(parse_task_id).transpose()?;
+            Ok(task.change_parent(parent_id))
+        }),
+        TaskLogEvent::TaskBlockersChanged { id, blocked_by } => modify(tasks, &id, |task| {
+            Ok(task.change_blocked_by(parse_task_ids(&blocked_by)?))
+        }),
+        TaskLogEvent::TaskRecurrenceChanged { id, recurrence } => modify(tasks, &id, |task| {
+            Ok(task.change_recurrence(parse_recurrence(recurrence.as_deref())?))
+        }),
+        TaskLogEvent::TaskTimestampsChanged {
+            id,
+            updated_at,
+            completed_at,
+        } => modify(tasks, &id, |task| {
+            let completed_at = completed_at.as_deref().map(parse_time).transpose()?;
+            Ok(task
+                .change_updated_at(parse_time(&updated_at)?)
+                .change_completed_at(completed_at))
+        }),
+        TaskLogEvent::TaskVersionChanged { id, version } => {
+            modify(tasks, &id, |task| Ok(task.change_version(version)))
+        }
+        TaskLogEvent::TaskTrashed { id, deleted_at } => modify(tasks, &id, |task| {
+            Ok(task.change_deleted_at(Some(parse_time(&deleted_at)?)))
+        }),
+        TaskLogEvent::TaskRestored { id } => {
+            modify(tasks, &id, |task| Ok(task.change_deleted_at(None)))
+        }
+        TaskLogEvent::TaskArchived { id, archived_at } => modify(tasks, &id, |task| {
+            Ok(task.change_archived_at(Some(parse_time(&archived_at)?)))
+        }),
+        TaskLogEvent::TaskUnarchived { id } => {
+            modify(tasks, &id, |task| Ok(task.change_archived_at(None)))
+        }
+        TaskLogEvent::TaskDeleted { id } => {
+            let id = parse_task_id(&id)?;
+            tasks.remove(&id);
+            for task in tasks.values_mut() {
+                task.blocked_by.retain(|blocker_id| *blocker_id != id);
+            }
+            Ok(())
+        }
+        TaskLogEvent::TaskHistoryRecorded { event } => {
+            history.push(event.into_event()?);
+            Ok(())
+        }
+    }
+}
+
+fn modify(
+    tasks: &mut HashMap<TaskId, Task>,
+    id: &str,
+    change: impl FnOnce(Task) -> Result<Task, TaskRepositoryError>,
+) -> Result<(), TaskRepositoryError> {
+    let id = parse_task_id(id)?;
+    let task = tasks
+        .remove(&id)
+        .ok_or_else(|| corrupted("task reference"))?;
+    tasks.insert(id, change(task)?);
+    Ok(())
+}
+
+impl TaskRecord {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id.to_string(),
+            title: task.title.to_string(),
+            description: task.description.to_string(),
+            status: task.status.to_int(),
+            priority: task.priority.to_int(),
+            due_at: task.due_at.as_ref().map(|due_at| due_at.to_string()),
+            labels: task.labels.iter().map(|label| label.to_string()).collect(),
+            parent_id: task
+                .parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.to_string()),
+            blocked_by: task.blocked_by.iter().map(|id| id.to_string()).collect(),
+            recurrence: task
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+            created_at: task.created_at.to_rfc3339(),
+            updated_at: task.updated_at.to_rfc3339(),
+            completed_at: task
+                .completed_at
+                .map(|completed_at| completed_at.to_rfc3339()),
+            deleted_at: task.deleted_at.map(|deleted_at| deleted_at.to_rfc3339()),
+            archived_at: task.archived_at.map(|archived_at| archived_at.to_rfc3339()),
+            version: task.version,
+        }
+    }
+
+    fn into_task(self, workflow: &TaskWorkflow) -> Result<Task, TaskRepositoryError> {
+        Ok(Task::new(
+            parse_task_id(&self.id)?,
+            parse_title(&self.title)?,
+            parse_description(&self.description)?,
+            resolve_status(workflow, self.status),
+            parse_time(&self.created_at)?,
+        )
+        .change_priority(parse_priority(self.priority)?)
+        .change_due_at(parse_due_at(self.due_at.as_deref())?)
+        .change_labels(parse_labels(&self.labels)?)
+        .change_parent(self.parent_id.as_deref().map(parse_task_id).transpose()?)
+        .change_blocked_by(parse_task_ids(&self.blocked_by)?)
+        .change_recurrence(parse_recurrence(self.recurrence.as_deref())?)
+        .change_updated_at(parse_time(&self.updated_at)?)
+        .change_completed_at(self.completed_at.as_deref().map(parse_time).transpose()?)
+        .change_deleted_at(self.deleted_at.as_deref().map(parse_time).transpose()?)
+        .change_archived_at(self.archived_at.as_deref().map(parse_time).transpose()?)
+        .change_version(self.version))
+    }
+}
+
+impl TaskHistoryRecord {
+    fn from(event: &TaskEvent) -> Self {
+        Self {
+            task_id: event.task_id.to_string(),
+            kind: event.kind.to_string(),
+            actor: event.actor.clone(),
+            changes: event
+                .changes
+                .iter()
+                .map(|change| TaskFieldChangeRecord {
+                    field: change.field.clone(),
+                    before: change.before.clone(),
+                    after: change.after.clone(),
+                })
+                .collect(),
+            occurred_at: event.occurred_at.to_rfc3339(),
+        }
+    }
+
+    fn into_event(self) -> Result<TaskEvent, TaskRepositoryError> {
+        Ok(TaskEvent {
+            task_id: parse_task_id(&self.task_id)?,
+            kind: TaskEventKind::try_from(self.kind.as_str()).map_err(|_| corrupted("kind"))?,
+            changes: self
+                .changes
+                .into_iter()
+                .map(|change| TaskFieldChange {
+                    field: change.field,
+                    before: change.before,
+                    after: change.after,
+                })
+                .collect(),
+            actor: self.actor,
+            occurred_at: parse_time(&self.occurred_at)?,
+        })
+    }
+}
+
+fn initial_version() -> u64 {
+    1
+}
+
+fn resolve_status(workflow: &TaskWorkflow, status: i32) -> TaskStatus {
+    workflow
+        .status(status)
+        .unwrap_or_else(|_| TaskStatus::new(status, TaskStatusCategory::Todo))
+}
+
+fn parse_task_id(value: &str) -> Result<TaskId, TaskRepositoryError> {
+    TaskId::try_from(value).map_err(|_| corrupted("task id"))
+}
+
+fn parse_task_ids(values: &[String]) -> Result<Vec<TaskId>, TaskRepositoryError> {
+    values.iter().map(|value| parse_task_id(value)).collect()
+}
+
+fn parse_title(value: &str) -> Result<TaskTitle, TaskRepositoryError> {
+    TaskTitle::try_from(value).map_err(|_| corrupted("title"))
+}
+
+fn parse_description(value: &str) -> Result<TaskDescription, TaskRepositoryError> {
+    TaskDescription::try_from(value).map_err(|_| corrupted("description"))
+}
+
+fn parse_priority(value: i32) -> Result<TaskPriority, TaskRepositoryError> {
+    TaskPriority::try_from(value).map_err(|_| corrupted("priority"))
+}
+
+fn parse_due_at(value: Option<&str>) -> Result<Option<TaskDueDate>, TaskRepositoryError> {
+    value
+        .map(TaskDueDate::try_from)
+        .transpose()
+        .map_err(|_| corrupted("due date"))
+}
+
+fn parse_labels(values: &[String]) -> Result<Vec<LabelId>, TaskRepositoryError> {
+    values
+        .iter()
+        .map(|value| LabelId::try_from(value.as_str()).map_err(|_| corrupted("label id")))
+        .collect()
+}
+
+fn parse_recurrence(value: Option<&str>) -> Result<Option<TaskRecurrence>, TaskRepositoryError> {
+    value
+        .map(TaskRecurrence::try_from)
+        .transpose()
+        .map_err(|_| corrupted("recurrence"))
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>, TaskRepositoryError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|value| value.with_timezone(&Utc))
+        .map_err(|_| corrupted("timestamp"))
+}
+
+fn corrupted(field: &str) -> TaskRepositoryError {
+    TaskRepositoryError::DbError(format!("corrupted event log: invalid {}", field))
+}
+
+impl TaskLogLine {
+    fn into_record(self, line_number: u64) -> TaskLogRecord {
+        match self {
+            TaskLogLine::Record(record) => record,
+            TaskLogLine::Event(event) => TaskLogRecord {
+                sequence: line_number,
+                events: vec![event],
+            },
+        }
+    }
+}
+
+fn write_record(log: &mut File, record: &TaskLogRecord) -> Result<(), TaskRepositoryError> {
+    let mut line =
+        serde_json::to_string(record).map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+    line.push('\n');
+    log.write_all(line.as_bytes())
+        .and_then(|_| log.sync_data())
+        .map_err(io_error)
+}
+
+fn io_error(e: std::io::Error) -> TaskRepositoryError {
+    TaskRepositoryError::DbError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::task::task_repository_behavior::{self, register_test_data};
+    use uuid::Uuid;
+
+    struct TemporaryLog {
+        directory: PathBuf,
+        workflow: TaskWorkflow,
+    }
+
+    impl TemporaryLog {
+        fn new() -> Self {
+            Self {
+                directory: std::env::temp_dir().join(format!("task-events-{}", Uuid::new_v4())),
+                workflow: TaskWorkflow::default(),
+            }
+        }
+
+        fn path(&self) -> PathBuf {
+            self.directory.join("tasks.log")
+        }
+
+        fn open(&self, snapshot_interval: usize) -> TaskEventSourcedRepository {
+            TaskEventSourcedRepository::open(&self.path(), snapshot_interval, &self.workflow)
+                .unwrap()
+        }
+    }
+
+    impl Drop for TemporaryLog {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+    }
+
+    #[test]
+    fn search_when_tasks_are_registered_then_returns_task_list() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_tasks_are_registered_then_returns_task_list(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_tasks_are_not_registered_then_returns_empty_list() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_tasks_are_not_registered_then_returns_empty_list(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_status_filter_then_returns_matching_tasks() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_status_filter_then_returns_matching_tasks(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_limit_then_returns_page_and_total() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_query_has_limit_then_returns_page_and_total(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_cursor_then_returns_tasks_after_it() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_query_has_cursor_then_returns_tasks_after_it(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_due_filters_then_returns_matching_tasks() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_due_filters_then_returns_matching_tasks(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_sorted_by_priority_then_orders_by_priority_then_due_date(log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL));
+    }
+
+    #[test]
+    fn search_when_label_filter_then_returns_labeled_tasks() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_label_filter_then_returns_labeled_tasks(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn get_by_id_when_valid_value_then_returns_task() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::get_by_id_when_valid_value_then_returns_task(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn register_when_valid_value_then_returns_task() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::register_when_valid_value_then_returns_task(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_task_is_trashed_then_returns_it_only_from_trash() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_task_is_trashed_then_returns_it_only_from_trash(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::search_when_task_is_archived_then_returns_it_only_when_archived_are_included(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn rollback_transaction_when_changes_were_made_then_discards_them() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::rollback_transaction_when_changes_were_made_then_discards_them(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn commit_transaction_when_changes_were_made_then_keeps_them() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::commit_transaction_when_changes_were_made_then_keeps_them(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn update_when_valid_value_then_returns_task() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::update_when_valid_value_then_returns_task(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn update_when_version_is_stale_then_returns_conflict() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::update_when_version_is_stale_then_returns_conflict(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn delete_when_valid_id_then_returns_empty_task() {
+        let log = TemporaryLog::new();
+        task_repository_behavior::delete_when_valid_id_then_returns_empty_task(
+            log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL),
+        );
+    }
+
+    #[test]
+    fn open_when_log_exists_then_rebuilds_state_by_replay() {
+        let log = TemporaryLog::new();
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        let ids = register_test_data(&repository);
+        let task = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_title(TaskTitle::try_from("AAA2").unwrap())
+            .change_status(TaskStatus::new(3, TaskStatusCategory::Done))
+            .add_blocker(ids[2].clone());
+        repository.update(task).unwrap();
+        repository.delete(&ids[2]).unwrap();
+        let event = TaskEvent::deleted(&repository.get_by_id(&ids[1]).unwrap(), None, Utc::now());
+        repository.append(event).unwrap();
+        drop(repository);
+
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        assert_eq!(task.title.to_string(), "AAA2");
+        assert!(task.status.is_done());
+        assert!(task.blocked_by.is_empty());
+        assert_eq!(task.version, 2);
+        assert!(repository.get_by_id(&ids[2]).is_err());
+        assert_eq!(repository.history(&ids[1]).unwrap().len(), 1);
+
+        let content = fs::read_to_string(log.path()).unwrap();
+        for kind in [
+            "TaskRegistered",
+            "TaskTitleChanged",
+            "TaskStatusChanged",
+            "TaskVersionChanged",
+            "TaskDeleted",
+            "TaskHistoryRecorded",
+        ] {
+            assert!(content.contains(&format!("\"type\":\"{}\"", kind)));
+        }
+    }
+
+    #[test]
+    fn commit_when_snapshot_interval_reached_then_writes_snapshot() {
+        let log = TemporaryLog::new();
+        let repository = log.open(2);
+        let ids = register_test_data(&repository);
+        let snapshot: TaskSnapshot =
+            serde_json::from_str(&fs::read_to_string(&repository.snapshot_path).unwrap()).unwrap();
+        assert_eq!(snapshot.sequence, 2);
+        assert_eq!(snapshot.tasks.len(), 2);
+        assert_eq!(fs::read_to_string(log.path()).unwrap().lines().count(), 1);
+        drop(repository);
+
+        let repository = log.open(2);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+        assert_eq!(repository.state.borrow().sequence, 3);
+        assert_eq!(repository.state.borrow().since_snapshot, 1);
+        assert_eq!(
+            repository.get_by_id(&ids[2]).unwrap().title.to_string(),
+            "CCC"
+        );
+    }
+
+    #[test]
+    fn open_when_log_is_corrupted_then_returns_error() {
+        let log = TemporaryLog::new();
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        register_test_data(&repository);
+        drop(repository);
+
+        let content = fs::read_to_string(log.path()).unwrap();
+        fs::write(
+            log.path(),
+            format!("{{\"type\":\"TaskTitleChanged\"\n{}", content),
+        )
+        .unwrap();
+
+        let result = TaskEventSourcedRepository::open(&log.path(), 100, &log.workflow);
+        assert!(matches!(result, Err(TaskRepositoryError::DbError(_))));
+    }
+
+    #[test]
+    fn open_when_last_record_is_torn_then_discards_it() {
+        let log = TemporaryLog::new();
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        let ids = register_test_data(&repository);
+        drop(repository);
+        let content = fs::read_to_string(log.path()).unwrap();
+
+        let torn = b"{\"sequence\":4,\"events\":[{\"type\":\"TaskDel";
+        let mut file = OpenOptions::new().append(true).open(log.path()).unwrap();
+        file.write_all(torn).unwrap();
+
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        assert_eq!(repository.discarded_bytes(), torn.len());
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+        assert_eq!(fs::read_to_string(log.path()).unwrap(), content);
+        repository.delete(&ids[0]).unwrap();
+        drop(repository);
+
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        assert_eq!(repository.discarded_bytes(), 0);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 2);
+    }
+
+    #[test]
+    fn commit_when_transaction_has_several_events_then_writes_one_record() {
+        let log = TemporaryLog::new();
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        repository.begin_transaction().unwrap();
+        register_test_data(&repository);
+        repository.commit_transaction().unwrap();
+
+        let content = fs::read_to_string(log.path()).unwrap();
+        assert_eq!(content.lines().count(), 1);
+        let record: TaskLogRecord = serde_json::from_str(content.trim_end()).unwrap();
+        assert_eq!(record.sequence, 1);
+        assert_eq!(record.events.len(), 3);
+    }
+
+    #[test]
+    fn open_when_log_has_single_event_lines_then_replays_them() {
+        let log = TemporaryLog::new();
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        register_test_data(&repository);
+        drop(repository);
+
+        let legacy: String = fs::read_to_string(log.path())
+            .unwrap()
+            .lines()
+            .flat_map(|line| serde_json::from_str::<TaskLogRecord>(line).unwrap().events)
+            .map(|event| serde_json::to_string(&event).unwrap() + "\n")
+            .collect();
+        fs::write(log.path(), legacy).unwrap();
+
+        let repository = log.open(TASK_SNAPSHOT_DEFAULT_INTERVAL);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+        assert_eq!(repository.state.borrow().sequence, 3);
+    }
+}
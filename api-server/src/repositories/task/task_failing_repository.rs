@@ -0,0 +1,106 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+use std::cell::Cell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskFailingOperation {
+    Search,
+    GetById,
+    Register,
+    Update,
+    Delete,
+    Append,
+    History,
+}
+
+pub struct TaskFailingRepository {
+    inner: TaskInMemoryRepository,
+    operation: TaskFailingOperation,
+    successful_calls: Cell<usize>,
+}
+
+impl TaskFailingRepository {
+    pub fn new(inner: TaskInMemoryRepository, operation: TaskFailingOperation) -> Self {
+        Self {
+            inner,
+            operation,
+            successful_calls: Cell::new(0),
+        }
+    }
+
+    pub fn after(self, successful_calls: usize) -> Self {
+        self.successful_calls.set(successful_calls);
+        self
+    }
+
+    fn check(&self, operation: TaskFailingOperation) -> Result<(), TaskRepositoryError> {
+        if operation != self.operation {
+            return Ok(());
+        }
+        match self.successful_calls.get() {
+            0 => Err(TaskRepositoryError::DbError(String::from(
+                "injected failure",
+            ))),
+            remaining => {
+                self.successful_calls.set(remaining - 1);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TaskRepository for TaskFailingRepository {
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        self.check(TaskFailingOperation::Search)?;
+        self.inner.search(query)
+    }
+
+    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+        self.check(TaskFailingOperation::GetById)?;
+        self.inner.get_by_id(id)
+    }
+
+    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.check(TaskFailingOperation::Register)?;
+        self.inner.register(task)
+    }
+
+    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.check(TaskFailingOperation::Update)?;
+        self.inner.update(task)
+    }
+
+    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
+        self.check(TaskFailingOperation::Delete)?;
+        self.inner.delete(task_id)
+    }
+
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        self.inner.begin_transaction()
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        self.inner.commit_transaction()
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        self.inner.rollback_transaction()
+    }
+}
+
+impl TaskEventRepository for TaskFailingRepository {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        self.check(TaskFailingOperation::Append)?;
+        self.inner.append(event)
+    }
+
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        self.check(TaskFailingOperation::History)?;
+        self.inner.history(task_id)
+    }
+}
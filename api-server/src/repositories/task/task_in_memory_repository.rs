@@ -1,27 +1,50 @@
 use crate::domain::task::task::Task;
-use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
 use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
 use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
-use crate::domain::task::task_status::TaskStatus;
-use crate::domain::task::task_title::TaskTitle;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+type TaskInMemorySavepoint = (HashMap<TaskId, Task>, Vec<TaskEvent>);
+
 pub struct TaskInMemoryRepository {
     data: RefCell<HashMap<TaskId, Task>>,
+    events: RefCell<Vec<TaskEvent>>,
+    savepoint: RefCell<Option<TaskInMemorySavepoint>>,
 }
 
 impl TaskInMemoryRepository {
     pub fn new() -> Self {
         Self {
             data: RefCell::new(HashMap::new()),
+            events: RefCell::new(Vec::new()),
+            savepoint: RefCell::new(None),
         }
     }
 }
 
 impl TaskRepository for TaskInMemoryRepository {
-    fn list(&self) -> Result<Vec<Task>, TaskRepositoryError> {
-        Ok(self.data.borrow().values().cloned().collect())
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        let mut tasks: Vec<Task> = self
+            .data
+            .borrow()
+            .values()
+            .filter(|task| query.matches(task))
+            .cloned()
+            .collect();
+        let total = tasks.len();
+        tasks.retain(|task| query.is_after_cursor(task));
+        tasks.sort_by(|a, b| query.compare(a, b));
+
+        let tasks = tasks
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect();
+
+        Ok(TaskPage { tasks, total })
     }
 
     fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
@@ -46,10 +69,13 @@ impl TaskRepository for TaskInMemoryRepository {
     fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
         let mut data = self.data.borrow_mut();
 
-        if !data.contains_key(&task.id) {
-            return Err(TaskRepositoryError::NotFound);
+        let current = data.get(&task.id).ok_or(TaskRepositoryError::NotFound)?;
+        if current.version != task.version {
+            return Err(TaskRepositoryError::Conflict);
         }
 
+        let version = task.version + 1;
+        let task = task.change_version(version);
         data.insert(task.id.clone(), task.clone());
         Ok(task)
     }
@@ -58,13 +84,67 @@ impl TaskRepository for TaskInMemoryRepository {
         let mut data = self.data.borrow_mut();
 
         data.remove(task_id).ok_or(TaskRepositoryError::NotFound)?;
+        for task in data.values_mut() {
+            task.blocked_by.retain(|blocker_id| blocker_id != task_id);
+        }
         Ok(())
     }
+
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut savepoint = self.savepoint.borrow_mut();
+        if savepoint.is_some() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        *savepoint = Some((self.data.borrow().clone(), self.events.borrow().clone()));
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        self.savepoint
+            .borrow_mut()
+            .take()
+            .map(|_| ())
+            .ok_or(TaskRepositoryError::TransactionState)
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let (data, events) = self
+            .savepoint
+            .borrow_mut()
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+        *self.data.borrow_mut() = data;
+        *self.events.borrow_mut() = events;
+        Ok(())
+    }
+}
+
+impl TaskEventRepository for TaskInMemoryRepository {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        self.events.borrow_mut().push(event);
+        Ok(())
+    }
+
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        Ok(self
+            .events
+            .borrow()
+            .iter()
+            .filter(|event| event.task_id == *task_id)
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(test)]
 impl TaskInMemoryRepository {
     pub fn register_test_data(&mut self) -> Vec<TaskId> {
+        use crate::domain::task::task_description::TaskDescription;
+        use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+        use crate::domain::task::task_title::TaskTitle;
+        use chrono::DateTime;
+
         let mut ids = Vec::new();
         let data = [("AAA", "AAA"), ("BBB", "BBB"), ("CCC", "CCC")];
 
@@ -75,7 +155,8 @@ impl TaskInMemoryRepository {
                 TaskId::new(),
                 TaskTitle::try_from(title).unwrap(),
                 TaskDescription::try_from(description).unwrap(),
-                TaskStatus::Todo,
+                TaskStatus::new(1, TaskStatusCategory::Todo),
+                DateTime::UNIX_EPOCH,
             );
 
             ids.push(task.id.clone());
@@ -89,70 +170,122 @@ impl TaskInMemoryRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::repositories::task::task_repository_behavior;
 
     #[test]
-    fn list_when_tasks_are_registered_then_returns_task_list() {
-        let mut repository = TaskInMemoryRepository::new();
-        repository.register_test_data();
+    fn search_when_tasks_are_registered_then_returns_task_list() {
+        task_repository_behavior::search_when_tasks_are_registered_then_returns_task_list(
+            TaskInMemoryRepository::new(),
+        );
+    }
 
-        let list = repository.list();
-        assert_eq!(list.unwrap().len(), 3);
+    #[test]
+    fn search_when_tasks_are_not_registered_then_returns_empty_list() {
+        task_repository_behavior::search_when_tasks_are_not_registered_then_returns_empty_list(
+            TaskInMemoryRepository::new(),
+        );
     }
 
     #[test]
-    fn list_when_tasks_are_not_registered_then_returns_empty_list() {
-        let repository = TaskInMemoryRepository::new();
-        let list = repository.list();
-        assert_eq!(list.unwrap().len(), 0);
+    fn search_when_status_filter_then_returns_matching_tasks() {
+        task_repository_behavior::search_when_status_filter_then_returns_matching_tasks(
+            TaskInMemoryRepository::new(),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_limit_then_returns_page_and_total() {
+        task_repository_behavior::search_when_query_has_limit_then_returns_page_and_total(
+            TaskInMemoryRepository::new(),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_cursor_then_returns_tasks_after_it() {
+        task_repository_behavior::search_when_query_has_cursor_then_returns_tasks_after_it(
+            TaskInMemoryRepository::new(),
+        );
+    }
+
+    #[test]
+    fn search_when_due_filters_then_returns_matching_tasks() {
+        task_repository_behavior::search_when_due_filters_then_returns_matching_tasks(
+            TaskInMemoryRepository::new(),
+        );
+    }
+
+    #[test]
+    fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date() {
+        task_repository_behavior::search_when_sorted_by_priority_then_orders_by_priority_then_due_date(TaskInMemoryRepository::new());
+    }
+
+    #[test]
+    fn search_when_label_filter_then_returns_labeled_tasks() {
+        task_repository_behavior::search_when_label_filter_then_returns_labeled_tasks(
+            TaskInMemoryRepository::new(),
+        );
     }
 
     #[test]
     fn get_by_id_when_valid_value_then_returns_task() {
-        let mut repository = TaskInMemoryRepository::new();
-        let ids = repository.register_test_data();
-        let task = repository.get_by_id(&ids[0]).ok().unwrap();
-        assert_eq!(task.id, ids[0]);
-        assert_eq!(task.title.to_string(), "AAA");
-        assert_eq!(task.description.to_string(), "AAA");
+        task_repository_behavior::get_by_id_when_valid_value_then_returns_task(
+            TaskInMemoryRepository::new(),
+        );
     }
 
     #[test]
     fn register_when_valid_value_then_returns_task() {
-        let mut repository = TaskInMemoryRepository::new();
-        repository.register_test_data();
+        task_repository_behavior::register_when_valid_value_then_returns_task(
+            TaskInMemoryRepository::new(),
+        );
+    }
 
-        let task_id = TaskId::new();
-        let task_title = TaskTitle::try_from("DDD").unwrap();
-        let task_description = TaskDescription::try_from("DDD").unwrap();
-        let task = Task::new(task_id, task_title, task_description, TaskStatus::Todo);
-        let task = repository.register(task).ok().unwrap();
-        assert_eq!(task.title.to_string(), "DDD");
-        assert_eq!(task.description.to_string(), "DDD");
+    #[test]
+    fn search_when_task_is_trashed_then_returns_it_only_from_trash() {
+        task_repository_behavior::search_when_task_is_trashed_then_returns_it_only_from_trash(
+            TaskInMemoryRepository::new(),
+        );
     }
 
     #[test]
-    fn update_when_valid_value_then_returns_task() {
-        let mut repository = TaskInMemoryRepository::new();
-        let ids = repository.register_test_data();
+    fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included() {
+        task_repository_behavior::search_when_task_is_archived_then_returns_it_only_when_archived_are_included(
+            TaskInMemoryRepository::new(),
+        );
+    }
 
-        let task_id = ids[0].clone();
-        let task_title = TaskTitle::try_from("AAA2").unwrap();
-        let task_description = TaskDescription::try_from("AAA2").unwrap();
-        let task = Task::new(task_id, task_title, task_description, TaskStatus::Todo);
-        let task = repository.update(task).ok().unwrap();
-        assert_eq!(task.title.to_string(), "AAA2");
-        assert_eq!(task.description.to_string(), "AAA2");
+    #[test]
+    fn rollback_transaction_when_changes_were_made_then_discards_them() {
+        task_repository_behavior::rollback_transaction_when_changes_were_made_then_discards_them(
+            TaskInMemoryRepository::new(),
+        );
     }
 
     #[test]
-    fn delete_when_valid_id_then_returns_empty_task() {
-        let mut repository = TaskInMemoryRepository::new();
-        let ids = repository.register_test_data();
+    fn commit_transaction_when_changes_were_made_then_keeps_them() {
+        task_repository_behavior::commit_transaction_when_changes_were_made_then_keeps_them(
+            TaskInMemoryRepository::new(),
+        );
+    }
+
+    #[test]
+    fn update_when_valid_value_then_returns_task() {
+        task_repository_behavior::update_when_valid_value_then_returns_task(
+            TaskInMemoryRepository::new(),
+        );
+    }
 
-        let task_id = ids[0].clone();
-        let _ = repository.delete(&task_id);
+    #[test]
+    fn update_when_version_is_stale_then_returns_conflict() {
+        task_repository_behavior::update_when_version_is_stale_then_returns_conflict(
+            TaskInMemoryRepository::new(),
+        );
+    }
 
-        let list = repository.list();
-        assert_eq!(list.unwrap().len(), 2);
+    #[test]
+    fn delete_when_valid_id_then_returns_empty_task() {
+        task_repository_behavior::delete_when_valid_id_then_returns_empty_task(
+            TaskInMemoryRepository::new(),
+        );
     }
 }
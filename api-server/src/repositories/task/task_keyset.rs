@@ -0,0 +1,79 @@
+use crate::domain::task::task_cursor::TaskCursor;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_query::{SortOrder, TaskSortKey};
+use chrono::{DateTime, Utc};
+
+pub enum TaskKeysetParam {
+    Text(String),
+    Int(i32),
+    DateTime(DateTime<Utc>),
+    Id(TaskId),
+}
+
+pub fn keyset_condition(cursor: &TaskCursor) -> (String, Vec<TaskKeysetParam>) {
+    let op = match cursor.order {
+        SortOrder::Asc => ">",
+        SortOrder::Desc => "<",
+    };
+    let id = || TaskKeysetParam::Id(cursor.id.clone());
+
+    match cursor.sort {
+        TaskSortKey::Id => (format!("id {} ?", op), vec![id()]),
+        TaskSortKey::Title => (
+            format!("(title {} ? OR (title = ? AND id {} ?))", op, op),
+            vec![
+                TaskKeysetParam::Text(cursor.title.clone()),
+                TaskKeysetParam::Text(cursor.title.clone()),
+                id(),
+            ],
+        ),
+        TaskSortKey::Status => (
+            format!("(status {} ? OR (status = ? AND id {} ?))", op, op),
+            vec![
+                TaskKeysetParam::Int(cursor.status),
+                TaskKeysetParam::Int(cursor.status),
+                id(),
+            ],
+        ),
+        TaskSortKey::Priority => {
+            let (due_condition, due_params) = due_at_condition(cursor.due_at, &cursor.id, ">");
+            let mut params = vec![
+                TaskKeysetParam::Int(cursor.priority.to_int()),
+                TaskKeysetParam::Int(cursor.priority.to_int()),
+            ];
+            params.extend(due_params);
+            (
+                format!(
+                    "(priority {} ? OR (priority = ? AND {}))",
+                    op, due_condition
+                ),
+                params,
+            )
+        }
+        TaskSortKey::DueAt => due_at_condition(cursor.due_at, &cursor.id, op),
+    }
+}
+
+fn due_at_condition(
+    due_at: Option<DateTime<Utc>>,
+    id: &TaskId,
+    op: &str,
+) -> (String, Vec<TaskKeysetParam>) {
+    match due_at {
+        Some(due_at) => (
+            format!(
+                "(due_at {} ? OR (due_at = ? AND id {} ?) OR due_at IS NULL)",
+                op, op
+            ),
+            vec![
+                TaskKeysetParam::DateTime(due_at),
+                TaskKeysetParam::DateTime(due_at),
+                TaskKeysetParam::Id(id.clone()),
+            ],
+        ),
+        None => (
+            format!("(due_at IS NULL AND id {} ?)", op),
+            vec![TaskKeysetParam::Id(id.clone())],
+        ),
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::task::task_async_repository::TaskAsyncRepository;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+use std::sync::{Mutex, MutexGuard};
+
+pub struct TaskLockedRepository {
+    inner: Mutex<TaskInMemoryRepository>,
+}
+
+impl TaskLockedRepository {
+    pub fn new(inner: TaskInMemoryRepository) -> Self {
+        Self {
+            inner: Mutex::new(inner),
+        }
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, TaskInMemoryRepository> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl TaskAsyncRepository for TaskLockedRepository {
+    async fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        self.inner.lock().unwrap().search(query)
+    }
+}
@@ -1,114 +1,859 @@
+use crate::domain::label::label_id::LabelId;
 use crate::domain::task::task::Task;
 use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::{TaskEvent, TaskEventKind, TaskFieldChange};
+use crate::domain::task::task_event_repository::TaskEventRepository;
 use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{
+    SortOrder, TaskPage, TaskQuery, TaskSortKey, TaskTrashFilter,
+};
+use crate::domain::task::task_recurrence::TaskRecurrence;
 use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
-use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
 use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
 use crate::repositories::app_db::AppDb;
-use mysql::params;
+use crate::repositories::task::task_keyset::{TaskKeysetParam, keyset_condition};
+use chrono::NaiveDateTime;
 use mysql::prelude::*;
+use mysql::{Params, PooledConn, Row, Value, params};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+const ER_DUP_ENTRY: u16 = 1062;
+const TASK_COLUMNS: &str = "id, title, description, status, priority, due_at, due_all_day, parent_id, recurrence, \
+     created_at, updated_at, completed_at, deleted_at, archived_at, version";
 
 pub struct TaskMysqlRepository<'a> {
     db: &'a AppDb,
+    workflow: &'a TaskWorkflow,
+    transaction: RefCell<Option<PooledConn>>,
 }
 
 impl<'a> TaskMysqlRepository<'a> {
-    pub fn new(db: &'a AppDb) -> Self {
-        Self { db }
+    pub fn new(db: &'a AppDb, workflow: &'a TaskWorkflow) -> Self {
+        Self {
+            db,
+            workflow,
+            transaction: RefCell::new(None),
+        }
+    }
+
+    fn with_conn<R>(
+        &self,
+        f: impl FnOnce(&mut PooledConn) -> Result<R, TaskRepositoryError>,
+    ) -> Result<R, TaskRepositoryError> {
+        match self.transaction.borrow_mut().as_mut() {
+            Some(conn) => f(conn),
+            None => f(&mut self.db.get_conn()),
+        }
+    }
+
+    fn with_transaction<R>(
+        &self,
+        f: impl FnOnce(&mut PooledConn) -> Result<R, TaskRepositoryError>,
+    ) -> Result<R, TaskRepositoryError> {
+        if let Some(conn) = self.transaction.borrow_mut().as_mut() {
+            return f(conn);
+        }
+
+        let mut conn = self.db.get_conn();
+        conn.query_drop("START TRANSACTION")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        match f(&mut conn) {
+            Ok(result) => {
+                conn.query_drop("COMMIT")
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                Ok(result)
+            }
+            Err(e) => {
+                let _ = conn.query_drop("ROLLBACK");
+                Err(e)
+            }
+        }
     }
 }
 
 impl<'a> TaskRepository for TaskMysqlRepository<'a> {
-    fn list(&self) -> Result<Vec<Task>, TaskRepositoryError> {
-        let mut conn = self.db.get_conn();
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let mut conditions = Vec::new();
+            let mut params: Vec<Value> = Vec::new();
+            if let Some(status) = &query.status {
+                conditions.push("status = ?");
+                params.push(status.to_int().into());
+            }
+            if let Some(priority) = &query.priority {
+                conditions.push("priority = ?");
+                params.push(priority.to_int().into());
+            }
+            if let Some(label) = &query.label {
+                conditions.push("id IN (SELECT task_id FROM task_labels WHERE label_id = ?)");
+                params.push(label.to_string().into());
+            }
+            match &query.parent {
+                Some(Some(parent_id)) => {
+                    conditions.push("parent_id = ?");
+                    params.push(parent_id.to_string().into());
+                }
+                Some(None) => conditions.push("parent_id IS NULL"),
+                None => {}
+            }
+            if let Some(due_before) = query.due_before {
+                conditions.push("due_at <= ?");
+                params.push(due_before.naive_utc().into());
+            }
+            match query.trash {
+                TaskTrashFilter::Exclude => conditions.push("deleted_at IS NULL"),
+                TaskTrashFilter::Only => conditions.push("deleted_at IS NOT NULL"),
+                TaskTrashFilter::Include => {}
+            }
+            if let Some(deleted_before) = query.deleted_before {
+                conditions.push("deleted_at <= ?");
+                params.push(deleted_before.naive_utc().into());
+            }
+            if !query.include_archived {
+                conditions.push("archived_at IS NULL");
+            }
+            if let Some(completed_before) = query.completed_before {
+                conditions.push("completed_at <= ?");
+                params.push(completed_before.naive_utc().into());
+            }
+            let overdue_condition;
+            if let Some(overdue_at) = query.overdue_at {
+                let done_statuses = self.workflow.done_statuses();
+                overdue_condition = if done_statuses.is_empty() {
+                    String::from("due_at <= ?")
+                } else {
+                    format!(
+                        "due_at <= ? AND status NOT IN ({})",
+                        vec!["?"; done_statuses.len()].join(", ")
+                    )
+                };
+                conditions.push(overdue_condition.as_str());
+                params.push(overdue_at.naive_utc().into());
+                for status in done_statuses {
+                    params.push(status.to_int().into());
+                }
+            }
+            let where_clause = if conditions.is_empty() {
+                String::new()
+            } else {
+                format!("WHERE {}", conditions.join(" AND "))
+            };
+
+            let total: Option<u64> = conn
+                .exec_first(
+                    format!("SELECT COUNT(*) FROM tasks {}", where_clause),
+                    params.clone(),
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            let mut select_clause = where_clause;
+            if let Some(after) = &query.after {
+                let (condition, keyset_params) = keyset_condition(after);
+                let keyword = if conditions.is_empty() {
+                    "WHERE"
+                } else {
+                    "AND"
+                };
+                select_clause = format!("{} {} {}", select_clause, keyword, condition);
+                params.extend(keyset_params.into_iter().map(keyset_value));
+            }
+
+            let order = match query.order {
+                SortOrder::Asc => "ASC",
+                SortOrder::Desc => "DESC",
+            };
+            let order_by = match query.sort {
+                TaskSortKey::Id => format!("id {}", order),
+                TaskSortKey::Title => format!("title {}, id {}", order, order),
+                TaskSortKey::Status => format!("status {}, id {}", order, order),
+                TaskSortKey::Priority => {
+                    format!("priority {}, due_at IS NULL, due_at ASC, id ASC", order)
+                }
+                TaskSortKey::DueAt => format!("due_at IS NULL, due_at {}, id {}", order, order),
+            };
+            params.push((query.limit as u64).into());
+            params.push((query.offset as u64).into());
+
+            let tasks = conn
+                .exec_map(
+                    format!(
+                        "SELECT {} FROM tasks {} ORDER BY {} LIMIT ? OFFSET ?",
+                        TASK_COLUMNS, select_clause, order_by
+                    ),
+                    params,
+                    |row| task_from_row(row, self.workflow),
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            let tasks = load_labels(conn, tasks)?;
+            let tasks = load_blockers(conn, tasks)?;
+
+            Ok(TaskPage {
+                tasks,
+                total: total.unwrap_or_default() as usize,
+            })
+        })
+    }
 
-        let tasks = conn
-            .query_map(
-                "SELECT id, title, description, status FROM tasks",
-                |(id, title, description, status): (String, String, String, i32)| {
-                    Task::new(
-                        TaskId::try_from(id.as_str()).unwrap(),
-                        TaskTitle::try_from(title.as_str()).unwrap(),
-                        TaskDescription::try_from(description.as_str()).unwrap(),
-                        TaskStatus::try_from(status).unwrap(),
+    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let row: Option<Row> = conn
+                .exec_first(
+                    format!("SELECT {} FROM tasks WHERE id = :id", TASK_COLUMNS),
+                    params! {
+                        "id" => id.to_string(),
+                    },
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            let task = row
+                .map(|row| task_from_row(row, self.workflow))
+                .ok_or(TaskRepositoryError::NotFound)?;
+            let tasks = load_labels(conn, vec![task])?;
+            let mut tasks = load_blockers(conn, tasks)?;
+
+            Ok(tasks.remove(0))
+        })
+    }
+
+    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.with_transaction(|conn| {
+            conn.exec_drop(
+                "INSERT INTO tasks
+                     (id, title, description, status, priority, due_at, due_all_day, parent_id,
+                      recurrence, created_at, updated_at, completed_at, deleted_at, archived_at, version)
+                 VALUES
+                     (:id, :title, :description, :status, :priority, :due_at, :due_all_day, :parent_id,
+                      :recurrence, :created_at, :updated_at, :completed_at, :deleted_at, :archived_at,
+                      :version)",
+                task_params(&task),
+            )
+            .map_err(|e| match e {
+                mysql::Error::MySqlError(ref err) if err.code == ER_DUP_ENTRY => {
+                    TaskRepositoryError::AlreadyExists
+                }
+                e => TaskRepositoryError::DbError(e.to_string()),
+            })?;
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(task)
+        })
+    }
+
+    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.with_transaction(|conn| {
+            conn.exec_drop(
+                "UPDATE tasks
+                 SET title = :title, description = :description, status = :status,
+                     priority = :priority, due_at = :due_at, due_all_day = :due_all_day,
+                     parent_id = :parent_id, recurrence = :recurrence,
+                     updated_at = :updated_at, completed_at = :completed_at,
+                     deleted_at = :deleted_at, archived_at = :archived_at, version = version + 1
+                 WHERE id = :id AND version = :version",
+                task_params(&task),
+            )
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            if conn.affected_rows() == 0 {
+                let exists: Option<u8> = conn
+                    .exec_first(
+                        "SELECT 1 FROM tasks WHERE id = :id",
+                        params! {
+                            "id" => task.id.to_string(),
+                        },
                     )
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                return Err(match exists {
+                    Some(_) => TaskRepositoryError::Conflict,
+                    None => TaskRepositoryError::NotFound,
+                });
+            }
+            let version = task.version + 1;
+            let task = task.change_version(version);
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(task)
+        })
+    }
+
+    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
+        self.with_conn(|conn| {
+            conn.exec_drop(
+                "DELETE FROM tasks WHERE id = :id",
+                params! {
+                    "id" => task_id.to_string(),
                 },
             )
             .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        Ok(tasks)
+            if conn.affected_rows() == 0 {
+                return Err(TaskRepositoryError::NotFound);
+            }
+
+            Ok(())
+        })
     }
 
-    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut transaction = self.transaction.borrow_mut();
+        if transaction.is_some() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
         let mut conn = self.db.get_conn();
+        conn.query_drop("START TRANSACTION")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        *transaction = Some(conn);
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut conn = self
+            .transaction
+            .borrow_mut()
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
 
-        let row: Option<(String, String, String, i32)> = conn
-            .exec_first(
-                "SELECT id, title, description, status FROM tasks WHERE id = :id",
+        conn.query_drop("COMMIT")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut conn = self
+            .transaction
+            .borrow_mut()
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+
+        conn.query_drop("ROLLBACK")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+}
+
+impl<'a> TaskEventRepository for TaskMysqlRepository<'a> {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let changes: Vec<TaskFieldChangeRecord> = event
+                .changes
+                .into_iter()
+                .map(|change| TaskFieldChangeRecord {
+                    field: change.field,
+                    before: change.before,
+                    after: change.after,
+                })
+                .collect();
+            let changes = serde_json::to_string(&changes)
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            conn.exec_drop(
+                "INSERT INTO task_events (task_id, kind, actor, changes, occurred_at)
+                 VALUES (:task_id, :kind, :actor, :changes, :occurred_at)",
                 params! {
-                    "id" => id.to_string(),
+                    "task_id" => event.task_id.to_string(),
+                    "kind" => event.kind.to_string(),
+                    "actor" => event.actor,
+                    "changes" => changes,
+                    "occurred_at" => event.occurred_at.naive_utc(),
                 },
             )
-            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+        })
+    }
 
-        let (id, title, description, status) = row.ok_or(TaskRepositoryError::NotFound)?;
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let rows: Vec<(String, Option<String>, String, NaiveDateTime)> = conn
+                .exec(
+                    "SELECT kind, actor, changes, occurred_at FROM task_events
+                     WHERE task_id = :task_id ORDER BY id",
+                    params! {
+                        "task_id" => task_id.to_string(),
+                    },
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        Ok(Task::new(
-            TaskId::try_from(id.as_str()).unwrap(),
-            TaskTitle::try_from(title.as_str()).unwrap(),
-            TaskDescription::try_from(description.as_str()).unwrap(),
-            TaskStatus::try_from(status).unwrap(),
-        ))
+            rows.into_iter()
+                .map(|(kind, actor, changes, occurred_at)| {
+                    let changes: Vec<TaskFieldChangeRecord> = serde_json::from_str(&changes)
+                        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                    Ok(TaskEvent {
+                        task_id: task_id.clone(),
+                        kind: TaskEventKind::try_from(kind.as_str()).map_err(|_| {
+                            TaskRepositoryError::DbError(format!(
+                                "invalid task event kind: {}",
+                                kind
+                            ))
+                        })?,
+                        changes: changes
+                            .into_iter()
+                            .map(|change| TaskFieldChange {
+                                field: change.field,
+                                before: change.before,
+                                after: change.after,
+                            })
+                            .collect(),
+                        actor,
+                        occurred_at: occurred_at.and_utc(),
+                    })
+                })
+                .collect()
+        })
     }
+}
 
-    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
-        let mut conn = self.db.get_conn();
+#[derive(Serialize, Deserialize)]
+struct TaskFieldChangeRecord {
+    field: String,
+    before: Option<String>,
+    after: Option<String>,
+}
 
-        conn.exec_drop(
-            "INSERT INTO tasks (id, title, description, status)
-             VALUES (:id, :title, :description, :status)",
-            params! {
-                "id" => task.id.to_string(),
-                "title" => task.title.to_string(),
-                "description" => task.description.to_string(),
-                "status" => task.status.to_int(),
-            },
+fn task_from_row(mut row: Row, workflow: &TaskWorkflow) -> Task {
+    let id: String = row.take("id").unwrap();
+    let title: String = row.take("title").unwrap();
+    let description: String = row.take("description").unwrap();
+    let status: i32 = row.take("status").unwrap();
+    let priority: i32 = row.take("priority").unwrap();
+    let due_at: Option<NaiveDateTime> = row.take("due_at").unwrap();
+    let due_all_day: bool = row.take("due_all_day").unwrap();
+    let parent_id: Option<String> = row.take("parent_id").unwrap();
+    let recurrence: Option<String> = row.take("recurrence").unwrap();
+    let created_at: NaiveDateTime = row.take("created_at").unwrap();
+    let updated_at: NaiveDateTime = row.take("updated_at").unwrap();
+    let completed_at: Option<NaiveDateTime> = row.take("completed_at").unwrap();
+    let deleted_at: Option<NaiveDateTime> = row.take("deleted_at").unwrap();
+    let archived_at: Option<NaiveDateTime> = row.take("archived_at").unwrap();
+    let version: u64 = row.take("version").unwrap();
+
+    Task::new(
+        TaskId::try_from(id.as_str()).unwrap(),
+        TaskTitle::try_from(title.as_str()).unwrap(),
+        TaskDescription::try_from(description.as_str()).unwrap(),
+        workflow
+            .status(status)
+            .unwrap_or_else(|_| TaskStatus::new(status, TaskStatusCategory::Todo)),
+        created_at.and_utc(),
+    )
+    .change_priority(TaskPriority::try_from(priority).unwrap())
+    .change_due_at(due_at.map(|due_at| TaskDueDate::from_deadline(due_at.and_utc(), due_all_day)))
+    .change_parent(parent_id.map(|parent_id| TaskId::try_from(parent_id.as_str()).unwrap()))
+    .change_recurrence(
+        recurrence.map(|recurrence| TaskRecurrence::try_from(recurrence.as_str()).unwrap()),
+    )
+    .change_updated_at(updated_at.and_utc())
+    .change_completed_at(completed_at.map(|completed_at| completed_at.and_utc()))
+    .change_deleted_at(deleted_at.map(|deleted_at| deleted_at.and_utc()))
+    .change_archived_at(archived_at.map(|archived_at| archived_at.and_utc()))
+    .change_version(version)
+}
+
+fn load_labels(conn: &mut PooledConn, tasks: Vec<Task>) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let placeholders = vec!["?"; tasks.len()].join(", ");
+    let ids: Vec<Value> = tasks
+        .iter()
+        .map(|task| task.id.to_string().into())
+        .collect();
+    let rows: Vec<(String, String)> = conn
+        .exec(
+            format!(
+                "SELECT task_id, label_id FROM task_labels WHERE task_id IN ({}) ORDER BY label_id",
+                placeholders
+            ),
+            ids,
         )
         .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        Ok(task)
+    let mut labels: HashMap<String, Vec<LabelId>> = HashMap::new();
+    for (task_id, label_id) in rows {
+        labels
+            .entry(task_id)
+            .or_default()
+            .push(LabelId::try_from(label_id.as_str()).unwrap());
     }
 
-    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
-        let mut conn = self.db.get_conn();
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let task_labels = labels.remove(&task.id.to_string()).unwrap_or_default();
+            task.change_labels(task_labels)
+        })
+        .collect())
+}
+
+fn save_labels(conn: &mut PooledConn, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.exec_drop(
+        "DELETE FROM task_labels WHERE task_id = :task_id",
+        params! {
+            "task_id" => task.id.to_string(),
+        },
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        conn.exec_drop(
-            "UPDATE tasks
-             SET title = :title, description = :description, status = :status
-             WHERE id = :id",
+    conn.exec_batch(
+        "INSERT INTO task_labels (task_id, label_id) VALUES (:task_id, :label_id)",
+        task.labels.iter().map(|label_id| {
             params! {
-                "id" => task.id.to_string(),
-                "title" => task.title.to_string(),
-                "description" => task.description.to_string(),
-                "status" => task.status.to_int(),
-            },
+                "task_id" => task.id.to_string(),
+                "label_id" => label_id.to_string(),
+            }
+        }),
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+}
+
+fn load_blockers(
+    conn: &mut PooledConn,
+    tasks: Vec<Task>,
+) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let placeholders = vec!["?"; tasks.len()].join(", ");
+    let ids: Vec<Value> = tasks
+        .iter()
+        .map(|task| task.id.to_string().into())
+        .collect();
+    let rows: Vec<(String, String)> = conn
+        .exec(
+            format!(
+                "SELECT task_id, blocked_by_id FROM task_dependencies
+                 WHERE task_id IN ({}) ORDER BY blocked_by_id",
+                placeholders
+            ),
+            ids,
         )
         .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        Ok(task)
+    let mut blockers: HashMap<String, Vec<TaskId>> = HashMap::new();
+    for (task_id, blocked_by_id) in rows {
+        blockers
+            .entry(task_id)
+            .or_default()
+            .push(TaskId::try_from(blocked_by_id.as_str()).unwrap());
     }
 
-    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
-        let mut conn = self.db.get_conn();
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let task_blockers = blockers.remove(&task.id.to_string()).unwrap_or_default();
+            task.change_blocked_by(task_blockers)
+        })
+        .collect())
+}
+
+fn save_blockers(conn: &mut PooledConn, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.exec_drop(
+        "DELETE FROM task_dependencies WHERE task_id = :task_id",
+        params! {
+            "task_id" => task.id.to_string(),
+        },
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
 
-        conn.exec_drop(
-            "DELETE FROM tasks WHERE id = :id",
+    conn.exec_batch(
+        "INSERT INTO task_dependencies (task_id, blocked_by_id) VALUES (:task_id, :blocked_by_id)",
+        task.blocked_by.iter().map(|blocked_by_id| {
             params! {
-                "id" => task_id.to_string(),
-            },
-        )
-        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                "task_id" => task.id.to_string(),
+                "blocked_by_id" => blocked_by_id.to_string(),
+            }
+        }),
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+}
 
-        Ok(())
+fn keyset_value(param: TaskKeysetParam) -> Value {
+    match param {
+        TaskKeysetParam::Text(value) => value.into(),
+        TaskKeysetParam::Int(value) => value.into(),
+        TaskKeysetParam::DateTime(value) => value.naive_utc().into(),
+        TaskKeysetParam::Id(value) => value.to_string().into(),
+    }
+}
+
+fn task_params(task: &Task) -> Params {
+    params! {
+        "id" => task.id.to_string(),
+        "title" => task.title.to_string(),
+        "description" => task.description.to_string(),
+        "status" => task.status.to_int(),
+        "priority" => task.priority.to_int(),
+        "due_at" => task.due_at.as_ref().map(|due_at| due_at.deadline().naive_utc()),
+        "due_all_day" => task.due_at.as_ref().is_some_and(|due_at| due_at.is_all_day()),
+        "parent_id" => task.parent_id.as_ref().map(|parent_id| parent_id.to_string()),
+        "recurrence" => task.recurrence.as_ref().map(|recurrence| recurrence.to_string()),
+        "created_at" => task.created_at.naive_utc(),
+        "updated_at" => task.updated_at.naive_utc(),
+        "completed_at" => task.completed_at.map(|completed_at| completed_at.naive_utc()),
+        "deleted_at" => task.deleted_at.map(|deleted_at| deleted_at.naive_utc()),
+        "archived_at" => task.archived_at.map(|archived_at| archived_at.naive_utc()),
+        "version" => task.version,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::label::label::Label;
+    use crate::domain::label::label_color::LabelColor;
+    use crate::domain::label::label_name::LabelName;
+    use crate::domain::label::label_repository::LabelRepository;
+    use crate::repositories::app_db::MysqlTestDb;
+    use crate::repositories::label::label_mysql_repository::LabelMysqlRepository;
+    use crate::repositories::task::task_repository_behavior::{self, register_test_data};
+    use chrono::DateTime;
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_tasks_are_registered_then_returns_task_list() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_registered_then_returns_task_list(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_tasks_are_not_registered_then_returns_empty_list() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_not_registered_then_returns_empty_list(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_status_filter_then_returns_matching_tasks() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_status_filter_then_returns_matching_tasks(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_query_has_limit_then_returns_page_and_total() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_limit_then_returns_page_and_total(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_query_has_cursor_then_returns_tasks_after_it() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_cursor_then_returns_tasks_after_it(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_due_filters_then_returns_matching_tasks() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_due_filters_then_returns_matching_tasks(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_sorted_by_priority_then_orders_by_priority_then_due_date(TaskMysqlRepository::new(&test_db.db, &workflow));
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn get_by_id_when_valid_value_then_returns_task() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::get_by_id_when_valid_value_then_returns_task(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn register_when_valid_value_then_returns_task() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::register_when_valid_value_then_returns_task(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_task_is_trashed_then_returns_it_only_from_trash() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_trashed_then_returns_it_only_from_trash(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_archived_then_returns_it_only_when_archived_are_included(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn rollback_transaction_when_changes_were_made_then_discards_them() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::rollback_transaction_when_changes_were_made_then_discards_them(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn commit_transaction_when_changes_were_made_then_keeps_them() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::commit_transaction_when_changes_were_made_then_keeps_them(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn update_when_valid_value_then_returns_task() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_valid_value_then_returns_task(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn update_when_version_is_stale_then_returns_conflict() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_version_is_stale_then_returns_conflict(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn delete_when_valid_id_then_returns_empty_task() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::delete_when_valid_id_then_returns_empty_task(
+            TaskMysqlRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn search_when_label_filter_then_returns_labeled_tasks() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskMysqlRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let label = LabelMysqlRepository::new(&test_db.db)
+            .register(Label::new(
+                LabelId::new(),
+                LabelName::try_from("bug").unwrap(),
+                LabelColor::try_from("#d73a4a").unwrap(),
+            ))
+            .unwrap();
+        let task = repository.get_by_id(&ids[2]).unwrap();
+        repository
+            .update(task.attach_label(label.id.clone()))
+            .unwrap();
+
+        let query = TaskQuery {
+            label: Some(label.id),
+            ..TaskQuery::default()
+        };
+        let page = repository.search(&query).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.tasks[0].id, ids[2]);
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn register_when_id_already_exists_then_returns_already_exists() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskMysqlRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+
+        let result = repository.register(task);
+        assert!(matches!(result, Err(TaskRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn delete_when_task_blocks_others_then_removes_dependency() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskMysqlRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository.update(task.add_blocker(ids[1].clone())).unwrap();
+
+        repository.delete(&ids[1]).unwrap();
+        assert!(repository.get_by_id(&ids[0]).unwrap().blocked_by.is_empty());
+    }
+
+    #[test]
+    #[ignore = "requires a local MySQL instance in TEST_MYSQL_URL"]
+    fn history_when_events_are_appended_then_returns_events_in_order() {
+        let test_db = MysqlTestDb::migrated();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskMysqlRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let before = repository.get_by_id(&ids[0]).unwrap();
+        let after = before
+            .clone()
+            .change_title(TaskTitle::try_from("AAA2").unwrap());
+        repository
+            .append(TaskEvent::created(&before, None, DateTime::UNIX_EPOCH))
+            .unwrap();
+        repository
+            .append(TaskEvent::updated(
+                &before,
+                &after,
+                Some("alice"),
+                DateTime::UNIX_EPOCH,
+            ))
+            .unwrap();
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].kind, TaskEventKind::Created);
+        assert_eq!(history[1].kind, TaskEventKind::Updated);
+        assert_eq!(history[1].actor.as_deref(), Some("alice"));
+        assert_eq!(history[1].changes[0].field, "title");
+        assert!(repository.history(&ids[1]).unwrap().is_empty());
     }
 }
@@ -0,0 +1,135 @@
+use crate::domain::task::task_async_repository::TaskAsyncRepository;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_repository::TaskRepositoryError;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::repositories::task::task_postgres_repository::{
+    TASK_BLOCKERS_QUERY, TASK_LABELS_QUERY, TaskSearchStatement, task_from_row, task_uuids,
+    with_blockers, with_labels,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio_postgres::{Client, Config, NoTls};
+
+pub struct TaskPostgresAsyncRepository {
+    config: Config,
+    workflow: TaskWorkflow,
+    client: Mutex<Option<Arc<Client>>>,
+}
+
+impl TaskPostgresAsyncRepository {
+    pub fn new(config: Config, workflow: &TaskWorkflow) -> Self {
+        Self {
+            config,
+            workflow: workflow.clone(),
+            client: Mutex::new(None),
+        }
+    }
+
+    pub fn close(&self) {
+        self.client.blocking_lock().take();
+    }
+
+    async fn client(&self) -> Result<Arc<Client>, TaskRepositoryError> {
+        let mut client = self.client.lock().await;
+        if let Some(client) = client.as_ref().filter(|client| !client.is_closed()) {
+            return Ok(client.clone());
+        }
+
+        let (connected, connection) = self
+            .config
+            .connect(NoTls)
+            .await
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                eprintln!("error: postgres connection closed: {}", e);
+            }
+        });
+        let connected = Arc::new(connected);
+        *client = Some(connected.clone());
+
+        Ok(connected)
+    }
+}
+
+impl TaskAsyncRepository for TaskPostgresAsyncRepository {
+    async fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        let client = self.client().await?;
+        let statement = TaskSearchStatement::new(query, &self.workflow);
+        let total: i64 = client
+            .query_one(&statement.count, &statement.count_params())
+            .await
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?
+            .get(0);
+        let tasks: Vec<_> = client
+            .query(&statement.select, &statement.select_params())
+            .await
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?
+            .iter()
+            .map(|row| task_from_row(row, &self.workflow))
+            .collect();
+        if tasks.is_empty() {
+            return Ok(TaskPage {
+                tasks,
+                total: total as usize,
+            });
+        }
+
+        let ids = task_uuids(&tasks);
+        let labels = client
+            .query(TASK_LABELS_QUERY, &[&ids])
+            .await
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        let blockers = client
+            .query(TASK_BLOCKERS_QUERY, &[&ids])
+            .await
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+        Ok(TaskPage {
+            tasks: with_blockers(with_labels(tasks, labels), blockers),
+            total: total as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_repository::TaskRepository;
+    use crate::repositories::postgres_db::PostgresTestDb;
+    use crate::repositories::task::task_postgres_repository::TaskPostgresRepository;
+    use crate::repositories::task::task_repository_behavior::register_test_data;
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_tasks_are_registered_then_returns_same_page_as_blocking_repository() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        let blocking = TaskPostgresRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&blocking);
+        let task = blocking.get_by_id(&ids[1]).unwrap();
+        blocking
+            .update(task.change_blocked_by(vec![ids[0].clone()]))
+            .unwrap();
+        let query = TaskQuery {
+            limit: 2,
+            ..TaskQuery::default()
+        };
+        let expected = blocking.search(&query).unwrap();
+
+        let repository = TaskPostgresAsyncRepository::new(test_db.async_config(), &workflow);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let page = runtime.block_on(repository.search(&query)).unwrap();
+        assert_eq!(page.total, expected.total);
+        let summary = |tasks: &[crate::domain::task::task::Task]| {
+            tasks
+                .iter()
+                .map(|task| (task.id.clone(), task.blocked_by.clone(), task.version))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summary(&page.tasks), summary(&expected.tasks));
+    }
+}
@@ -0,0 +1,895 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::{TaskEvent, TaskEventKind, TaskFieldChange};
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{
+    SortOrder, TaskPage, TaskQuery, TaskSortKey, TaskTrashFilter,
+};
+use crate::domain::task::task_recurrence::TaskRecurrence;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::repositories::postgres_db::{PostgresConn, PostgresDb};
+use crate::repositories::task::task_keyset::{TaskKeysetParam, keyset_condition};
+use chrono::{DateTime, Utc};
+use postgres::error::SqlState;
+use postgres::types::ToSql;
+use postgres::{Client, GenericClient, Row};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const TASK_COLUMNS: &str = "id, title, description, status, priority, due_at, due_all_day, parent_id, recurrence, \
+     created_at, updated_at, completed_at, deleted_at, archived_at, version";
+const TASK_CREATED_AT_PARAM: usize = 9;
+
+pub struct TaskPostgresRepository<'a> {
+    db: &'a PostgresDb,
+    workflow: &'a TaskWorkflow,
+    transaction: RefCell<Option<PostgresConn>>,
+}
+
+impl<'a> TaskPostgresRepository<'a> {
+    pub fn new(db: &'a PostgresDb, workflow: &'a TaskWorkflow) -> Self {
+        Self {
+            db,
+            workflow,
+            transaction: RefCell::new(None),
+        }
+    }
+
+    fn with_conn<R>(
+        &self,
+        f: impl FnOnce(&mut Client) -> Result<R, TaskRepositoryError>,
+    ) -> Result<R, TaskRepositoryError> {
+        match self.transaction.borrow_mut().as_mut() {
+            Some(conn) => f(conn),
+            None => f(&mut self.db.get_conn()),
+        }
+    }
+
+    fn with_transaction<R>(
+        &self,
+        f: impl FnOnce(&mut Client) -> Result<R, TaskRepositoryError>,
+    ) -> Result<R, TaskRepositoryError> {
+        if let Some(conn) = self.transaction.borrow_mut().as_mut() {
+            return f(conn);
+        }
+
+        let mut conn = self.db.get_conn();
+        conn.batch_execute("BEGIN")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        match f(&mut conn) {
+            Ok(result) => {
+                conn.batch_execute("COMMIT")
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                Ok(result)
+            }
+            Err(e) => {
+                let _ = conn.batch_execute("ROLLBACK");
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<'a> TaskRepository for TaskPostgresRepository<'a> {
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let statement = TaskSearchStatement::new(query, self.workflow);
+            let total: i64 = conn
+                .query_one(&statement.count, &statement.count_params())
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?
+                .get(0);
+            let tasks = conn
+                .query(&statement.select, &statement.select_params())
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?
+                .iter()
+                .map(|row| task_from_row(row, self.workflow))
+                .collect();
+            let tasks = load_labels(&mut *conn, tasks)?;
+            let tasks = load_blockers(&mut *conn, tasks)?;
+
+            Ok(TaskPage {
+                tasks,
+                total: total as usize,
+            })
+        })
+    }
+
+    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let row = conn
+                .query_opt(
+                    &format!("SELECT {} FROM tasks WHERE id = $1", TASK_COLUMNS),
+                    &[&id.to_uuid()],
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            let task = row
+                .map(|row| task_from_row(&row, self.workflow))
+                .ok_or(TaskRepositoryError::NotFound)?;
+            let tasks = load_labels(&mut *conn, vec![task])?;
+            let mut tasks = load_blockers(&mut *conn, tasks)?;
+
+            Ok(tasks.remove(0))
+        })
+    }
+
+    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.with_transaction(|conn| {
+            conn.execute(
+                "INSERT INTO tasks
+                     (id, title, description, status, priority, due_at, due_all_day, parent_id,
+                      recurrence, created_at, updated_at, completed_at, deleted_at, archived_at, version)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)",
+                &bind(&task_params(&task)),
+            )
+            .map_err(|e| match e.code() {
+                Some(code) if *code == SqlState::UNIQUE_VIOLATION => TaskRepositoryError::AlreadyExists,
+                _ => TaskRepositoryError::DbError(e.to_string()),
+            })?;
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(task)
+        })
+    }
+
+    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        let mut params = task_params(&task);
+        params.remove(TASK_CREATED_AT_PARAM);
+        self.with_transaction(|conn| {
+            let updated = conn
+                .execute(
+                    "UPDATE tasks
+                     SET title = $2, description = $3, status = $4, priority = $5, due_at = $6,
+                         due_all_day = $7, parent_id = $8, recurrence = $9,
+                         updated_at = $10, completed_at = $11, deleted_at = $12,
+                         archived_at = $13, version = version + 1
+                     WHERE id = $1 AND version = $14",
+                    &bind(&params),
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            if updated == 0 {
+                let exists = conn
+                    .query_opt("SELECT 1 FROM tasks WHERE id = $1", &[&task.id.to_uuid()])
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                return Err(match exists {
+                    Some(_) => TaskRepositoryError::Conflict,
+                    None => TaskRepositoryError::NotFound,
+                });
+            }
+            let version = task.version + 1;
+            let task = task.change_version(version);
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(task)
+        })
+    }
+
+    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let affected_rows = conn
+                .execute("DELETE FROM tasks WHERE id = $1", &[&task_id.to_uuid()])
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            if affected_rows == 0 {
+                return Err(TaskRepositoryError::NotFound);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut transaction = self.transaction.borrow_mut();
+        if transaction.is_some() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        let mut conn = self.db.get_conn();
+        conn.batch_execute("BEGIN")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        *transaction = Some(conn);
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut conn = self
+            .transaction
+            .borrow_mut()
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+
+        conn.batch_execute("COMMIT")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let mut conn = self
+            .transaction
+            .borrow_mut()
+            .take()
+            .ok_or(TaskRepositoryError::TransactionState)?;
+
+        conn.batch_execute("ROLLBACK")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+}
+
+impl<'a> TaskEventRepository for TaskPostgresRepository<'a> {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let changes: Vec<TaskFieldChangeRecord> = event
+                .changes
+                .into_iter()
+                .map(|change| TaskFieldChangeRecord {
+                    field: change.field,
+                    before: change.before,
+                    after: change.after,
+                })
+                .collect();
+            let changes = serde_json::to_value(&changes)
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            conn.execute(
+                "INSERT INTO task_events (task_id, kind, actor, changes, occurred_at)
+                 VALUES ($1, $2, $3, $4, $5)",
+                &[
+                    &event.task_id.to_uuid(),
+                    &event.kind.to_string(),
+                    &event.actor,
+                    &changes,
+                    &event.occurred_at,
+                ],
+            )
+            .map(|_| ())
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+        })
+    }
+
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        self.with_conn(|conn| {
+            let rows = conn
+                .query(
+                    "SELECT kind, actor, changes, occurred_at FROM task_events
+                     WHERE task_id = $1 ORDER BY id",
+                    &[&task_id.to_uuid()],
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+            rows.into_iter()
+                .map(|row| {
+                    let kind: String = row.get("kind");
+                    let changes: Vec<TaskFieldChangeRecord> =
+                        serde_json::from_value(row.get("changes"))
+                            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                    Ok(TaskEvent {
+                        task_id: task_id.clone(),
+                        kind: TaskEventKind::try_from(kind.as_str()).map_err(|_| {
+                            TaskRepositoryError::DbError(format!(
+                                "invalid task event kind: {}",
+                                kind
+                            ))
+                        })?,
+                        changes: changes
+                            .into_iter()
+                            .map(|change| TaskFieldChange {
+                                field: change.field,
+                                before: change.before,
+                                after: change.after,
+                            })
+                            .collect(),
+                        actor: row.get("actor"),
+                        occurred_at: row.get("occurred_at"),
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskFieldChangeRecord {
+    field: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+pub struct TaskSearchStatement {
+    pub count: String,
+    pub select: String,
+    params: Vec<Box<dyn ToSql + Send + Sync>>,
+    count_len: usize,
+}
+
+impl TaskSearchStatement {
+    pub fn new(query: &TaskQuery, workflow: &TaskWorkflow) -> Self {
+        let mut conditions = Vec::new();
+        let mut params: Vec<Box<dyn ToSql + Send + Sync>> = Vec::new();
+        if let Some(status) = &query.status {
+            params.push(Box::new(status.to_int()));
+            conditions.push(format!("status = ${}", params.len()));
+        }
+        if let Some(priority) = &query.priority {
+            params.push(Box::new(priority.to_int()));
+            conditions.push(format!("priority = ${}", params.len()));
+        }
+        if let Some(label) = &query.label {
+            params.push(Box::new(label.to_uuid()));
+            conditions.push(format!(
+                "id IN (SELECT task_id FROM task_labels WHERE label_id = ${})",
+                params.len()
+            ));
+        }
+        match &query.parent {
+            Some(Some(parent_id)) => {
+                params.push(Box::new(parent_id.to_uuid()));
+                conditions.push(format!("parent_id = ${}", params.len()));
+            }
+            Some(None) => conditions.push(String::from("parent_id IS NULL")),
+            None => {}
+        }
+        if let Some(due_before) = query.due_before {
+            params.push(Box::new(due_before));
+            conditions.push(format!("due_at <= ${}", params.len()));
+        }
+        match query.trash {
+            TaskTrashFilter::Exclude => conditions.push(String::from("deleted_at IS NULL")),
+            TaskTrashFilter::Only => conditions.push(String::from("deleted_at IS NOT NULL")),
+            TaskTrashFilter::Include => {}
+        }
+        if let Some(deleted_before) = query.deleted_before {
+            params.push(Box::new(deleted_before));
+            conditions.push(format!("deleted_at <= ${}", params.len()));
+        }
+        if !query.include_archived {
+            conditions.push(String::from("archived_at IS NULL"));
+        }
+        if let Some(completed_before) = query.completed_before {
+            params.push(Box::new(completed_before));
+            conditions.push(format!("completed_at <= ${}", params.len()));
+        }
+        if let Some(overdue_at) = query.overdue_at {
+            params.push(Box::new(overdue_at));
+            conditions.push(format!("due_at <= ${}", params.len()));
+            let done_statuses: Vec<i32> = workflow
+                .done_statuses()
+                .iter()
+                .map(|status| status.to_int())
+                .collect();
+            if !done_statuses.is_empty() {
+                params.push(Box::new(done_statuses));
+                conditions.push(format!("status <> ALL(${})", params.len()));
+            }
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let count_len = params.len();
+
+        let mut select_clause = where_clause.clone();
+        if let Some(after) = &query.after {
+            let (condition, keyset_params) = keyset_condition(after);
+            let mut numbered = String::new();
+            let mut keyset_params = keyset_params.into_iter();
+            for part in condition.split('?') {
+                numbered.push_str(part);
+                if let Some(param) = keyset_params.next() {
+                    params.push(keyset_value(param));
+                    numbered.push_str(&format!("${}", params.len()));
+                }
+            }
+            let keyword = if conditions.is_empty() {
+                "WHERE"
+            } else {
+                "AND"
+            };
+            select_clause = format!("{} {} {}", select_clause, keyword, numbered);
+        }
+
+        let order = match query.order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+        let order_by = match query.sort {
+            TaskSortKey::Id => format!("id {}", order),
+            TaskSortKey::Title => format!("title {}, id {}", order, order),
+            TaskSortKey::Status => format!("status {}, id {}", order, order),
+            TaskSortKey::Priority => {
+                format!("priority {}, due_at IS NULL, due_at ASC, id ASC", order)
+            }
+            TaskSortKey::DueAt => format!("due_at IS NULL, due_at {}, id {}", order, order),
+        };
+        params.push(Box::new(query.limit as i64));
+        params.push(Box::new(query.offset as i64));
+
+        Self {
+            count: format!("SELECT COUNT(*) FROM tasks {}", where_clause),
+            select: format!(
+                "SELECT {} FROM tasks {} ORDER BY {} LIMIT ${} OFFSET ${}",
+                TASK_COLUMNS,
+                select_clause,
+                order_by,
+                params.len() - 1,
+                params.len()
+            ),
+            params,
+            count_len,
+        }
+    }
+
+    pub fn count_params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        self.params[..self.count_len]
+            .iter()
+            .map(|param| param.as_ref() as &(dyn ToSql + Sync))
+            .collect()
+    }
+
+    pub fn select_params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        self.params
+            .iter()
+            .map(|param| param.as_ref() as &(dyn ToSql + Sync))
+            .collect()
+    }
+}
+
+fn keyset_value(param: TaskKeysetParam) -> Box<dyn ToSql + Send + Sync> {
+    match param {
+        TaskKeysetParam::Text(value) => Box::new(value),
+        TaskKeysetParam::Int(value) => Box::new(value),
+        TaskKeysetParam::DateTime(value) => Box::new(value),
+        TaskKeysetParam::Id(value) => Box::new(value.to_uuid()),
+    }
+}
+
+pub fn task_from_row(row: &Row, workflow: &TaskWorkflow) -> Task {
+    let id: Uuid = row.get("id");
+    let title: String = row.get("title");
+    let description: String = row.get("description");
+    let status: i32 = row.get("status");
+    let priority: i32 = row.get("priority");
+    let due_at: Option<DateTime<Utc>> = row.get("due_at");
+    let due_all_day: bool = row.get("due_all_day");
+    let parent_id: Option<Uuid> = row.get("parent_id");
+    let recurrence: Option<String> = row.get("recurrence");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let updated_at: DateTime<Utc> = row.get("updated_at");
+    let completed_at: Option<DateTime<Utc>> = row.get("completed_at");
+    let deleted_at: Option<DateTime<Utc>> = row.get("deleted_at");
+    let archived_at: Option<DateTime<Utc>> = row.get("archived_at");
+    let version: i64 = row.get("version");
+
+    Task::new(
+        TaskId::from(id),
+        TaskTitle::try_from(title.as_str()).unwrap(),
+        TaskDescription::try_from(description.as_str()).unwrap(),
+        workflow
+            .status(status)
+            .unwrap_or_else(|_| TaskStatus::new(status, TaskStatusCategory::Todo)),
+        created_at,
+    )
+    .change_priority(TaskPriority::try_from(priority).unwrap())
+    .change_due_at(due_at.map(|due_at| TaskDueDate::from_deadline(due_at, due_all_day)))
+    .change_parent(parent_id.map(TaskId::from))
+    .change_recurrence(
+        recurrence.map(|recurrence| TaskRecurrence::try_from(recurrence.as_str()).unwrap()),
+    )
+    .change_updated_at(updated_at)
+    .change_completed_at(completed_at)
+    .change_deleted_at(deleted_at)
+    .change_archived_at(archived_at)
+    .change_version(version as u64)
+}
+
+pub const TASK_LABELS_QUERY: &str =
+    "SELECT task_id, label_id FROM task_labels WHERE task_id = ANY($1) ORDER BY label_id";
+pub const TASK_BLOCKERS_QUERY: &str = "SELECT task_id, blocked_by_id FROM task_dependencies
+     WHERE task_id = ANY($1) ORDER BY blocked_by_id";
+
+fn load_labels(
+    conn: &mut impl GenericClient,
+    tasks: Vec<Task>,
+) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let rows = conn
+        .query(TASK_LABELS_QUERY, &[&task_uuids(&tasks)])
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    Ok(with_labels(tasks, rows))
+}
+
+pub fn task_uuids(tasks: &[Task]) -> Vec<Uuid> {
+    tasks.iter().map(|task| task.id.to_uuid()).collect()
+}
+
+pub fn with_labels(tasks: Vec<Task>, rows: Vec<Row>) -> Vec<Task> {
+    let mut labels: HashMap<Uuid, Vec<LabelId>> = HashMap::new();
+    for row in rows {
+        labels
+            .entry(row.get("task_id"))
+            .or_default()
+            .push(LabelId::from(row.get("label_id")));
+    }
+
+    tasks
+        .into_iter()
+        .map(|task| {
+            let task_labels = labels.remove(&task.id.to_uuid()).unwrap_or_default();
+            task.change_labels(task_labels)
+        })
+        .collect()
+}
+
+fn save_labels(conn: &mut impl GenericClient, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.execute(
+        "DELETE FROM task_labels WHERE task_id = $1",
+        &[&task.id.to_uuid()],
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    let label_ids: Vec<Uuid> = task
+        .labels
+        .iter()
+        .map(|label_id| label_id.to_uuid())
+        .collect();
+    conn.execute(
+        "INSERT INTO task_labels (task_id, label_id) SELECT $1, UNNEST($2::UUID[])",
+        &[&task.id.to_uuid(), &label_ids],
+    )
+    .map(|_| ())
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+}
+
+fn load_blockers(
+    conn: &mut impl GenericClient,
+    tasks: Vec<Task>,
+) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let rows = conn
+        .query(TASK_BLOCKERS_QUERY, &[&task_uuids(&tasks)])
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    Ok(with_blockers(tasks, rows))
+}
+
+pub fn with_blockers(tasks: Vec<Task>, rows: Vec<Row>) -> Vec<Task> {
+    let mut blockers: HashMap<Uuid, Vec<TaskId>> = HashMap::new();
+    for row in rows {
+        blockers
+            .entry(row.get("task_id"))
+            .or_default()
+            .push(TaskId::from(row.get("blocked_by_id")));
+    }
+
+    tasks
+        .into_iter()
+        .map(|task| {
+            let task_blockers = blockers.remove(&task.id.to_uuid()).unwrap_or_default();
+            task.change_blocked_by(task_blockers)
+        })
+        .collect()
+}
+
+fn save_blockers(conn: &mut impl GenericClient, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.execute(
+        "DELETE FROM task_dependencies WHERE task_id = $1",
+        &[&task.id.to_uuid()],
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    let blocked_by_ids: Vec<Uuid> = task
+        .blocked_by
+        .iter()
+        .map(|blocked_by_id| blocked_by_id.to_uuid())
+        .collect();
+    conn.execute(
+        "INSERT INTO task_dependencies (task_id, blocked_by_id) SELECT $1, UNNEST($2::UUID[])",
+        &[&task.id.to_uuid(), &blocked_by_ids],
+    )
+    .map(|_| ())
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+}
+
+fn task_params(task: &Task) -> Vec<Box<dyn ToSql + Sync>> {
+    vec![
+        Box::new(task.id.to_uuid()),
+        Box::new(task.title.to_string()),
+        Box::new(task.description.to_string()),
+        Box::new(task.status.to_int()),
+        Box::new(task.priority.to_int()),
+        Box::new(task.due_at.as_ref().map(|due_at| due_at.deadline())),
+        Box::new(
+            task.due_at
+                .as_ref()
+                .is_some_and(|due_at| due_at.is_all_day()),
+        ),
+        Box::new(task.parent_id.as_ref().map(|parent_id| parent_id.to_uuid())),
+        Box::new(
+            task.recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+        ),
+        Box::new(task.created_at),
+        Box::new(task.updated_at),
+        Box::new(task.completed_at),
+        Box::new(task.deleted_at),
+        Box::new(task.archived_at),
+        Box::new(task.version as i64),
+    ]
+}
+
+fn bind(params: &[Box<dyn ToSql + Sync>]) -> Vec<&(dyn ToSql + Sync)> {
+    params.iter().map(|param| param.as_ref()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::label::label::Label;
+    use crate::domain::label::label_color::LabelColor;
+    use crate::domain::label::label_name::LabelName;
+    use crate::domain::label::label_repository::LabelRepository;
+    use crate::repositories::label::label_postgres_repository::LabelPostgresRepository;
+    use crate::repositories::postgres_db::PostgresTestDb;
+    use crate::repositories::task::task_repository_behavior::{self, register_test_data};
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_tasks_are_registered_then_returns_task_list() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_registered_then_returns_task_list(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_tasks_are_not_registered_then_returns_empty_list() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_not_registered_then_returns_empty_list(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_status_filter_then_returns_matching_tasks() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_status_filter_then_returns_matching_tasks(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_query_has_limit_then_returns_page_and_total() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_limit_then_returns_page_and_total(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_query_has_cursor_then_returns_tasks_after_it() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_cursor_then_returns_tasks_after_it(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_due_filters_then_returns_matching_tasks() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_due_filters_then_returns_matching_tasks(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_sorted_by_priority_then_orders_by_priority_then_due_date(TaskPostgresRepository::new(&test_db.db, &workflow));
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn get_by_id_when_valid_value_then_returns_task() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::get_by_id_when_valid_value_then_returns_task(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn register_when_valid_value_then_returns_task() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::register_when_valid_value_then_returns_task(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_task_is_trashed_then_returns_it_only_from_trash() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_trashed_then_returns_it_only_from_trash(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_archived_then_returns_it_only_when_archived_are_included(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn rollback_transaction_when_changes_were_made_then_discards_them() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::rollback_transaction_when_changes_were_made_then_discards_them(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn commit_transaction_when_changes_were_made_then_keeps_them() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::commit_transaction_when_changes_were_made_then_keeps_them(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn update_when_valid_value_then_returns_task() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_valid_value_then_returns_task(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn update_when_version_is_stale_then_returns_conflict() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_version_is_stale_then_returns_conflict(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn delete_when_valid_id_then_returns_empty_task() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::delete_when_valid_id_then_returns_empty_task(
+            TaskPostgresRepository::new(&test_db.db, &workflow),
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn search_when_label_filter_then_returns_labeled_tasks() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskPostgresRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let label = LabelPostgresRepository::new(&test_db.db)
+            .register(Label::new(
+                LabelId::new(),
+                LabelName::try_from("bug").unwrap(),
+                LabelColor::try_from("#d73a4a").unwrap(),
+            ))
+            .unwrap();
+        let task = repository.get_by_id(&ids[2]).unwrap();
+        repository
+            .update(task.attach_label(label.id.clone()))
+            .unwrap();
+
+        let query = TaskQuery {
+            label: Some(label.id),
+            ..TaskQuery::default()
+        };
+        let page = repository.search(&query).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.tasks[0].id, ids[2]);
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn register_when_id_already_exists_then_returns_already_exists() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskPostgresRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+
+        let result = repository.register(task);
+        assert!(matches!(result, Err(TaskRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn delete_when_task_blocks_others_then_removes_dependency() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskPostgresRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository.update(task.add_blocker(ids[1].clone())).unwrap();
+
+        repository.delete(&ids[1]).unwrap();
+        assert!(repository.get_by_id(&ids[0]).unwrap().blocked_by.is_empty());
+    }
+
+    #[test]
+    #[ignore = "requires a local PostgreSQL instance in TEST_POSTGRES_URL"]
+    fn history_when_events_are_appended_then_returns_events_in_order() {
+        let test_db = PostgresTestDb::new();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskPostgresRepository::new(&test_db.db, &workflow);
+        let ids = register_test_data(&repository);
+        let before = repository.get_by_id(&ids[0]).unwrap();
+        let after = before
+            .clone()
+            .change_title(TaskTitle::try_from("AAA2").unwrap());
+        repository
+            .append(TaskEvent::created(&before, None, DateTime::UNIX_EPOCH))
+            .unwrap();
+        repository
+            .append(TaskEvent::updated(
+                &before,
+                &after,
+                Some("alice"),
+                DateTime::UNIX_EPOCH,
+            ))
+            .unwrap();
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].kind, TaskEventKind::Created);
+        assert_eq!(history[1].kind, TaskEventKind::Updated);
+        assert_eq!(history[1].actor.as_deref(), Some("alice"));
+        assert_eq!(history[1].changes[0].field, "title");
+        assert!(repository.history(&ids[1]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,419 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_cursor::TaskCursor;
+use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{SortOrder, TaskQuery, TaskSortKey, TaskTrashFilter};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+use crate::domain::task::task_title::TaskTitle;
+use chrono::{DateTime, TimeDelta, Utc};
+
+pub fn register_test_data<T: TaskRepository>(repository: &T) -> Vec<TaskId> {
+    ["AAA", "BBB", "CCC"]
+        .into_iter()
+        .map(|value| {
+            let task = Task::new(
+                TaskId::new(),
+                TaskTitle::try_from(value).unwrap(),
+                TaskDescription::try_from(value).unwrap(),
+                TaskStatus::new(1, TaskStatusCategory::Todo),
+                DateTime::UNIX_EPOCH,
+            );
+            repository.register(task).unwrap().id
+        })
+        .collect()
+}
+
+pub fn search_when_tasks_are_registered_then_returns_task_list<T: TaskRepository>(repository: T) {
+    register_test_data(&repository);
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.tasks.len(), 3);
+    assert_eq!(page.total, 3);
+}
+
+pub fn search_when_tasks_are_not_registered_then_returns_empty_list<T: TaskRepository>(
+    repository: T,
+) {
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.tasks.len(), 0);
+}
+
+pub fn search_when_status_filter_then_returns_matching_tasks<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+    let task = repository.get_by_id(&ids[1]).unwrap();
+    repository
+        .update(task.change_status(TaskStatus::new(2, TaskStatusCategory::InProgress)))
+        .unwrap();
+
+    let query = TaskQuery {
+        status: Some(TaskStatus::new(2, TaskStatusCategory::InProgress)),
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.tasks[0].id, ids[1]);
+}
+
+pub fn search_when_query_has_limit_then_returns_page_and_total<T: TaskRepository>(repository: T) {
+    register_test_data(&repository);
+
+    let query = TaskQuery {
+        sort: TaskSortKey::Title,
+        order: SortOrder::Desc,
+        offset: 1,
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 3);
+    assert_eq!(page.tasks.len(), 2);
+    assert_eq!(page.tasks[0].title.to_string(), "BBB");
+}
+
+pub fn search_when_due_filters_then_returns_matching_tasks<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+    let due_dates = ["2026-10-01", "2026-10-20"];
+    for (id, due_at) in ids.iter().zip(due_dates) {
+        let task = repository.get_by_id(id).unwrap();
+        let due_at = TaskDueDate::try_from(due_at).unwrap();
+        repository.update(task.change_due_at(Some(due_at))).unwrap();
+    }
+    let now = TaskDueDate::try_from("2026-10-10T00:00:00Z")
+        .unwrap()
+        .deadline();
+
+    let query = TaskQuery {
+        overdue_at: Some(now),
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.tasks[0].id, ids[0]);
+
+    let query = TaskQuery {
+        due_before: Some(TaskDueDate::try_from("2026-10-20").unwrap().deadline()),
+        sort: TaskSortKey::DueAt,
+        order: SortOrder::Desc,
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 2);
+    assert_eq!(page.tasks[0].id, ids[1]);
+}
+
+pub fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date<T: TaskRepository>(
+    repository: T,
+) {
+    let ids = register_test_data(&repository);
+    let fixtures = [
+        (TaskPriority::High, Some("2026-10-20")),
+        (TaskPriority::Urgent, None),
+        (TaskPriority::High, Some("2026-10-10")),
+    ];
+    for (id, (priority, due_at)) in ids.iter().zip(fixtures) {
+        let task = repository
+            .get_by_id(id)
+            .unwrap()
+            .change_priority(priority)
+            .change_due_at(due_at.map(|due_at| TaskDueDate::try_from(due_at).unwrap()));
+        repository.update(task).unwrap();
+    }
+
+    let query = TaskQuery {
+        sort: TaskSortKey::Priority,
+        order: SortOrder::Desc,
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    let sorted: Vec<TaskId> = page.tasks.into_iter().map(|task| task.id).collect();
+    assert_eq!(sorted, vec![ids[1].clone(), ids[2].clone(), ids[0].clone()]);
+
+    let query = TaskQuery {
+        priority: Some(TaskPriority::High),
+        ..TaskQuery::default()
+    };
+    assert_eq!(repository.search(&query).unwrap().total, 2);
+}
+
+pub fn search_when_query_has_cursor_then_returns_tasks_after_it<T: TaskRepository>(repository: T) {
+    let mut ids = register_test_data(&repository);
+    ids.extend(register_test_data(&repository));
+    let fixtures = [
+        (TaskPriority::High, Some("2026-10-20")),
+        (TaskPriority::Urgent, None),
+        (TaskPriority::High, Some("2026-10-10")),
+        (TaskPriority::High, Some("2026-10-10")),
+        (TaskPriority::Low, None),
+        (TaskPriority::High, None),
+    ];
+    for (id, (priority, due_at)) in ids.iter().zip(fixtures) {
+        let task = repository
+            .get_by_id(id)
+            .unwrap()
+            .change_priority(priority)
+            .change_due_at(due_at.map(|due_at| TaskDueDate::try_from(due_at).unwrap()));
+        repository.update(task).unwrap();
+    }
+
+    for sort in [
+        TaskSortKey::Id,
+        TaskSortKey::Title,
+        TaskSortKey::Status,
+        TaskSortKey::Priority,
+        TaskSortKey::DueAt,
+    ] {
+        for order in [SortOrder::Asc, SortOrder::Desc] {
+            let query = TaskQuery {
+                sort,
+                order,
+                ..TaskQuery::default()
+            };
+            let expected: Vec<TaskId> = repository
+                .search(&query)
+                .unwrap()
+                .tasks
+                .into_iter()
+                .map(|task| task.id)
+                .collect();
+
+            let mut query = TaskQuery { limit: 2, ..query };
+            let mut paged = Vec::new();
+            loop {
+                let page = repository.search(&query).unwrap();
+                assert_eq!(page.total, 6);
+                let Some(last) = page.tasks.last() else {
+                    break;
+                };
+                query.after = Some(TaskCursor::new(last, sort, order));
+                paged.extend(page.tasks.into_iter().map(|task| task.id));
+            }
+            assert_eq!(paged, expected, "{} {}", sort, order);
+        }
+    }
+}
+
+pub fn search_when_label_filter_then_returns_labeled_tasks<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+    let label_id = LabelId::new();
+    let task = repository.get_by_id(&ids[2]).unwrap();
+    repository
+        .update(task.attach_label(label_id.clone()))
+        .unwrap();
+
+    let query = TaskQuery {
+        label: Some(label_id),
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.tasks[0].id, ids[2]);
+}
+
+pub fn search_when_task_is_trashed_then_returns_it_only_from_trash<T: TaskRepository>(
+    repository: T,
+) {
+    let ids = register_test_data(&repository);
+    let deleted_at = DateTime::parse_from_rfc3339("2026-10-18T09:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    repository
+        .update(task.change_deleted_at(Some(deleted_at)))
+        .unwrap();
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.total, 2);
+    assert!(page.tasks.iter().all(|task| task.id != ids[0]));
+
+    let query = TaskQuery {
+        trash: TaskTrashFilter::Only,
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.tasks[0].deleted_at, Some(deleted_at));
+
+    let query = TaskQuery {
+        trash: TaskTrashFilter::Only,
+        deleted_before: Some(deleted_at - TimeDelta::seconds(1)),
+        ..TaskQuery::default()
+    };
+    assert_eq!(repository.search(&query).unwrap().total, 0);
+
+    let query = TaskQuery {
+        trash: TaskTrashFilter::Include,
+        ..TaskQuery::default()
+    };
+    assert_eq!(repository.search(&query).unwrap().total, 3);
+}
+
+pub fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included<
+    T: TaskRepository,
+>(
+    repository: T,
+) {
+    let ids = register_test_data(&repository);
+    let completed_at = DateTime::parse_from_rfc3339("2026-10-01T09:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    repository
+        .update(
+            task.change_completed_at(Some(completed_at))
+                .change_archived_at(Some(completed_at + TimeDelta::days(7))),
+        )
+        .unwrap();
+    let task = repository.get_by_id(&ids[1]).unwrap();
+    repository
+        .update(task.change_completed_at(Some(completed_at)))
+        .unwrap();
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.total, 2);
+    assert!(page.tasks.iter().all(|task| task.id != ids[0]));
+
+    let query = TaskQuery {
+        include_archived: true,
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 3);
+    let archived = page.tasks.iter().find(|task| task.id == ids[0]).unwrap();
+    assert_eq!(
+        archived.archived_at,
+        Some(completed_at + TimeDelta::days(7))
+    );
+
+    let query = TaskQuery {
+        completed_before: Some(completed_at),
+        ..TaskQuery::default()
+    };
+    let page = repository.search(&query).unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.tasks[0].id, ids[1]);
+}
+
+pub fn get_by_id_when_valid_value_then_returns_task<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+    let task = repository.get_by_id(&ids[0]).ok().unwrap();
+    assert_eq!(task.id, ids[0]);
+    assert_eq!(task.title.to_string(), "AAA");
+    assert_eq!(task.description.to_string(), "AAA");
+}
+
+pub fn register_when_valid_value_then_returns_task<T: TaskRepository>(repository: T) {
+    register_test_data(&repository);
+
+    let task_id = TaskId::new();
+    let task_title = TaskTitle::try_from("DDD").unwrap();
+    let task_description = TaskDescription::try_from("DDD").unwrap();
+    let task = Task::new(
+        task_id,
+        task_title,
+        task_description,
+        TaskStatus::new(1, TaskStatusCategory::Todo),
+        DateTime::UNIX_EPOCH,
+    );
+    let task = repository.register(task).ok().unwrap();
+    assert_eq!(task.title.to_string(), "DDD");
+    assert_eq!(task.description.to_string(), "DDD");
+}
+
+pub fn update_when_valid_value_then_returns_task<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+
+    let task_id = ids[0].clone();
+    let task_title = TaskTitle::try_from("AAA2").unwrap();
+    let task_description = TaskDescription::try_from("AAA2").unwrap();
+    let task = Task::new(
+        task_id,
+        task_title,
+        task_description,
+        TaskStatus::new(1, TaskStatusCategory::Todo),
+        DateTime::UNIX_EPOCH,
+    );
+    let task = repository.update(task).ok().unwrap();
+    assert_eq!(task.title.to_string(), "AAA2");
+    assert_eq!(task.description.to_string(), "AAA2");
+    assert_eq!(task.version, 2);
+    assert_eq!(repository.get_by_id(&task.id).unwrap().version, 2);
+}
+
+pub fn update_when_version_is_stale_then_returns_conflict<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    repository
+        .update(
+            task.clone()
+                .change_title(TaskTitle::try_from("AAA2").unwrap()),
+        )
+        .unwrap();
+
+    let result = repository.update(task.change_title(TaskTitle::try_from("AAA3").unwrap()));
+    assert!(matches!(result, Err(TaskRepositoryError::Conflict)));
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    assert_eq!(task.title.to_string(), "AAA2");
+    assert_eq!(task.version, 2);
+}
+
+pub fn delete_when_valid_id_then_returns_empty_task<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+
+    let task_id = ids[0].clone();
+    let _ = repository.delete(&task_id);
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.tasks.len(), 2);
+}
+
+pub fn rollback_transaction_when_changes_were_made_then_discards_them<T: TaskRepository>(
+    repository: T,
+) {
+    let ids = register_test_data(&repository);
+
+    repository.begin_transaction().unwrap();
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    repository
+        .update(task.change_title(TaskTitle::try_from("AAA2").unwrap()))
+        .unwrap();
+    repository.delete(&ids[1]).unwrap();
+    register_test_data(&repository);
+    repository.rollback_transaction().unwrap();
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.total, 3);
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    assert_eq!(task.title.to_string(), "AAA");
+    assert_eq!(task.version, 1);
+    assert!(matches!(
+        repository.commit_transaction(),
+        Err(TaskRepositoryError::TransactionState)
+    ));
+}
+
+pub fn commit_transaction_when_changes_were_made_then_keeps_them<T: TaskRepository>(repository: T) {
+    let ids = register_test_data(&repository);
+
+    repository.begin_transaction().unwrap();
+    assert!(matches!(
+        repository.begin_transaction(),
+        Err(TaskRepositoryError::TransactionState)
+    ));
+    let task = repository.get_by_id(&ids[0]).unwrap();
+    repository
+        .update(task.change_title(TaskTitle::try_from("AAA2").unwrap()))
+        .unwrap();
+    repository.delete(&ids[1]).unwrap();
+    repository.commit_transaction().unwrap();
+
+    let page = repository.search(&TaskQuery::default()).unwrap();
+    assert_eq!(page.total, 2);
+    assert_eq!(
+        repository.get_by_id(&ids[0]).unwrap().title.to_string(),
+        "AAA2"
+    );
+}
@@ -0,0 +1,941 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::{TaskEvent, TaskEventKind, TaskFieldChange};
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{
+    SortOrder, TaskPage, TaskQuery, TaskSortKey, TaskTrashFilter,
+};
+use crate::domain::task::task_recurrence::TaskRecurrence;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::repositories::sqlite_db::{SqliteDb, is_unique_violation};
+use crate::repositories::task::task_keyset::{TaskKeysetParam, keyset_condition};
+use chrono::NaiveDateTime;
+use rusqlite::types::{ToSql, Value};
+use rusqlite::{
+    Connection, OptionalExtension, Row, Transaction, TransactionBehavior, named_params,
+    params_from_iter,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const TASK_COLUMNS: &str = "id, title, description, status, priority, due_at, due_all_day, parent_id, recurrence, \
+     created_at, updated_at, completed_at, deleted_at, archived_at, version";
+
+pub struct TaskSqliteRepository<'a> {
+    db: &'a SqliteDb,
+    workflow: &'a TaskWorkflow,
+}
+
+impl<'a> TaskSqliteRepository<'a> {
+    pub fn new(db: &'a SqliteDb, workflow: &'a TaskWorkflow) -> Self {
+        Self { db, workflow }
+    }
+
+    fn write<R>(
+        &self,
+        f: impl FnOnce(&Connection) -> Result<R, TaskRepositoryError>,
+    ) -> Result<R, TaskRepositoryError> {
+        let conn = self.db.get_conn();
+        if !conn.is_autocommit() {
+            return f(conn);
+        }
+
+        let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        let result = f(&tx)?;
+        tx.commit()
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        Ok(result)
+    }
+}
+
+impl<'a> TaskRepository for TaskSqliteRepository<'a> {
+    fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let mut conditions = Vec::new();
+        let mut params: Vec<Value> = Vec::new();
+        if let Some(status) = &query.status {
+            conditions.push("status = ?");
+            params.push(Value::Integer(status.to_int().into()));
+        }
+        if let Some(priority) = &query.priority {
+            conditions.push("priority = ?");
+            params.push(Value::Integer(priority.to_int().into()));
+        }
+        if let Some(label) = &query.label {
+            conditions.push("id IN (SELECT task_id FROM task_labels WHERE label_id = ?)");
+            params.push(Value::Text(label.to_string()));
+        }
+        match &query.parent {
+            Some(Some(parent_id)) => {
+                conditions.push("parent_id = ?");
+                params.push(Value::Text(parent_id.to_string()));
+            }
+            Some(None) => conditions.push("parent_id IS NULL"),
+            None => {}
+        }
+        if let Some(due_before) = query.due_before {
+            conditions.push("due_at <= ?");
+            params.push(Value::Text(datetime_to_text(due_before.naive_utc())));
+        }
+        match query.trash {
+            TaskTrashFilter::Exclude => conditions.push("deleted_at IS NULL"),
+            TaskTrashFilter::Only => conditions.push("deleted_at IS NOT NULL"),
+            TaskTrashFilter::Include => {}
+        }
+        if let Some(deleted_before) = query.deleted_before {
+            conditions.push("deleted_at <= ?");
+            params.push(Value::Text(datetime_to_text(deleted_before.naive_utc())));
+        }
+        if !query.include_archived {
+            conditions.push("archived_at IS NULL");
+        }
+        if let Some(completed_before) = query.completed_before {
+            conditions.push("completed_at <= ?");
+            params.push(Value::Text(datetime_to_text(completed_before.naive_utc())));
+        }
+        let overdue_condition;
+        if let Some(overdue_at) = query.overdue_at {
+            let done_statuses = self.workflow.done_statuses();
+            overdue_condition = if done_statuses.is_empty() {
+                String::from("due_at <= ?")
+            } else {
+                format!(
+                    "due_at <= ? AND status NOT IN ({})",
+                    vec!["?"; done_statuses.len()].join(", ")
+                )
+            };
+            conditions.push(overdue_condition.as_str());
+            params.push(Value::Text(datetime_to_text(overdue_at.naive_utc())));
+            for status in done_statuses {
+                params.push(Value::Integer(status.to_int().into()));
+            }
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        let total: i64 = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM tasks {}", where_clause),
+                params_from_iter(params.iter()),
+                |row| row.get(0),
+            )
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+        let mut select_clause = where_clause;
+        if let Some(after) = &query.after {
+            let (condition, keyset_params) = keyset_condition(after);
+            let keyword = if conditions.is_empty() {
+                "WHERE"
+            } else {
+                "AND"
+            };
+            select_clause = format!("{} {} {}", select_clause, keyword, condition);
+            params.extend(keyset_params.into_iter().map(keyset_value));
+        }
+
+        let order = match query.order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+        let order_by = match query.sort {
+            TaskSortKey::Id => format!("id {}", order),
+            TaskSortKey::Title => format!("title {}, id {}", order, order),
+            TaskSortKey::Status => format!("status {}, id {}", order, order),
+            TaskSortKey::Priority => {
+                format!("priority {}, due_at IS NULL, due_at ASC, id ASC", order)
+            }
+            TaskSortKey::DueAt => format!("due_at IS NULL, due_at {}, id {}", order, order),
+        };
+        params.push(Value::Integer(query.limit as i64));
+        params.push(Value::Integer(query.offset as i64));
+
+        let mut statement = conn
+            .prepare(&format!(
+                "SELECT {} FROM tasks {} ORDER BY {} LIMIT ? OFFSET ?",
+                TASK_COLUMNS, select_clause, order_by
+            ))
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        let tasks = statement
+            .query_map(params_from_iter(params.iter()), |row| {
+                task_from_row(row, self.workflow)
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<Task>, _>>())
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        let tasks = load_labels(conn, tasks)?;
+        let tasks = load_blockers(conn, tasks)?;
+
+        Ok(TaskPage {
+            tasks,
+            total: total as usize,
+        })
+    }
+
+    fn get_by_id(&self, id: &TaskId) -> Result<Task, TaskRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let task = conn
+            .query_row(
+                &format!("SELECT {} FROM tasks WHERE id = :id", TASK_COLUMNS),
+                named_params! {
+                    ":id": id.to_string(),
+                },
+                |row| task_from_row(row, self.workflow),
+            )
+            .optional()
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?
+            .ok_or(TaskRepositoryError::NotFound)?;
+        let tasks = load_labels(conn, vec![task])?;
+        let mut tasks = load_blockers(conn, tasks)?;
+
+        Ok(tasks.remove(0))
+    }
+
+    fn register(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        self.write(|conn| {
+            conn.execute(
+                "INSERT INTO tasks
+                     (id, title, description, status, priority, due_at, due_all_day, parent_id,
+                      recurrence, created_at, updated_at, completed_at, deleted_at, archived_at, version)
+                 VALUES
+                     (:id, :title, :description, :status, :priority, :due_at, :due_all_day, :parent_id,
+                      :recurrence, :created_at, :updated_at, :completed_at, :deleted_at, :archived_at,
+                      :version)",
+                bind(&task_params(&task)).as_slice(),
+            )
+            .map_err(|e| {
+                if is_unique_violation(&e) {
+                    TaskRepositoryError::AlreadyExists
+                } else {
+                    TaskRepositoryError::DbError(e.to_string())
+                }
+            })?;
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(())
+        })?;
+
+        Ok(task)
+    }
+
+    fn update(&self, task: Task) -> Result<Task, TaskRepositoryError> {
+        let params: Vec<(&str, Value)> = task_params(&task)
+            .into_iter()
+            .filter(|(name, _)| *name != ":created_at")
+            .collect();
+        self.write(|conn| {
+            let updated = conn
+                .execute(
+                    "UPDATE tasks
+                     SET title = :title, description = :description, status = :status,
+                         priority = :priority, due_at = :due_at, due_all_day = :due_all_day,
+                         parent_id = :parent_id, recurrence = :recurrence,
+                         updated_at = :updated_at, completed_at = :completed_at,
+                         deleted_at = :deleted_at, archived_at = :archived_at, version = version + 1
+                     WHERE id = :id AND version = :version",
+                    bind(&params).as_slice(),
+                )
+                .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+            if updated == 0 {
+                let exists = conn
+                    .query_row(
+                        "SELECT 1 FROM tasks WHERE id = :id",
+                        named_params! {
+                            ":id": task.id.to_string(),
+                        },
+                        |_| Ok(()),
+                    )
+                    .optional()
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                return Err(match exists {
+                    Some(_) => TaskRepositoryError::Conflict,
+                    None => TaskRepositoryError::NotFound,
+                });
+            }
+            let version = task.version + 1;
+            let task = task.change_version(version);
+            save_labels(conn, &task)?;
+            save_blockers(conn, &task)?;
+            Ok(task)
+        })
+    }
+
+    fn delete(&self, task_id: &TaskId) -> Result<(), TaskRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let affected_rows = conn
+            .execute(
+                "DELETE FROM tasks WHERE id = :id",
+                named_params! {
+                    ":id": task_id.to_string(),
+                },
+            )
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+        if affected_rows == 0 {
+            return Err(TaskRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    fn begin_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let conn = self.db.get_conn();
+        if !conn.is_autocommit() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        conn.execute_batch("BEGIN IMMEDIATE")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+
+    fn commit_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let conn = self.db.get_conn();
+        if conn.is_autocommit() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        conn.execute_batch("COMMIT")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+
+    fn rollback_transaction(&self) -> Result<(), TaskRepositoryError> {
+        let conn = self.db.get_conn();
+        if conn.is_autocommit() {
+            return Err(TaskRepositoryError::TransactionState);
+        }
+
+        conn.execute_batch("ROLLBACK")
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+}
+
+impl<'a> TaskEventRepository for TaskSqliteRepository<'a> {
+    fn append(&self, event: TaskEvent) -> Result<(), TaskRepositoryError> {
+        let conn = self.db.get_conn();
+        let changes: Vec<TaskFieldChangeRecord> = event
+            .changes
+            .into_iter()
+            .map(|change| TaskFieldChangeRecord {
+                field: change.field,
+                before: change.before,
+                after: change.after,
+            })
+            .collect();
+        let changes = serde_json::to_string(&changes)
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+        conn.execute(
+            "INSERT INTO task_events (task_id, kind, actor, changes, occurred_at)
+             VALUES (:task_id, :kind, :actor, :changes, :occurred_at)",
+            named_params! {
+                ":task_id": event.task_id.to_string(),
+                ":kind": event.kind.to_string(),
+                ":actor": event.actor,
+                ":changes": changes,
+                ":occurred_at": datetime_to_text(event.occurred_at.naive_utc()),
+            },
+        )
+        .map(|_| ())
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+    }
+
+    fn history(&self, task_id: &TaskId) -> Result<Vec<TaskEvent>, TaskRepositoryError> {
+        let conn = self.db.get_conn();
+
+        let mut statement = conn
+            .prepare(
+                "SELECT kind, actor, changes, occurred_at FROM task_events
+                 WHERE task_id = :task_id ORDER BY id",
+            )
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+        let rows: Vec<(String, Option<String>, String, NaiveDateTime)> = statement
+            .query_map(
+                named_params! {
+                    ":task_id": task_id.to_string(),
+                },
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .and_then(|rows| rows.collect())
+            .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|(kind, actor, changes, occurred_at)| {
+                let changes: Vec<TaskFieldChangeRecord> = serde_json::from_str(&changes)
+                    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+                Ok(TaskEvent {
+                    task_id: task_id.clone(),
+                    kind: TaskEventKind::try_from(kind.as_str()).map_err(|_| {
+                        TaskRepositoryError::DbError(format!("invalid task event kind: {}", kind))
+                    })?,
+                    changes: changes
+                        .into_iter()
+                        .map(|change| TaskFieldChange {
+                            field: change.field,
+                            before: change.before,
+                            after: change.after,
+                        })
+                        .collect(),
+                    actor,
+                    occurred_at: occurred_at.and_utc(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskFieldChangeRecord {
+    field: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+fn task_from_row(row: &Row, workflow: &TaskWorkflow) -> Result<Task, rusqlite::Error> {
+    let id: String = row.get("id")?;
+    let title: String = row.get("title")?;
+    let description: String = row.get("description")?;
+    let status: i32 = row.get("status")?;
+    let priority: i32 = row.get("priority")?;
+    let due_at: Option<NaiveDateTime> = row.get("due_at")?;
+    let due_all_day: bool = row.get("due_all_day")?;
+    let parent_id: Option<String> = row.get("parent_id")?;
+    let recurrence: Option<String> = row.get("recurrence")?;
+    let created_at: NaiveDateTime = row.get("created_at")?;
+    let updated_at: NaiveDateTime = row.get("updated_at")?;
+    let completed_at: Option<NaiveDateTime> = row.get("completed_at")?;
+    let deleted_at: Option<NaiveDateTime> = row.get("deleted_at")?;
+    let archived_at: Option<NaiveDateTime> = row.get("archived_at")?;
+    let version: u64 = row.get("version")?;
+
+    Ok(Task::new(
+        TaskId::try_from(id.as_str()).unwrap(),
+        TaskTitle::try_from(title.as_str()).unwrap(),
+        TaskDescription::try_from(description.as_str()).unwrap(),
+        workflow
+            .status(status)
+            .unwrap_or_else(|_| TaskStatus::new(status, TaskStatusCategory::Todo)),
+        created_at.and_utc(),
+    )
+    .change_priority(TaskPriority::try_from(priority).unwrap())
+    .change_due_at(due_at.map(|due_at| TaskDueDate::from_deadline(due_at.and_utc(), due_all_day)))
+    .change_parent(parent_id.map(|parent_id| TaskId::try_from(parent_id.as_str()).unwrap()))
+    .change_recurrence(
+        recurrence.map(|recurrence| TaskRecurrence::try_from(recurrence.as_str()).unwrap()),
+    )
+    .change_updated_at(updated_at.and_utc())
+    .change_completed_at(completed_at.map(|completed_at| completed_at.and_utc()))
+    .change_deleted_at(deleted_at.map(|deleted_at| deleted_at.and_utc()))
+    .change_archived_at(archived_at.map(|archived_at| archived_at.and_utc()))
+    .change_version(version))
+}
+
+fn load_labels(conn: &Connection, tasks: Vec<Task>) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let rows = select_pairs(
+        conn,
+        "SELECT task_id, label_id FROM task_labels WHERE task_id IN ({}) ORDER BY label_id",
+        &tasks,
+    )?;
+
+    let mut labels: HashMap<String, Vec<LabelId>> = HashMap::new();
+    for (task_id, label_id) in rows {
+        labels
+            .entry(task_id)
+            .or_default()
+            .push(LabelId::try_from(label_id.as_str()).unwrap());
+    }
+
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let task_labels = labels.remove(&task.id.to_string()).unwrap_or_default();
+            task.change_labels(task_labels)
+        })
+        .collect())
+}
+
+fn save_labels(conn: &Connection, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.execute(
+        "DELETE FROM task_labels WHERE task_id = :task_id",
+        named_params! {
+            ":task_id": task.id.to_string(),
+        },
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    for label_id in &task.labels {
+        conn.execute(
+            "INSERT INTO task_labels (task_id, label_id) VALUES (:task_id, :label_id)",
+            named_params! {
+                ":task_id": task.id.to_string(),
+                ":label_id": label_id.to_string(),
+            },
+        )
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn load_blockers(conn: &Connection, tasks: Vec<Task>) -> Result<Vec<Task>, TaskRepositoryError> {
+    if tasks.is_empty() {
+        return Ok(tasks);
+    }
+
+    let rows = select_pairs(
+        conn,
+        "SELECT task_id, blocked_by_id FROM task_dependencies
+         WHERE task_id IN ({}) ORDER BY blocked_by_id",
+        &tasks,
+    )?;
+
+    let mut blockers: HashMap<String, Vec<TaskId>> = HashMap::new();
+    for (task_id, blocked_by_id) in rows {
+        blockers
+            .entry(task_id)
+            .or_default()
+            .push(TaskId::try_from(blocked_by_id.as_str()).unwrap());
+    }
+
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let task_blockers = blockers.remove(&task.id.to_string()).unwrap_or_default();
+            task.change_blocked_by(task_blockers)
+        })
+        .collect())
+}
+
+fn save_blockers(conn: &Connection, task: &Task) -> Result<(), TaskRepositoryError> {
+    conn.execute(
+        "DELETE FROM task_dependencies WHERE task_id = :task_id",
+        named_params! {
+            ":task_id": task.id.to_string(),
+        },
+    )
+    .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+
+    for blocked_by_id in &task.blocked_by {
+        conn.execute(
+            "INSERT INTO task_dependencies (task_id, blocked_by_id) VALUES (:task_id, :blocked_by_id)",
+            named_params! {
+                ":task_id": task.id.to_string(),
+                ":blocked_by_id": blocked_by_id.to_string(),
+            },
+        )
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn select_pairs(
+    conn: &Connection,
+    sql: &str,
+    tasks: &[Task],
+) -> Result<Vec<(String, String)>, TaskRepositoryError> {
+    let placeholders = vec!["?"; tasks.len()].join(", ");
+    let ids = tasks.iter().map(|task| task.id.to_string());
+
+    let mut statement = conn
+        .prepare(&sql.replace("{}", &placeholders))
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))?;
+    statement
+        .query_map(params_from_iter(ids), |row| Ok((row.get(0)?, row.get(1)?)))
+        .and_then(|rows| rows.collect())
+        .map_err(|e| TaskRepositoryError::DbError(e.to_string()))
+}
+
+fn task_params(task: &Task) -> Vec<(&'static str, Value)> {
+    vec![
+        (":id", Value::Text(task.id.to_string())),
+        (":title", Value::Text(task.title.to_string())),
+        (":description", Value::Text(task.description.to_string())),
+        (":status", Value::Integer(task.status.to_int().into())),
+        (":priority", Value::Integer(task.priority.to_int().into())),
+        (
+            ":due_at",
+            optional_text(
+                task.due_at
+                    .as_ref()
+                    .map(|due_at| datetime_to_text(due_at.deadline().naive_utc())),
+            ),
+        ),
+        (
+            ":due_all_day",
+            Value::Integer(
+                task.due_at
+                    .as_ref()
+                    .is_some_and(|due_at| due_at.is_all_day()) as i64,
+            ),
+        ),
+        (
+            ":parent_id",
+            optional_text(
+                task.parent_id
+                    .as_ref()
+                    .map(|parent_id| parent_id.to_string()),
+            ),
+        ),
+        (
+            ":recurrence",
+            optional_text(
+                task.recurrence
+                    .as_ref()
+                    .map(|recurrence| recurrence.to_string()),
+            ),
+        ),
+        (
+            ":created_at",
+            Value::Text(datetime_to_text(task.created_at.naive_utc())),
+        ),
+        (
+            ":updated_at",
+            Value::Text(datetime_to_text(task.updated_at.naive_utc())),
+        ),
+        (
+            ":completed_at",
+            optional_text(
+                task.completed_at
+                    .map(|completed_at| datetime_to_text(completed_at.naive_utc())),
+            ),
+        ),
+        (
+            ":deleted_at",
+            optional_text(
+                task.deleted_at
+                    .map(|deleted_at| datetime_to_text(deleted_at.naive_utc())),
+            ),
+        ),
+        (
+            ":archived_at",
+            optional_text(
+                task.archived_at
+                    .map(|archived_at| datetime_to_text(archived_at.naive_utc())),
+            ),
+        ),
+        (":version", Value::Integer(task.version as i64)),
+    ]
+}
+
+fn bind<'p>(params: &'p [(&'static str, Value)]) -> Vec<(&'static str, &'p dyn ToSql)> {
+    params
+        .iter()
+        .map(|(name, value)| (*name, value as &dyn ToSql))
+        .collect()
+}
+
+fn optional_text(value: Option<String>) -> Value {
+    value.map(Value::Text).unwrap_or(Value::Null)
+}
+
+fn keyset_value(param: TaskKeysetParam) -> Value {
+    match param {
+        TaskKeysetParam::Text(value) => Value::Text(value),
+        TaskKeysetParam::Int(value) => Value::Integer(value.into()),
+        TaskKeysetParam::DateTime(value) => Value::Text(datetime_to_text(value.naive_utc())),
+        TaskKeysetParam::Id(value) => Value::Text(value.to_string()),
+    }
+}
+
+fn datetime_to_text(value: NaiveDateTime) -> String {
+    value.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::label::label::Label;
+    use crate::domain::label::label_color::LabelColor;
+    use crate::domain::label::label_name::LabelName;
+    use crate::domain::label::label_repository::LabelRepository;
+    use crate::repositories::label::label_sqlite_repository::LabelSqliteRepository;
+    use crate::repositories::task::task_repository_behavior::{self, register_test_data};
+    use chrono::{DateTime, Utc};
+
+    #[test]
+    fn search_when_tasks_are_registered_then_returns_task_list() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_registered_then_returns_task_list(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_tasks_are_not_registered_then_returns_empty_list() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_tasks_are_not_registered_then_returns_empty_list(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_status_filter_then_returns_matching_tasks() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_status_filter_then_returns_matching_tasks(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_limit_then_returns_page_and_total() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_limit_then_returns_page_and_total(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_query_has_cursor_then_returns_tasks_after_it() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_query_has_cursor_then_returns_tasks_after_it(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_due_filters_then_returns_matching_tasks() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_due_filters_then_returns_matching_tasks(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_sorted_by_priority_then_orders_by_priority_then_due_date() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_sorted_by_priority_then_orders_by_priority_then_due_date(TaskSqliteRepository::new(&db, &workflow));
+    }
+
+    #[test]
+    fn get_by_id_when_valid_value_then_returns_task() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::get_by_id_when_valid_value_then_returns_task(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn register_when_valid_value_then_returns_task() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::register_when_valid_value_then_returns_task(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_task_is_trashed_then_returns_it_only_from_trash() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_trashed_then_returns_it_only_from_trash(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_task_is_archived_then_returns_it_only_when_archived_are_included() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::search_when_task_is_archived_then_returns_it_only_when_archived_are_included(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn rollback_transaction_when_changes_were_made_then_discards_them() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::rollback_transaction_when_changes_were_made_then_discards_them(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn commit_transaction_when_changes_were_made_then_keeps_them() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::commit_transaction_when_changes_were_made_then_keeps_them(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn update_when_valid_value_then_returns_task() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_valid_value_then_returns_task(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn update_when_version_is_stale_then_returns_conflict() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::update_when_version_is_stale_then_returns_conflict(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn delete_when_valid_id_then_returns_empty_task() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        task_repository_behavior::delete_when_valid_id_then_returns_empty_task(
+            TaskSqliteRepository::new(&db, &workflow),
+        );
+    }
+
+    #[test]
+    fn search_when_label_filter_then_returns_labeled_tasks() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let label = LabelSqliteRepository::new(&db)
+            .register(Label::new(
+                LabelId::new(),
+                LabelName::try_from("bug").unwrap(),
+                LabelColor::try_from("#d73a4a").unwrap(),
+            ))
+            .unwrap();
+        let task = repository.get_by_id(&ids[2]).unwrap();
+        repository
+            .update(task.attach_label(label.id.clone()))
+            .unwrap();
+
+        let query = TaskQuery {
+            label: Some(label.id),
+            ..TaskQuery::default()
+        };
+        let page = repository.search(&query).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.tasks[0].id, ids[2]);
+    }
+
+    #[test]
+    fn register_when_id_already_exists_then_returns_already_exists() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+
+        let result = repository.register(task);
+        assert!(matches!(result, Err(TaskRepositoryError::AlreadyExists)));
+    }
+
+    #[test]
+    fn delete_when_task_blocks_others_then_removes_dependency() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository.update(task.add_blocker(ids[1].clone())).unwrap();
+
+        repository.delete(&ids[1]).unwrap();
+        assert!(repository.get_by_id(&ids[0]).unwrap().blocked_by.is_empty());
+    }
+
+    #[test]
+    fn update_when_timestamps_and_recurrence_change_then_round_trips() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let completed_at = DateTime::parse_from_rfc3339("2026-10-18T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let task = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_due_at(Some(TaskDueDate::from_deadline(completed_at, true)))
+            .change_recurrence(Some(TaskRecurrence::try_from("FREQ=WEEKLY").unwrap()))
+            .change_updated_at(completed_at)
+            .change_completed_at(Some(completed_at));
+        repository.update(task).unwrap();
+
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        assert!(task.due_at.as_ref().unwrap().is_all_day());
+        assert_eq!(task.recurrence.unwrap().to_string(), "FREQ=WEEKLY");
+        assert_eq!(task.created_at, DateTime::UNIX_EPOCH);
+        assert_eq!(task.updated_at, completed_at);
+        assert_eq!(task.completed_at, Some(completed_at));
+    }
+
+    #[test]
+    fn history_when_events_are_appended_then_returns_events_in_order() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let before = repository.get_by_id(&ids[0]).unwrap();
+        let after = before
+            .clone()
+            .change_title(TaskTitle::try_from("AAA2").unwrap());
+        repository
+            .append(TaskEvent::created(&before, None, DateTime::UNIX_EPOCH))
+            .unwrap();
+        repository
+            .append(TaskEvent::updated(
+                &before,
+                &after,
+                Some("alice"),
+                DateTime::UNIX_EPOCH,
+            ))
+            .unwrap();
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].kind, TaskEventKind::Created);
+        assert_eq!(history[1].kind, TaskEventKind::Updated);
+        assert_eq!(history[1].actor.as_deref(), Some("alice"));
+        assert_eq!(history[1].changes[0].field, "title");
+        assert!(repository.history(&ids[1]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn history_when_event_kind_is_unknown_then_returns_db_error() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        db.get_conn()
+            .execute(
+                "INSERT INTO task_events (task_id, kind, actor, changes, occurred_at)
+                 VALUES (:task_id, 'renamed', NULL, '[]', '1970-01-01 00:00:00')",
+                named_params! {
+                    ":task_id": ids[0].to_string(),
+                },
+            )
+            .unwrap();
+
+        let result = repository.history(&ids[0]);
+        assert!(matches!(result, Err(TaskRepositoryError::DbError(_))));
+    }
+}
@@ -0,0 +1,159 @@
+use axum::http::Method;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Param(String),
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PathParams {
+    values: HashMap<String, String>,
+}
+
+impl PathParams {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|value| value.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RouteMatch<R> {
+    Found(R, PathParams),
+    MethodNotAllowed,
+    NotFound,
+}
+
+pub struct Router<R> {
+    routes: Vec<(Method, Vec<Segment>, R)>,
+}
+
+impl<R: Copy> Router<R> {
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    pub fn add(mut self, method: Method, pattern: &str, route: R) -> Self {
+        let segments = split_path(pattern)
+            .map(|segment| match segment.strip_prefix('{') {
+                Some(name) => Segment::Param(String::from(name.trim_end_matches('}'))),
+                None => Segment::Literal(String::from(segment)),
+            })
+            .collect();
+
+        self.routes.push((method, segments, route));
+        self
+    }
+
+    pub fn resolve(&self, method: &Method, url: &str) -> RouteMatch<R> {
+        let path = url.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = split_path(path).collect();
+
+        let mut path_matched = false;
+        for (route_method, pattern, route) in &self.routes {
+            let params = match match_segments(pattern, &segments) {
+                Some(params) => params,
+                None => continue,
+            };
+
+            path_matched = true;
+            if route_method == method {
+                return RouteMatch::Found(*route, params);
+            }
+        }
+
+        if path_matched {
+            RouteMatch::MethodNotAllowed
+        } else {
+            RouteMatch::NotFound
+        }
+    }
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+fn match_segments(pattern: &[Segment], segments: &[&str]) -> Option<PathParams> {
+    if pattern.len() != segments.len() {
+        return None;
+    }
+
+    let mut params = PathParams::default();
+    for (expected, actual) in pattern.iter().zip(segments) {
+        match expected {
+            Segment::Literal(literal) if literal == actual => {}
+            Segment::Literal(_) => return None,
+            Segment::Param(name) => {
+                params.values.insert(name.clone(), String::from(*actual));
+            }
+        }
+    }
+
+    Some(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TestRoute {
+        List,
+        Get,
+        Delete,
+    }
+
+    fn router() -> Router<TestRoute> {
+        Router::new()
+            .add(Method::GET, "/api/v1/tasks", TestRoute::List)
+            .add(Method::GET, "/api/v1/tasks/{id}", TestRoute::Get)
+            .add(Method::DELETE, "/api/v1/tasks/{id}", TestRoute::Delete)
+    }
+
+    #[test]
+    fn resolve_when_literal_path_matches_then_returns_route() {
+        let route_match = router().resolve(&Method::GET, "/api/v1/tasks");
+        assert_eq!(
+            route_match,
+            RouteMatch::Found(TestRoute::List, PathParams::default())
+        );
+    }
+
+    #[test]
+    fn resolve_when_path_has_param_then_returns_extracted_value() {
+        let route_match = router().resolve(&Method::DELETE, "/api/v1/tasks/abc");
+        match route_match {
+            RouteMatch::Found(route, params) => {
+                assert_eq!(route, TestRoute::Delete);
+                assert_eq!(params.get("id"), Some("abc"));
+            }
+            _ => panic!("route was not found"),
+        }
+    }
+
+    #[test]
+    fn resolve_when_url_has_query_string_then_ignores_it() {
+        let route_match = router().resolve(&Method::GET, "/api/v1/tasks/abc?x=1");
+        match route_match {
+            RouteMatch::Found(route, params) => {
+                assert_eq!(route, TestRoute::Get);
+                assert_eq!(params.get("id"), Some("abc"));
+            }
+            _ => panic!("route was not found"),
+        }
+    }
+
+    #[test]
+    fn resolve_when_method_does_not_match_then_returns_method_not_allowed() {
+        let route_match = router().resolve(&Method::PUT, "/api/v1/tasks/abc");
+        assert_eq!(route_match, RouteMatch::MethodNotAllowed);
+    }
+
+    #[test]
+    fn resolve_when_path_does_not_match_then_returns_not_found() {
+        let route_match = router().resolve(&Method::GET, "/api/v1/tasks/abc/def");
+        assert_eq!(route_match, RouteMatch::NotFound);
+    }
+}
@@ -1,97 +1,793 @@
-use crate::application_config::ApplicationConfig;
+use crate::application_config::{ApplicationConfig, DbConfig, StorageBackend};
+use crate::controllers::http::{Header, Request, Response};
+use crate::controllers::label_controller::LabelController;
+use crate::controllers::problem::{PROBLEM_CONTENT_TYPE, Problem};
 use crate::controllers::task_controller::TaskController;
+use crate::controllers::task_export_controller::{TASK_EXPORT_CONTENT_TYPE, TaskExportController};
+use crate::controllers::workflow_controller::WorkflowController;
+use crate::domain::clock::SystemClock;
+use crate::domain::label::label_repository::LabelRepository;
+use crate::domain::task::task_async_repository::TaskAsyncRepository;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_query::{TaskPage, TaskQuery};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
 use crate::repositories::app_db::AppDb;
+use crate::repositories::label::label_file_repository::LabelFileRepository;
+use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+use crate::repositories::label::label_mysql_repository::LabelMysqlRepository;
+use crate::repositories::label::label_postgres_repository::LabelPostgresRepository;
+use crate::repositories::label::label_sqlite_repository::LabelSqliteRepository;
+use crate::repositories::postgres_db::PostgresDb;
+use crate::repositories::sqlite_db::{SQLITE_MEMORY_PATH, SqliteDb};
+use crate::repositories::task::task_event_sourced_repository::TaskEventSourcedRepository;
+use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
 use crate::repositories::task::task_mysql_repository::TaskMysqlRepository;
-use tiny_http::{Method, Response};
+use crate::repositories::task::task_postgres_async_repository::TaskPostgresAsyncRepository;
+use crate::repositories::task::task_postgres_repository::TaskPostgresRepository;
+use crate::repositories::task::task_sqlite_repository::TaskSqliteRepository;
+use crate::router::{PathParams, RouteMatch, Router};
+use crate::use_cases::task::purge_trash::PurgeTrash;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Method, StatusCode, header};
+use axum::response::IntoResponse;
+use futures_util::{Stream, TryStreamExt};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, watch};
+
+pub const SERVER_DEFAULT_WORKERS: usize = 4;
+pub const SERVER_DEFAULT_QUEUE_DEPTH: usize = 64;
+pub const SERVER_DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+const SERVER_MAX_BODY_SIZE: usize = 1024 * 1024;
+const SERVER_RUNTIME_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+const SERVER_STORAGE_CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+const TASK_TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, Copy)]
+enum Route {
+    ListTasks,
+    RegisterTask,
+    BatchTasks,
+    ExportTasks,
+    GetTask,
+    UpdateTask,
+    DeleteTask,
+    RestoreTask,
+    ListTrash,
+    ArchiveTask,
+    ArchiveDoneTasks,
+    ListTaskChildren,
+    GetTaskHistory,
+    AddTaskDependency,
+    RemoveTaskDependency,
+    AttachTaskLabel,
+    DetachTaskLabel,
+    ListLabels,
+    RegisterLabel,
+    GetLabel,
+    UpdateLabel,
+    DeleteLabel,
+    GetWorkflow,
+}
 
 pub struct Server {
     config: ApplicationConfig,
-    app_db: AppDb,
+}
+
+#[derive(Debug)]
+pub enum ServerError {
+    Io(std::io::Error),
+    Storage(String),
+    ShutdownTimeout,
+}
+
+enum Storage {
+    Memory(Mutex<(TaskInMemoryRepository, LabelInMemoryRepository)>),
+    Mysql(AppDb),
+    EventSourced(Mutex<(TaskEventSourcedRepository, LabelFileRepository)>),
+    Sqlite(Vec<Mutex<SqliteDb>>),
+    Postgres(PostgresDb, Arc<TaskPostgresAsyncRepository>),
+}
+
+struct ServerState {
+    server: Arc<Server>,
+    storage: RwLock<Option<Storage>>,
+    stopping: AtomicBool,
+    router: Router<Route>,
+    permits: Arc<Semaphore>,
+}
+
+trait RepositoryHandler {
+    type Output;
+
+    fn handle<T: TaskRepository + TaskEventRepository, L: LabelRepository>(
+        self,
+        repository: &mut T,
+        label_repository: &mut L,
+    ) -> Self::Output;
+}
+
+struct Dispatch<'r> {
+    server: &'r Server,
+    route: Route,
+    params: PathParams,
+    request: &'r Request,
+}
+
+struct PurgeTrashJob<'s> {
+    server: &'s Server,
+    stopping: &'s AtomicBool,
+}
+
+struct SearchTasksJob {
+    query: TaskQuery,
+}
+
+struct StorageTaskReader {
+    state: Arc<ServerState>,
+    _permit: OwnedSemaphorePermit,
 }
 
 impl Server {
     pub fn new(application_config: ApplicationConfig) -> Self {
-        let app_db = match AppDb::new(application_config.db_config()) {
-            Ok(app_db) => app_db,
-            Err(e) => panic!("{}", e),
-        };
-
         Self {
             config: application_config,
-            app_db,
         }
     }
 
-    pub fn start(&self) {
-        let server = tiny_http::Server::http(format!(
+    pub fn start(self) -> Result<(), ServerError> {
+        let server = Arc::new(self);
+        let workers = server.config.server_config().workers();
+        let storage = server.open_storage(workers)?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .max_blocking_threads(workers)
+            .build()
+            .map_err(ServerError::Io)?;
+        let result = runtime.block_on(server.serve(storage, workers));
+        runtime.shutdown_timeout(SERVER_RUNTIME_SHUTDOWN_TIMEOUT);
+        result
+    }
+
+    fn open_storage(&self, workers: usize) -> Result<Storage, ServerError> {
+        let workflow = self.config.workflow();
+        let storage_config = self.config.storage_config();
+        let storage = match storage_config.backend() {
+            StorageBackend::Memory => Storage::Memory(Mutex::new((
+                TaskInMemoryRepository::new(),
+                LabelInMemoryRepository::new(),
+            ))),
+            StorageBackend::Mysql => Storage::Mysql(
+                AppDb::new(self.db_config()?).map_err(|e| ServerError::Storage(e.to_string()))?,
+            ),
+            StorageBackend::EventSourced => {
+                let repository = TaskEventSourcedRepository::open(
+                    storage_config.event_log_path(),
+                    storage_config.snapshot_interval(),
+                    workflow,
+                )
+                .map_err(|e| ServerError::Storage(e.to_string()))?;
+                if repository.discarded_bytes() > 0 {
+                    eprintln!(
+                        "error: discarded {} bytes of torn records at the end of {}",
+                        repository.discarded_bytes(),
+                        storage_config.event_log_path().display()
+                    );
+                }
+                let label_repository = LabelFileRepository::open(&storage_config.labels_path())
+                    .map_err(|e| ServerError::Storage(e.to_string()))?;
+                Storage::EventSourced(Mutex::new((repository, label_repository)))
+            }
+            StorageBackend::Sqlite => {
+                let path = storage_config.sqlite_path();
+                let connections = match path.as_os_str() == SQLITE_MEMORY_PATH {
+                    true => 1,
+                    false => workers,
+                };
+                let connections = (0..connections)
+                    .map(|_| SqliteDb::open(path).map(Mutex::new))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| ServerError::Storage(e.to_string()))?;
+                Storage::Sqlite(connections)
+            }
+            StorageBackend::Postgres => {
+                let db_config = self.db_config()?;
+                let config = db_config
+                    .postgres_url("app_db")
+                    .parse()
+                    .map_err(|e: postgres::Error| ServerError::Storage(e.to_string()))?;
+                let postgres_db = PostgresDb::new(db_config).map_err(ServerError::Storage)?;
+                Storage::Postgres(
+                    postgres_db,
+                    Arc::new(TaskPostgresAsyncRepository::new(config, workflow)),
+                )
+            }
+        };
+
+        Ok(storage)
+    }
+
+    fn db_config(&self) -> Result<&DbConfig, ServerError> {
+        self.config
+            .db_config()
+            .ok_or_else(|| ServerError::Storage(String::from("database is not configured")))
+    }
+
+    async fn serve(self: Arc<Self>, storage: Storage, workers: usize) -> Result<(), ServerError> {
+        let listener = TcpListener::bind(format!(
             "{}:{}",
             self.config.server_config().addr(),
             self.config.server_config().port()
         ))
-        .unwrap();
+        .await
+        .map_err(ServerError::Io)?;
 
         println!(
             "Listening for requests at {}://{}",
             self.config.server_config().scheme(),
-            server.server_addr()
+            listener.local_addr().map_err(ServerError::Io)?
         );
+        println!("info: serving requests with {} worker(s)", workers);
 
-        let mut repository = TaskMysqlRepository::new(&self.app_db);
+        let state = Arc::new(ServerState {
+            server: self.clone(),
+            storage: RwLock::new(Some(storage)),
+            stopping: AtomicBool::new(false),
+            router: routes(),
+            permits: Arc::new(Semaphore::new(
+                workers + self.config.server_config().queue_depth(),
+            )),
+        });
+        let purge = tokio::spawn(purge_trash(state.clone()));
 
-        loop {
-            let mut request = match server.recv() {
-                Ok(rq) => rq,
-                Err(e) => {
-                    println!("error: {}", e);
-                    break;
-                }
-            };
+        let (shutdown, shutdown_requested) = watch::channel(false);
+        let stopping = state.clone();
+        tokio::spawn(async move {
+            shutdown_signal().await;
+            stopping.stopping.store(true, Ordering::Relaxed);
+            let _ = shutdown.send(true);
+        });
 
-            match (request.url(), request.method()) {
-                ("/api/v1/tasks", Method::Get) => {
-                    let controller = TaskController::new(&mut repository);
-                    let response = controller.get();
-                    match request.respond(response) {
-                        Ok(_) => (),
-                        Err(e) => {
-                            println!("error (GET /api/v1/tasks): {}", e);
-                        }
-                    }
-                }
-                ("/api/v1/tasks", Method::Post) => {
-                    let mut controller = TaskController::new(&mut repository);
-                    let response = controller.post(&mut request);
-                    match request.respond(response) {
-                        Ok(_) => (),
-                        Err(e) => {
-                            println!("error(POST /api/v1/tasks): {}", e);
-                        }
-                    }
-                }
-                ("/api/v1/tasks", Method::Patch) => {
-                    let mut controller = TaskController::new(&mut repository);
-                    let response = controller.patch(&mut request);
-                    match request.respond(response) {
-                        Ok(_) => (),
-                        Err(e) => {
-                            println!("error (Patch /api/v1/tasks): {}", e);
-                        }
-                    }
-                }
-                ("/api/v1/tasks", Method::Delete) => {
-                    let mut controller = TaskController::new(&mut repository);
-                    let response = controller.delete(&mut request);
-                    match request.respond(response) {
-                        Ok(_) => (),
-                        Err(e) => {
-                            println!("error (Delete /api/v1/tasks): {}", e);
-                        }
+        let mut draining = shutdown_requested.clone();
+        let app = axum::Router::new()
+            .fallback(handle)
+            .with_state(state.clone());
+        let serve = axum::serve(listener, app).with_graceful_shutdown(async move {
+            let _ = draining.wait_for(|requested| *requested).await;
+            println!("info: shutting down, draining in-flight requests");
+        });
+
+        let mut deadline = shutdown_requested;
+        let timeout = self.config.server_config().shutdown_timeout();
+        let result = tokio::select! {
+            result = serve => result.map_err(ServerError::Io),
+            _ = async {
+                let _ = deadline.wait_for(|requested| *requested).await;
+                tokio::time::sleep(timeout).await;
+            } => Err(ServerError::ShutdownTimeout),
+        };
+
+        state.stopping.store(true, Ordering::Relaxed);
+        purge.abort();
+        let _ = purge.await;
+        let closing = tokio::task::spawn_blocking(move || state.close_storage());
+        match tokio::time::timeout(SERVER_STORAGE_CLOSE_TIMEOUT, closing).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => eprintln!("error: failed to close storage: {}", e),
+            Err(_) => eprintln!(
+                "error: storage was not closed within {}s",
+                SERVER_STORAGE_CLOSE_TIMEOUT.as_secs()
+            ),
+        }
+        result
+    }
+
+    fn dispatch<T: TaskRepository + TaskEventRepository, L: LabelRepository>(
+        &self,
+        route: Route,
+        params: &PathParams,
+        request: &Request,
+        repository: &mut T,
+        label_repository: &mut L,
+    ) -> Response {
+        let workflow = self.config.workflow();
+        let clock = &SystemClock;
+        let id = params.get("id").unwrap_or_default().to_string();
+        let label_id = params.get("label_id").unwrap_or_default().to_string();
+        let blocker_id = params.get("blocker_id").unwrap_or_default().to_string();
+        match route {
+            Route::ListTasks => TaskController::new(repository, workflow, clock).get(request),
+            Route::RegisterTask => TaskController::new(repository, workflow, clock).post(request),
+            Route::BatchTasks => TaskController::new(repository, workflow, clock)
+                .batch(request, self.config.task_config().delete_policy()),
+            Route::ExportTasks => {
+                Problem::internal("Streaming route was dispatched to a blocking handler").into()
+            }
+            Route::GetTask => TaskController::new(repository, workflow, clock).get_by_id(&id),
+            Route::UpdateTask => {
+                TaskController::new(repository, workflow, clock).patch(&id, request)
+            }
+            Route::DeleteTask => TaskController::new(repository, workflow, clock).delete(
+                &id,
+                request,
+                self.config.task_config().delete_policy(),
+            ),
+            Route::RestoreTask => {
+                TaskController::new(repository, workflow, clock).restore(&id, request)
+            }
+            Route::ListTrash => TaskController::new(repository, workflow, clock).get_trash(request),
+            Route::ArchiveTask => {
+                TaskController::new(repository, workflow, clock).archive(&id, request)
+            }
+            Route::ArchiveDoneTasks => {
+                TaskController::new(repository, workflow, clock).archive_done(request)
+            }
+            Route::ListTaskChildren => {
+                TaskController::new(repository, workflow, clock).get_children(&id, request)
+            }
+            Route::GetTaskHistory => {
+                TaskController::new(repository, workflow, clock).get_history(&id)
+            }
+            Route::AddTaskDependency => {
+                TaskController::new(repository, workflow, clock).add_dependency(&id, &blocker_id)
+            }
+            Route::RemoveTaskDependency => {
+                TaskController::new(repository, workflow, clock).remove_dependency(&id, &blocker_id)
+            }
+            Route::AttachTaskLabel => TaskController::new(repository, workflow, clock)
+                .attach_label(&id, &label_id, label_repository),
+            Route::DetachTaskLabel => {
+                TaskController::new(repository, workflow, clock).detach_label(&id, &label_id)
+            }
+            Route::ListLabels => LabelController::new(label_repository, repository).get(),
+            Route::RegisterLabel => {
+                LabelController::new(label_repository, repository).post(request)
+            }
+            Route::GetLabel => LabelController::new(label_repository, repository).get_by_id(&id),
+            Route::UpdateLabel => {
+                LabelController::new(label_repository, repository).patch(&id, request)
+            }
+            Route::DeleteLabel => LabelController::new(label_repository, repository).delete(&id),
+            Route::GetWorkflow => WorkflowController::new(workflow).get(),
+        }
+    }
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServerError::Io(e) => write!(f, "server error: {}", e),
+            ServerError::Storage(message) => write!(f, "failed to open storage: {}", message),
+            ServerError::ShutdownTimeout => {
+                write!(
+                    f,
+                    "shutdown deadline exceeded with requests still in flight"
+                )
+            }
+        }
+    }
+}
+
+impl ServerState {
+    fn with_repositories<H: RepositoryHandler>(&self, handler: H) -> Option<H::Output> {
+        let storage = self.storage.read().unwrap_or_else(PoisonError::into_inner);
+        storage
+            .as_ref()
+            .map(|storage| storage.with_repositories(&self.server, handler))
+    }
+
+    fn close_storage(&self) {
+        let storage = self
+            .storage
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(storage) = storage {
+            storage.close();
+        }
+    }
+}
+
+impl Storage {
+    fn close(self) {
+        match self {
+            Storage::Memory(_) | Storage::EventSourced(_) => {}
+            Storage::Mysql(app_db) => app_db.close(),
+            Storage::Sqlite(connections) => {
+                for connection in connections {
+                    let sqlite_db = connection
+                        .into_inner()
+                        .unwrap_or_else(PoisonError::into_inner);
+                    if let Err(e) = sqlite_db.close() {
+                        eprintln!("error: failed to close SQLite DB: {}", e);
                     }
                 }
-                _ => {
-                    let _ = request.respond(Response::new_empty(tiny_http::StatusCode(404)));
+                println!("info: closed DB connections");
+            }
+            Storage::Postgres(postgres_db, repository) => {
+                repository.close();
+                postgres_db.close();
+            }
+        }
+    }
+
+    fn with_repositories<H: RepositoryHandler>(&self, server: &Server, handler: H) -> H::Output {
+        let workflow = server.config.workflow();
+        match self {
+            Storage::Memory(repositories) => {
+                let mut repositories = lock(repositories);
+                let (repository, label_repository) = &mut *repositories;
+                handler.handle(repository, label_repository)
+            }
+            Storage::Mysql(app_db) => handler.handle(
+                &mut TaskMysqlRepository::new(app_db, workflow),
+                &mut LabelMysqlRepository::new(app_db),
+            ),
+            Storage::EventSourced(repositories) => {
+                let mut repositories = lock(repositories);
+                let (repository, label_repository) = &mut *repositories;
+                handler.handle(repository, label_repository)
+            }
+            Storage::Sqlite(connections) => {
+                let sqlite_db = connections
+                    .iter()
+                    .find_map(|connection| connection.try_lock().ok())
+                    .unwrap_or_else(|| lock(&connections[0]));
+                handler.handle(
+                    &mut TaskSqliteRepository::new(&sqlite_db, workflow),
+                    &mut LabelSqliteRepository::new(&sqlite_db),
+                )
+            }
+            Storage::Postgres(postgres_db, _) => handler.handle(
+                &mut TaskPostgresRepository::new(postgres_db, workflow),
+                &mut LabelPostgresRepository::new(postgres_db),
+            ),
+        }
+    }
+}
+
+impl RepositoryHandler for Dispatch<'_> {
+    type Output = Response;
+
+    fn handle<T: TaskRepository + TaskEventRepository, L: LabelRepository>(
+        self,
+        repository: &mut T,
+        label_repository: &mut L,
+    ) -> Response {
+        self.server.dispatch(
+            self.route,
+            &self.params,
+            self.request,
+            repository,
+            label_repository,
+        )
+    }
+}
+
+impl RepositoryHandler for PurgeTrashJob<'_> {
+    type Output = ();
+
+    fn handle<T: TaskRepository + TaskEventRepository, L: LabelRepository>(
+        self,
+        repository: &mut T,
+        _: &mut L,
+    ) {
+        let mut use_case = PurgeTrash::new(
+            repository,
+            self.server.config.task_config().trash_retention(),
+            &SystemClock,
+        )
+        .with_cancellation(self.stopping);
+        match use_case.execute() {
+            Ok(0) => {}
+            Ok(purged) => println!("info: purged {} task(s) from the trash", purged),
+            Err(e) => eprintln!("error: failed to purge trash: {:?}", e),
+        }
+    }
+}
+
+impl RepositoryHandler for SearchTasksJob {
+    type Output = Result<TaskPage, TaskRepositoryError>;
+
+    fn handle<T: TaskRepository + TaskEventRepository, L: LabelRepository>(
+        self,
+        repository: &mut T,
+        _: &mut L,
+    ) -> Self::Output {
+        repository.search(&self.query)
+    }
+}
+
+impl TaskAsyncRepository for StorageTaskReader {
+    async fn search(&self, query: &TaskQuery) -> Result<TaskPage, TaskRepositoryError> {
+        let state = self.state.clone();
+        let job = SearchTasksJob {
+            query: query.clone(),
+        };
+        match tokio::task::spawn_blocking(move || state.with_repositories(job)).await {
+            Ok(Some(page)) => page,
+            Ok(None) => Err(TaskRepositoryError::DbError(
+                "storage is closed".to_string(),
+            )),
+            Err(e) => Err(TaskRepositoryError::DbError(e.to_string())),
+        }
+    }
+}
+
+impl IntoResponse for Response {
+    fn into_response(self) -> axum::response::Response {
+        let mut builder = axum::http::Response::builder().status(self.status());
+        if !self.headers().iter().any(|header| {
+            header
+                .name()
+                .eq_ignore_ascii_case(header::CONTENT_TYPE.as_str())
+        }) {
+            builder = builder.header(header::CONTENT_TYPE, "text/plain; charset=UTF-8");
+        }
+        for header in self.headers() {
+            builder = builder.header(header.name(), header.value());
+        }
+
+        match builder.body(Body::from(self.into_body())) {
+            Ok(response) => response,
+            Err(e) => {
+                eprintln!("error: failed to build response: {}", e);
+                internal_error("Error occurred during building response")
+            }
+        }
+    }
+}
+
+fn internal_error(detail: &str) -> axum::response::Response {
+    let response = Response::from(Problem::internal(detail));
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+        response.into_body(),
+    )
+        .into_response()
+}
+
+async fn handle(
+    State(state): State<Arc<ServerState>>,
+    request: axum::extract::Request,
+) -> axum::response::Response {
+    let (route, params) = match state
+        .router
+        .resolve(request.method(), &request.uri().to_string())
+    {
+        RouteMatch::Found(route, params) => (route, params),
+        RouteMatch::MethodNotAllowed => {
+            return Response::from(Problem::new(
+                405,
+                "route.method_not_allowed",
+                "Method not allowed",
+            ))
+            .into_response();
+        }
+        RouteMatch::NotFound => {
+            return Response::from(Problem::not_found("route.not_found", "Route not found"))
+                .into_response();
+        }
+    };
+    let Ok(permit) = state.permits.clone().try_acquire_owned() else {
+        return Response::from(Problem::new(503, "server.busy", "Server is busy"))
+            .with_header(Header::new("Retry-After", "1"))
+            .into_response();
+    };
+    if let Route::ExportTasks = route {
+        let request = Request::new(&request.uri().to_string(), Vec::new(), String::new());
+        return export_tasks(state, &request, permit);
+    }
+
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, SERVER_MAX_BODY_SIZE).await {
+        Ok(body) => String::from_utf8(body.to_vec()).ok(),
+        Err(_) => None,
+    };
+    let Some(body) = body else {
+        return Response::from(Problem::invalid_request_body()).into_response();
+    };
+    let headers = parts
+        .headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect();
+    let request = Request::new(&parts.uri.to_string(), headers, body);
+
+    let label = format!("{} {}", parts.method, parts.uri);
+    let response = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        let dispatch = Dispatch {
+            server: &state.server,
+            route,
+            params,
+            request: &request,
+        };
+        state.with_repositories(dispatch)
+    })
+    .await;
+    match response {
+        Ok(Some(response)) => {
+            if let Some(cause) = response.cause() {
+                eprintln!("error ({}): {}", label, cause);
+            }
+            response.into_response()
+        }
+        Ok(None) => Response::from(Problem::new(
+            503,
+            "server.shutting_down",
+            "Server is shutting down",
+        ))
+        .into_response(),
+        Err(e) => {
+            eprintln!("error ({}): {}", label, e);
+            Response::from(Problem::internal("Error occurred during handling request"))
+                .into_response()
+        }
+    }
+}
+
+fn export_tasks(
+    state: Arc<ServerState>,
+    request: &Request,
+    permit: OwnedSemaphorePermit,
+) -> axum::response::Response {
+    let postgres = match &*state.storage.read().unwrap_or_else(PoisonError::into_inner) {
+        Some(Storage::Postgres(_, repository)) => Some(repository.clone()),
+        _ => None,
+    };
+    match postgres {
+        Some(repository) => {
+            stream_lines(TaskExportController::new(repository, &SystemClock).export(request))
+        }
+        None => {
+            let reader = Arc::new(StorageTaskReader {
+                state: state.clone(),
+                _permit: permit,
+            });
+            stream_lines(TaskExportController::new(reader, &SystemClock).export(request))
+        }
+    }
+}
+
+fn stream_lines(
+    lines: Result<impl Stream<Item = Result<String, Response>> + Send + 'static, Response>,
+) -> axum::response::Response {
+    let lines = match lines {
+        Ok(lines) => lines.map_err(|response| {
+            let cause = response.cause().unwrap_or("unknown error").to_string();
+            eprintln!("error: failed to stream tasks: {}", cause);
+            std::io::Error::other(cause)
+        }),
+        Err(response) => return response.into_response(),
+    };
+
+    match axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, TASK_EXPORT_CONTENT_TYPE)
+        .body(Body::from_stream(lines))
+    {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("error: failed to build response: {}", e);
+            internal_error("Error occurred during building response")
+        }
+    }
+}
+
+async fn purge_trash(state: Arc<ServerState>) {
+    let mut interval = tokio::time::interval(TASK_TRASH_PURGE_INTERVAL);
+    loop {
+        interval.tick().await;
+        let Ok(permit) = state.permits.clone().acquire_owned().await else {
+            return;
+        };
+        let state = state.clone();
+        let purge = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            let job = PurgeTrashJob {
+                server: &state.server,
+                stopping: &state.stopping,
+            };
+            state.with_repositories(job);
+        });
+        if let Err(e) = purge.await {
+            eprintln!("error: failed to purge trash: {}", e);
+        }
+    }
+}
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
                 }
             }
+            Err(e) => {
+                eprintln!("error: failed to listen for SIGTERM: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
         }
     }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn routes() -> Router<Route> {
+    Router::new()
+        .add(Method::GET, "/api/v1/tasks", Route::ListTasks)
+        .add(Method::POST, "/api/v1/tasks", Route::RegisterTask)
+        .add(Method::POST, "/api/v1/tasks:batch", Route::BatchTasks)
+        .add(Method::GET, "/api/v1/tasks:export", Route::ExportTasks)
+        .add(Method::GET, "/api/v1/tasks/{id}", Route::GetTask)
+        .add(Method::PATCH, "/api/v1/tasks/{id}", Route::UpdateTask)
+        .add(Method::DELETE, "/api/v1/tasks/{id}", Route::DeleteTask)
+        .add(
+            Method::POST,
+            "/api/v1/tasks/{id}/restore",
+            Route::RestoreTask,
+        )
+        .add(Method::GET, "/api/v1/trash", Route::ListTrash)
+        .add(
+            Method::POST,
+            "/api/v1/tasks/{id}/archive",
+            Route::ArchiveTask,
+        )
+        .add(Method::POST, "/api/v1/archive", Route::ArchiveDoneTasks)
+        .add(
+            Method::GET,
+            "/api/v1/tasks/{id}/children",
+            Route::ListTaskChildren,
+        )
+        .add(
+            Method::GET,
+            "/api/v1/tasks/{id}/history",
+            Route::GetTaskHistory,
+        )
+        .add(
+            Method::PUT,
+            "/api/v1/tasks/{id}/dependencies/{blocker_id}",
+            Route::AddTaskDependency,
+        )
+        .add(
+            Method::DELETE,
+            "/api/v1/tasks/{id}/dependencies/{blocker_id}",
+            Route::RemoveTaskDependency,
+        )
+        .add(
+            Method::PUT,
+            "/api/v1/tasks/{id}/labels/{label_id}",
+            Route::AttachTaskLabel,
+        )
+        .add(
+            Method::DELETE,
+            "/api/v1/tasks/{id}/labels/{label_id}",
+            Route::DetachTaskLabel,
+        )
+        .add(Method::GET, "/api/v1/labels", Route::ListLabels)
+        .add(Method::POST, "/api/v1/labels", Route::RegisterLabel)
+        .add(Method::GET, "/api/v1/labels/{id}", Route::GetLabel)
+        .add(Method::PATCH, "/api/v1/labels/{id}", Route::UpdateLabel)
+        .add(Method::DELETE, "/api/v1/labels/{id}", Route::DeleteLabel)
+        .add(Method::GET, "/api/v1/workflow", Route::GetWorkflow)
 }
@@ -0,0 +1,8 @@
+pub mod delete_label;
+pub mod delete_label_command;
+pub mod register_label;
+pub mod register_label_command;
+pub mod register_label_result;
+pub mod update_label;
+pub mod update_label_command;
+pub mod update_label_result;
@@ -0,0 +1,113 @@
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::domain::task::task_query::{TASK_QUERY_MAX_LIMIT, TaskQuery, TaskTrashFilter};
+use crate::domain::task::task_repository::TaskRepository;
+use crate::use_cases::label::delete_label_command::DeleteLabelCommand;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DeleteLabelError {
+    LabelNotFound,
+    RepositoryError,
+}
+
+pub struct DeleteLabel<'a, L: LabelRepository, T: TaskRepository> {
+    label_repository: &'a mut L,
+    task_repository: &'a mut T,
+}
+
+impl<'a, L: LabelRepository, T: TaskRepository> DeleteLabel<'a, L, T> {
+    pub fn new(label_repository: &'a mut L, task_repository: &'a mut T) -> Self {
+        Self {
+            label_repository,
+            task_repository,
+        }
+    }
+
+    pub fn execute(&mut self, command: DeleteLabelCommand) -> Result<(), DeleteLabelError> {
+        let id = LabelId::from(command.id);
+        match self.label_repository.get_by_id(&id) {
+            Ok(_) => {}
+            Err(LabelRepositoryError::NotFound) => return Err(DeleteLabelError::LabelNotFound),
+            Err(_) => return Err(DeleteLabelError::RepositoryError),
+        }
+
+        let query = TaskQuery {
+            label: Some(id.clone()),
+            trash: TaskTrashFilter::Include,
+            include_archived: true,
+            limit: TASK_QUERY_MAX_LIMIT,
+            ..TaskQuery::default()
+        };
+        loop {
+            let page = match self.task_repository.search(&query) {
+                Ok(page) => page,
+                Err(_) => return Err(DeleteLabelError::RepositoryError),
+            };
+            if page.tasks.is_empty() {
+                break;
+            }
+            for task in page.tasks {
+                if self.task_repository.update(task.detach_label(&id)).is_err() {
+                    return Err(DeleteLabelError::RepositoryError);
+                }
+            }
+        }
+
+        match self.label_repository.delete(&id) {
+            Ok(_) => Ok(()),
+            Err(LabelRepositoryError::NotFound) => Err(DeleteLabelError::LabelNotFound),
+            Err(_) => Err(DeleteLabelError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use uuid::Uuid;
+
+    #[test]
+    fn execute_when_label_is_attached_then_detaches_and_deletes_label() {
+        let mut label_repository = LabelInMemoryRepository::new();
+        let label_ids = label_repository.register_test_data();
+        let mut task_repository = TaskInMemoryRepository::new();
+        let task_ids = task_repository.register_test_data();
+        for task_id in &task_ids {
+            let task = task_repository.get_by_id(task_id).unwrap();
+            task_repository
+                .update(task.attach_label(label_ids[0].clone()))
+                .unwrap();
+        }
+
+        let mut delete_label = DeleteLabel::new(&mut label_repository, &mut task_repository);
+        let command = DeleteLabelCommand::new(label_ids[0].to_string().as_str())
+            .ok()
+            .unwrap();
+        delete_label.execute(command).unwrap();
+
+        assert!(label_repository.get_by_id(&label_ids[0]).is_err());
+        for task_id in &task_ids {
+            assert!(
+                task_repository
+                    .get_by_id(task_id)
+                    .unwrap()
+                    .labels
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn execute_when_label_not_found_then_returns_error() {
+        let mut label_repository = LabelInMemoryRepository::new();
+        let mut task_repository = TaskInMemoryRepository::new();
+        let mut delete_label = DeleteLabel::new(&mut label_repository, &mut task_repository);
+        let command = DeleteLabelCommand::new(Uuid::new_v4().to_string().as_str())
+            .ok()
+            .unwrap();
+        let result = delete_label.execute(command).err().unwrap();
+        assert_eq!(result, DeleteLabelError::LabelNotFound);
+    }
+}
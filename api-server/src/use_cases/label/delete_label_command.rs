@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+pub enum DeleteLabelCommandError {
+    InvalidFormatLabelId,
+}
+
+pub struct DeleteLabelCommand {
+    pub id: Uuid,
+}
+
+impl DeleteLabelCommand {
+    pub fn new(id: &str) -> Result<Self, DeleteLabelCommandError> {
+        let id = match Uuid::parse_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(DeleteLabelCommandError::InvalidFormatLabelId),
+        };
+
+        Ok(Self { id })
+    }
+}
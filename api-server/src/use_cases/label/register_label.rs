@@ -0,0 +1,94 @@
+use crate::domain::label::label::Label;
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::use_cases::label::register_label_command::RegisterLabelCommand;
+use crate::use_cases::label::register_label_result::RegisterLabelResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RegisterLabelError {
+    InvalidName,
+    InvalidColor,
+    DuplicateName,
+    RepositoryError,
+}
+
+pub struct RegisterLabel<'a, L: LabelRepository> {
+    repository: &'a mut L,
+}
+
+impl<'a, L: LabelRepository> RegisterLabel<'a, L> {
+    pub fn new(repository: &'a mut L) -> Self {
+        Self { repository }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: RegisterLabelCommand,
+    ) -> Result<RegisterLabelResult, RegisterLabelError> {
+        let name = match LabelName::try_from(command.name()) {
+            Ok(name) => name,
+            Err(_) => return Err(RegisterLabelError::InvalidName),
+        };
+        let color = match LabelColor::try_from(command.color()) {
+            Ok(color) => color,
+            Err(_) => return Err(RegisterLabelError::InvalidColor),
+        };
+
+        let label = Label::new(LabelId::new(), name, color);
+        let label = match self.repository.register(label) {
+            Ok(label) => label,
+            Err(LabelRepositoryError::AlreadyExists) => {
+                return Err(RegisterLabelError::DuplicateName);
+            }
+            Err(_) => return Err(RegisterLabelError::RepositoryError),
+        };
+
+        Ok(RegisterLabelResult::from(&label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+
+    #[test]
+    fn execute_when_valid_input_then_returns_registered_label() {
+        let mut repository = LabelInMemoryRepository::new();
+        let mut register_label = RegisterLabel::new(&mut repository);
+        let command = RegisterLabelCommand::new("urgent", "#FF0000");
+        let result = register_label.execute(command).ok().unwrap();
+        assert_eq!(result.name, "urgent");
+        assert_eq!(result.color, "#ff0000");
+    }
+
+    #[test]
+    fn execute_when_name_is_empty_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        let mut register_label = RegisterLabel::new(&mut repository);
+        let command = RegisterLabelCommand::new("", "#FF0000");
+        let result = register_label.execute(command).err().unwrap();
+        assert_eq!(result, RegisterLabelError::InvalidName);
+    }
+
+    #[test]
+    fn execute_when_color_is_invalid_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        let mut register_label = RegisterLabel::new(&mut repository);
+        let command = RegisterLabelCommand::new("urgent", "red");
+        let result = register_label.execute(command).err().unwrap();
+        assert_eq!(result, RegisterLabelError::InvalidColor);
+    }
+
+    #[test]
+    fn execute_when_name_is_taken_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        repository.register_test_data();
+        let mut register_label = RegisterLabel::new(&mut repository);
+        let command = RegisterLabelCommand::new("bug", "#FF0000");
+        let result = register_label.execute(command).err().unwrap();
+        assert_eq!(result, RegisterLabelError::DuplicateName);
+    }
+}
@@ -0,0 +1,21 @@
+pub struct RegisterLabelCommand {
+    name: String,
+    color: String,
+}
+
+impl RegisterLabelCommand {
+    pub fn new(name: &str, color: &str) -> RegisterLabelCommand {
+        RegisterLabelCommand {
+            name: String::from(name),
+            color: String::from(color),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn color(&self) -> &str {
+        &self.color
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::label::label::Label;
+
+#[derive(Debug)]
+pub struct RegisterLabelResult {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+}
+
+impl RegisterLabelResult {
+    pub fn from(label: &Label) -> Self {
+        Self {
+            id: label.id.to_string(),
+            name: label.name.to_string(),
+            color: label.color.to_string(),
+        }
+    }
+}
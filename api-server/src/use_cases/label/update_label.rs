@@ -0,0 +1,113 @@
+use crate::domain::label::label_color::LabelColor;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_name::LabelName;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::use_cases::label::update_label_command::UpdateLabelCommand;
+use crate::use_cases::label::update_label_result::UpdateLabelResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum UpdateLabelError {
+    InvalidName,
+    InvalidColor,
+    DuplicateName,
+    LabelNotFound,
+    RepositoryError,
+}
+
+pub struct UpdateLabel<'a, L: LabelRepository> {
+    repository: &'a mut L,
+}
+
+impl<'a, L: LabelRepository> UpdateLabel<'a, L> {
+    pub fn new(repository: &'a mut L) -> Self {
+        Self { repository }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: UpdateLabelCommand,
+    ) -> Result<UpdateLabelResult, UpdateLabelError> {
+        let id = LabelId::from(command.id);
+        let mut label = match self.repository.get_by_id(&id) {
+            Ok(label) => label,
+            Err(LabelRepositoryError::NotFound) => return Err(UpdateLabelError::LabelNotFound),
+            Err(_) => return Err(UpdateLabelError::RepositoryError),
+        };
+
+        if let Some(name) = command.name {
+            let name = match LabelName::try_from(name.as_str()) {
+                Ok(name) => name,
+                Err(_) => return Err(UpdateLabelError::InvalidName),
+            };
+            label = label.change_name(name);
+        }
+        if let Some(color) = command.color {
+            let color = match LabelColor::try_from(color.as_str()) {
+                Ok(color) => color,
+                Err(_) => return Err(UpdateLabelError::InvalidColor),
+            };
+            label = label.change_color(color);
+        }
+
+        match self.repository.update(label) {
+            Ok(label) => Ok(UpdateLabelResult::from(&label)),
+            Err(LabelRepositoryError::AlreadyExists) => Err(UpdateLabelError::DuplicateName),
+            Err(LabelRepositoryError::NotFound) => Err(UpdateLabelError::LabelNotFound),
+            Err(_) => Err(UpdateLabelError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+    use uuid::Uuid;
+
+    #[test]
+    fn execute_when_valid_input_then_returns_result() {
+        let mut repository = LabelInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let mut update_label = UpdateLabel::new(&mut repository);
+        let command =
+            UpdateLabelCommand::new(ids[0].to_string().as_str(), Some("defect"), None).unwrap();
+        let result = update_label.execute(command).ok().unwrap();
+        assert_eq!(result.name, "defect");
+        assert_eq!(result.color, "#d73a4a");
+    }
+
+    #[test]
+    fn execute_when_name_is_taken_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let mut update_label = UpdateLabel::new(&mut repository);
+        let command =
+            UpdateLabelCommand::new(ids[0].to_string().as_str(), Some("feature"), None).unwrap();
+        let result = update_label.execute(command).err().unwrap();
+        assert_eq!(result, UpdateLabelError::DuplicateName);
+    }
+
+    #[test]
+    fn execute_when_invalid_color_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let mut update_label = UpdateLabel::new(&mut repository);
+        let command =
+            UpdateLabelCommand::new(ids[0].to_string().as_str(), None, Some("blue")).unwrap();
+        let result = update_label.execute(command).err().unwrap();
+        assert_eq!(result, UpdateLabelError::InvalidColor);
+    }
+
+    #[test]
+    fn execute_when_label_not_found_then_returns_error() {
+        let mut repository = LabelInMemoryRepository::new();
+        let mut update_label = UpdateLabel::new(&mut repository);
+        let command =
+            UpdateLabelCommand::new(Uuid::new_v4().to_string().as_str(), Some("x"), None).unwrap();
+        let result = update_label.execute(command).err().unwrap();
+        assert_eq!(result, UpdateLabelError::LabelNotFound);
+    }
+}
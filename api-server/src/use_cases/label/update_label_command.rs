@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum UpdateLabelCommandError {
+    InvalidFormatLabelId,
+}
+
+#[derive(Debug)]
+pub struct UpdateLabelCommand {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+impl UpdateLabelCommand {
+    pub fn new(
+        id: &str,
+        name: Option<&str>,
+        color: Option<&str>,
+    ) -> Result<UpdateLabelCommand, UpdateLabelCommandError> {
+        let id = match Uuid::parse_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(UpdateLabelCommandError::InvalidFormatLabelId),
+        };
+
+        Ok(UpdateLabelCommand {
+            id,
+            name: name.map(String::from),
+            color: color.map(String::from),
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::label::label::Label;
+
+pub struct UpdateLabelResult {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+}
+
+impl UpdateLabelResult {
+    pub fn from(label: &Label) -> Self {
+        Self {
+            id: label.id.to_string(),
+            name: label.name.to_string(),
+            color: label.color.to_string(),
+        }
+    }
+}
@@ -1,8 +1,30 @@
+pub mod add_task_dependency;
+pub mod archive_done_tasks;
+pub mod archive_done_tasks_command;
+pub mod archive_task;
+pub mod archive_task_command;
+pub mod attach_task_label;
+pub mod batch_tasks;
+pub mod batch_tasks_command;
+pub mod batch_tasks_result;
+pub mod delete_task;
+pub mod delete_task_command;
+pub mod detach_task_label;
+pub mod export_tasks;
+pub mod export_tasks_command;
+pub mod list_tasks;
+pub mod list_tasks_command;
+pub mod list_tasks_result;
+pub mod purge_trash;
 pub mod register_task;
 pub mod register_task_command;
 pub mod register_task_result;
+pub mod remove_task_dependency;
+pub mod restore_task;
+pub mod restore_task_command;
+pub mod task_dependency_command;
+pub mod task_field_error;
+pub mod task_label_command;
 pub mod update_task;
 pub mod update_task_command;
 pub mod update_task_result;
-pub mod delete_task_command;
-pub mod delete_task;
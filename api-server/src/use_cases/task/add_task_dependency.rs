@@ -0,0 +1,127 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task_dependency;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::task_dependency_command::TaskDependencyCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AddTaskDependencyError {
+    TaskNotFound,
+    BlockerNotFound,
+    CycleDetected,
+    RepositoryError,
+}
+
+pub struct AddTaskDependency<'a, T: TaskRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository, C: Clock> AddTaskDependency<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: TaskDependencyCommand,
+    ) -> Result<UpdateTaskResult, AddTaskDependencyError> {
+        let task = match self.repository.get_by_id(&TaskId::from(command.task_id)) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(AddTaskDependencyError::TaskNotFound);
+            }
+            Err(_) => return Err(AddTaskDependencyError::RepositoryError),
+        };
+        let blocker = match self.repository.get_by_id(&TaskId::from(command.blocker_id)) {
+            Ok(blocker) if !blocker.is_trashed() => blocker,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(AddTaskDependencyError::BlockerNotFound);
+            }
+            Err(_) => return Err(AddTaskDependencyError::RepositoryError),
+        };
+
+        match task_dependency::creates_cycle(self.repository, &task.id, &blocker.id) {
+            Ok(false) => {}
+            Ok(true) => return Err(AddTaskDependencyError::CycleDetected),
+            Err(_) => return Err(AddTaskDependencyError::RepositoryError),
+        }
+
+        match self.repository.update(task.add_blocker(blocker.id)) {
+            Ok(task) => Ok(UpdateTaskResult::from(&task, self.clock.now())),
+            Err(TaskRepositoryError::NotFound) => Err(AddTaskDependencyError::TaskNotFound),
+            Err(_) => Err(AddTaskDependencyError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn command(task_id: &TaskId, blocker_id: &TaskId) -> TaskDependencyCommand {
+        TaskDependencyCommand::new(
+            task_id.to_string().as_str(),
+            blocker_id.to_string().as_str(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn execute_when_valid_input_then_adds_blocker() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut add_task_dependency = AddTaskDependency::new(&mut repository, &clock);
+        let result = add_task_dependency
+            .execute(command(&ids[1], &ids[0]))
+            .ok()
+            .unwrap();
+        assert_eq!(result.blocked_by, vec![ids[0].to_string()]);
+    }
+
+    #[test]
+    fn execute_when_dependency_creates_cycle_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut add_task_dependency = AddTaskDependency::new(&mut repository, &clock);
+        add_task_dependency
+            .execute(command(&ids[1], &ids[0]))
+            .unwrap();
+        add_task_dependency
+            .execute(command(&ids[2], &ids[1]))
+            .unwrap();
+
+        let result = add_task_dependency
+            .execute(command(&ids[0], &ids[2]))
+            .err()
+            .unwrap();
+        assert_eq!(result, AddTaskDependencyError::CycleDetected);
+
+        let result = add_task_dependency
+            .execute(command(&ids[0], &ids[0]))
+            .err()
+            .unwrap();
+        assert_eq!(result, AddTaskDependencyError::CycleDetected);
+    }
+
+    #[test]
+    fn execute_when_blocker_not_found_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut add_task_dependency = AddTaskDependency::new(&mut repository, &clock);
+        let result = add_task_dependency
+            .execute(command(&ids[0], &TaskId::new()))
+            .err()
+            .unwrap();
+        assert_eq!(result, AddTaskDependencyError::BlockerNotFound);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_query::{TASK_QUERY_MAX_LIMIT, TaskQuery};
+use crate::domain::task::task_repository::{self, TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::archive_done_tasks_command::ArchiveDoneTasksCommand;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ArchiveDoneTasksError {
+    RepositoryError,
+}
+
+pub struct ArchiveDoneTasks<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> ArchiveDoneTasks<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: ArchiveDoneTasksCommand,
+    ) -> Result<usize, ArchiveDoneTasksError> {
+        let now = self.clock.now();
+        let mut query = TaskQuery {
+            completed_before: Some(now - command.older_than),
+            limit: TASK_QUERY_MAX_LIMIT,
+            ..TaskQuery::default()
+        };
+
+        let mut candidates = Vec::new();
+        loop {
+            let page = match self.repository.search(&query) {
+                Ok(page) => page,
+                Err(_) => return Err(ArchiveDoneTasksError::RepositoryError),
+            };
+            let next_offset = query.next_offset(page.total);
+            candidates.extend(page.tasks.into_iter().filter(|task| task.status.is_done()));
+            match next_offset {
+                Some(offset) => query.offset = offset,
+                None => break,
+            }
+        }
+
+        let mut archived = 0;
+        for task in candidates {
+            let repository = &*self.repository;
+            let result = task_repository::transaction(repository, || {
+                self.archive(task, command.actor.as_deref(), now)
+            });
+            match result {
+                Ok(Ok(true)) => archived += 1,
+                Ok(Ok(false)) => {}
+                Ok(Err(e)) => return Err(e),
+                Err(_) => return Err(ArchiveDoneTasksError::RepositoryError),
+            }
+        }
+
+        Ok(archived)
+    }
+
+    fn archive(
+        &self,
+        task: Task,
+        actor: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<bool, ArchiveDoneTasksError> {
+        let task = match self.repository.update(task.change_archived_at(Some(now))) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound | TaskRepositoryError::Conflict) => return Ok(false),
+            Err(_) => return Err(ArchiveDoneTasksError::RepositoryError),
+        };
+        let event = TaskEvent::archived(&task, actor, now);
+        if self.repository.append(event).is_err() {
+            return Err(ArchiveDoneTasksError::RepositoryError);
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_id::TaskId;
+    use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+    use crate::repositories::task::task_failing_repository::{
+        TaskFailingOperation, TaskFailingRepository,
+    };
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn complete(repository: &TaskInMemoryRepository, id: &TaskId, completed_at: &str) {
+        let completed_at = DateTime::parse_from_rfc3339(completed_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        let task = repository.get_by_id(id).unwrap();
+        repository
+            .update(
+                task.change_status(TaskStatus::new(3, TaskStatusCategory::Done))
+                    .change_completed_at(Some(completed_at)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn execute_when_done_tasks_are_older_than_threshold_then_archives_them() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        complete(&repository, &ids[0], "2026-09-01T09:00:00Z");
+        complete(&repository, &ids[1], "2026-10-15T09:00:00Z");
+
+        let mut archive_done_tasks = ArchiveDoneTasks::new(&mut repository, &clock);
+        let command = ArchiveDoneTasksCommand::new(7, Some("alice")).ok().unwrap();
+        assert_eq!(archive_done_tasks.execute(command).unwrap(), 1);
+
+        assert!(repository.get_by_id(&ids[0]).unwrap().is_archived());
+        assert!(!repository.get_by_id(&ids[1]).unwrap().is_archived());
+        assert!(!repository.get_by_id(&ids[2]).unwrap().is_archived());
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.last().unwrap().kind, TaskEventKind::Archived);
+    }
+
+    #[test]
+    fn execute_when_completed_task_was_reopened_then_skips_it() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        complete(&repository, &ids[0], "2026-09-01T09:00:00Z");
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.change_status(TaskStatus::new(2, TaskStatusCategory::InProgress)))
+            .unwrap();
+
+        let mut archive_done_tasks = ArchiveDoneTasks::new(&mut repository, &clock);
+        let command = ArchiveDoneTasksCommand::new(7, None).ok().unwrap();
+        assert_eq!(archive_done_tasks.execute(command).unwrap(), 0);
+    }
+
+    #[test]
+    fn execute_when_append_fails_then_rolls_back_the_task_update() {
+        let mut inner = TaskInMemoryRepository::new();
+        let ids = inner.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        complete(&inner, &ids[0], "2026-09-01T09:00:00Z");
+        let mut repository = TaskFailingRepository::new(inner, TaskFailingOperation::Append);
+
+        let mut archive_done_tasks = ArchiveDoneTasks::new(&mut repository, &clock);
+        let command = ArchiveDoneTasksCommand::new(7, None).ok().unwrap();
+        let result = archive_done_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ArchiveDoneTasksError::RepositoryError);
+
+        assert!(!repository.get_by_id(&ids[0]).unwrap().is_archived());
+        assert!(repository.history(&ids[0]).unwrap().is_empty());
+    }
+}
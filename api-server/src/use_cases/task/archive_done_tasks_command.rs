@@ -0,0 +1,27 @@
+use chrono::TimeDelta;
+
+pub enum ArchiveDoneTasksCommandError {
+    InvalidOlderThanDays,
+}
+
+pub struct ArchiveDoneTasksCommand {
+    pub older_than: TimeDelta,
+    pub actor: Option<String>,
+}
+
+impl ArchiveDoneTasksCommand {
+    pub fn new(
+        older_than_days: i64,
+        actor: Option<&str>,
+    ) -> Result<Self, ArchiveDoneTasksCommandError> {
+        let older_than = match TimeDelta::try_days(older_than_days) {
+            Some(older_than) if older_than_days >= 0 => older_than,
+            _ => return Err(ArchiveDoneTasksCommandError::InvalidOlderThanDays),
+        };
+
+        Ok(Self {
+            older_than,
+            actor: actor.map(String::from),
+        })
+    }
+}
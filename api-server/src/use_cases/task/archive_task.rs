@@ -0,0 +1,133 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::archive_task_command::ArchiveTaskCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ArchiveTaskError {
+    TaskNotFound,
+    NotDone,
+    AlreadyArchived,
+    Conflict,
+    RepositoryError,
+}
+
+pub struct ArchiveTask<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> ArchiveTask<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: ArchiveTaskCommand,
+    ) -> Result<UpdateTaskResult, ArchiveTaskError> {
+        let task = match self.repository.get_by_id(&TaskId::from(command.id)) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(ArchiveTaskError::TaskNotFound);
+            }
+            Err(_) => return Err(ArchiveTaskError::RepositoryError),
+        };
+        if task.is_archived() {
+            return Err(ArchiveTaskError::AlreadyArchived);
+        }
+        if !task.status.is_done() {
+            return Err(ArchiveTaskError::NotDone);
+        }
+
+        let now = self.clock.now();
+        let task = match self.repository.update(task.change_archived_at(Some(now))) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound) => return Err(ArchiveTaskError::TaskNotFound),
+            Err(TaskRepositoryError::Conflict) => return Err(ArchiveTaskError::Conflict),
+            Err(_) => return Err(ArchiveTaskError::RepositoryError),
+        };
+        let event = TaskEvent::archived(&task, command.actor.as_deref(), now);
+        if self.repository.append(event).is_err() {
+            return Err(ArchiveTaskError::RepositoryError);
+        }
+
+        Ok(UpdateTaskResult::from(&task, self.clock.now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::domain::task::task_status::{TaskStatus, TaskStatusCategory};
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn complete(repository: &TaskInMemoryRepository, id: &TaskId) {
+        let task = repository.get_by_id(id).unwrap();
+        repository
+            .update(task.change_status(TaskStatus::new(3, TaskStatusCategory::Done)))
+            .unwrap();
+    }
+
+    #[test]
+    fn execute_when_task_is_done_then_archives_task() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        complete(&repository, &ids[0]);
+
+        let mut archive_task = ArchiveTask::new(&mut repository, &clock);
+        let command = ArchiveTaskCommand::new(ids[0].to_string().as_str(), Some("alice"))
+            .ok()
+            .unwrap();
+        let result = archive_task.execute(command).unwrap();
+        assert_eq!(
+            result.archived_at,
+            Some(String::from("2026-10-18T09:00:00Z"))
+        );
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 2);
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.last().unwrap().kind, TaskEventKind::Archived);
+        assert_eq!(history.last().unwrap().actor, Some(String::from("alice")));
+    }
+
+    #[test]
+    fn execute_when_task_is_not_done_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut archive_task = ArchiveTask::new(&mut repository, &clock);
+        let command = ArchiveTaskCommand::new(ids[0].to_string().as_str(), None)
+            .ok()
+            .unwrap();
+        let result = archive_task.execute(command).err().unwrap();
+        assert_eq!(result, ArchiveTaskError::NotDone);
+    }
+
+    #[test]
+    fn execute_when_task_is_already_archived_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        complete(&repository, &ids[0]);
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.change_archived_at(Some(clock.now())))
+            .unwrap();
+
+        let mut archive_task = ArchiveTask::new(&mut repository, &clock);
+        let command = ArchiveTaskCommand::new(ids[0].to_string().as_str(), None)
+            .ok()
+            .unwrap();
+        let result = archive_task.execute(command).err().unwrap();
+        assert_eq!(result, ArchiveTaskError::AlreadyArchived);
+    }
+}
@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+pub enum ArchiveTaskCommandError {
+    InvalidFormatTaskId,
+}
+
+pub struct ArchiveTaskCommand {
+    pub id: Uuid,
+    pub actor: Option<String>,
+}
+
+impl ArchiveTaskCommand {
+    pub fn new(id: &str, actor: Option<&str>) -> Result<Self, ArchiveTaskCommandError> {
+        let id = match Uuid::parse_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(ArchiveTaskCommandError::InvalidFormatTaskId),
+        };
+
+        Ok(Self {
+            id,
+            actor: actor.map(String::from),
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use crate::domain::clock::Clock;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::label::label_repository::{LabelRepository, LabelRepositoryError};
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::task_label_command::TaskLabelCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AttachTaskLabelError {
+    TaskNotFound,
+    LabelNotFound,
+    RepositoryError,
+}
+
+pub struct AttachTaskLabel<'a, T: TaskRepository, L: LabelRepository, C: Clock> {
+    task_repository: &'a mut T,
+    label_repository: &'a L,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository, L: LabelRepository, C: Clock> AttachTaskLabel<'a, T, L, C> {
+    pub fn new(task_repository: &'a mut T, label_repository: &'a L, clock: &'a C) -> Self {
+        Self {
+            task_repository,
+            label_repository,
+            clock,
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: TaskLabelCommand,
+    ) -> Result<UpdateTaskResult, AttachTaskLabelError> {
+        let task = match self
+            .task_repository
+            .get_by_id(&TaskId::from(command.task_id))
+        {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(AttachTaskLabelError::TaskNotFound);
+            }
+            Err(_) => return Err(AttachTaskLabelError::RepositoryError),
+        };
+        let label = match self
+            .label_repository
+            .get_by_id(&LabelId::from(command.label_id))
+        {
+            Ok(label) => label,
+            Err(LabelRepositoryError::NotFound) => {
+                return Err(AttachTaskLabelError::LabelNotFound);
+            }
+            Err(_) => return Err(AttachTaskLabelError::RepositoryError),
+        };
+
+        match self.task_repository.update(task.attach_label(label.id)) {
+            Ok(task) => Ok(UpdateTaskResult::from(&task, self.clock.now())),
+            Err(TaskRepositoryError::NotFound) => Err(AttachTaskLabelError::TaskNotFound),
+            Err(_) => Err(AttachTaskLabelError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::repositories::label::label_in_memory_repository::LabelInMemoryRepository;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use uuid::Uuid;
+
+    #[test]
+    fn execute_when_label_exists_then_attaches_it_once() {
+        let mut task_repository = TaskInMemoryRepository::new();
+        let task_ids = task_repository.register_test_data();
+        let mut label_repository = LabelInMemoryRepository::new();
+        let label_ids = label_repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut attach_task_label =
+            AttachTaskLabel::new(&mut task_repository, &label_repository, &clock);
+        for _ in 0..2 {
+            let command = TaskLabelCommand::new(
+                task_ids[0].to_string().as_str(),
+                label_ids[0].to_string().as_str(),
+            )
+            .unwrap();
+            let result = attach_task_label.execute(command).ok().unwrap();
+            assert_eq!(result.labels, vec![label_ids[0].to_string()]);
+        }
+    }
+
+    #[test]
+    fn execute_when_label_not_found_then_returns_error() {
+        let mut task_repository = TaskInMemoryRepository::new();
+        let task_ids = task_repository.register_test_data();
+        let label_repository = LabelInMemoryRepository::new();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut attach_task_label =
+            AttachTaskLabel::new(&mut task_repository, &label_repository, &clock);
+        let command = TaskLabelCommand::new(
+            task_ids[0].to_string().as_str(),
+            Uuid::new_v4().to_string().as_str(),
+        )
+        .unwrap();
+        let result = attach_task_label.execute(command).err().unwrap();
+        assert_eq!(result, AttachTaskLabelError::LabelNotFound);
+    }
+
+    #[test]
+    fn execute_when_task_not_found_then_returns_error() {
+        let mut task_repository = TaskInMemoryRepository::new();
+        let mut label_repository = LabelInMemoryRepository::new();
+        let label_ids = label_repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut attach_task_label =
+            AttachTaskLabel::new(&mut task_repository, &label_repository, &clock);
+        let command = TaskLabelCommand::new(
+            Uuid::new_v4().to_string().as_str(),
+            label_ids[0].to_string().as_str(),
+        )
+        .unwrap();
+        let result = attach_task_label.execute(command).err().unwrap();
+        assert_eq!(result, AttachTaskLabelError::TaskNotFound);
+    }
+}
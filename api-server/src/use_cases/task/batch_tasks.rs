@@ -0,0 +1,213 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task_delete_policy::TaskDeletePolicy;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_repository::TaskRepository;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::use_cases::task::batch_tasks_command::{BatchTaskOperation, BatchTasksCommand};
+use crate::use_cases::task::batch_tasks_result::BatchTaskOutcome;
+use crate::use_cases::task::delete_task::{DeleteTask, DeleteTaskError};
+use crate::use_cases::task::register_task::{RegisterTask, RegisterTaskError};
+use crate::use_cases::task::update_task::{UpdateTask, UpdateTaskError};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum BatchTaskOperationError {
+    Register(RegisterTaskError),
+    Update(UpdateTaskError),
+    Delete(DeleteTaskError),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum BatchTasksError {
+    OperationFailed {
+        index: usize,
+        error: BatchTaskOperationError,
+    },
+    RepositoryError,
+}
+
+pub struct BatchTasks<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
+    repository: &'a mut T,
+    workflow: &'a TaskWorkflow,
+    policy: TaskDeletePolicy,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> BatchTasks<'a, T, C> {
+    pub fn new(
+        repository: &'a mut T,
+        workflow: &'a TaskWorkflow,
+        policy: TaskDeletePolicy,
+        clock: &'a C,
+    ) -> Self {
+        Self {
+            repository,
+            workflow,
+            policy,
+            clock,
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: BatchTasksCommand,
+    ) -> Result<Vec<BatchTaskOutcome>, BatchTasksError> {
+        if self.repository.begin_transaction().is_err() {
+            return Err(BatchTasksError::RepositoryError);
+        }
+
+        let mut outcomes = Vec::with_capacity(command.operations.len());
+        for (index, operation) in command.operations.into_iter().enumerate() {
+            match self.apply(operation) {
+                Ok(outcome) => outcomes.push(outcome),
+                Err(error) => {
+                    if self.repository.rollback_transaction().is_err() {
+                        return Err(BatchTasksError::RepositoryError);
+                    }
+                    return Err(BatchTasksError::OperationFailed { index, error });
+                }
+            }
+        }
+
+        match self.repository.commit_transaction() {
+            Ok(()) => Ok(outcomes),
+            Err(_) => Err(BatchTasksError::RepositoryError),
+        }
+    }
+
+    fn apply(
+        &mut self,
+        operation: BatchTaskOperation,
+    ) -> Result<BatchTaskOutcome, BatchTaskOperationError> {
+        match operation {
+            BatchTaskOperation::Register(command) => {
+                RegisterTask::new(self.repository, self.workflow, self.clock)
+                    .execute_in_transaction(command)
+                    .map(BatchTaskOutcome::Registered)
+                    .map_err(BatchTaskOperationError::Register)
+            }
+            BatchTaskOperation::Update(command) => {
+                UpdateTask::new(self.repository, self.workflow, self.clock)
+                    .execute_in_transaction(command)
+                    .map(BatchTaskOutcome::Updated)
+                    .map_err(BatchTaskOperationError::Update)
+            }
+            BatchTaskOperation::Delete(command) => {
+                let id = command.id.to_string();
+                DeleteTask::new(self.repository, self.policy, self.clock)
+                    .execute_in_transaction(command)
+                    .map(|_| BatchTaskOutcome::Deleted(id))
+                    .map_err(BatchTaskOperationError::Delete)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::domain::task::task_title::TaskTitleParseError;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use crate::use_cases::task::delete_task_command::DeleteTaskCommand;
+    use crate::use_cases::task::register_task_command::RegisterTaskCommand;
+    use crate::use_cases::task::task_field_error::TaskFieldError;
+    use crate::use_cases::task::update_task_command::UpdateTaskCommand;
+
+    fn register(title: &str) -> BatchTaskOperation {
+        BatchTaskOperation::Register(RegisterTaskCommand::new(
+            title,
+            "Batch Description",
+            1,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ))
+    }
+
+    #[test]
+    fn execute_when_all_operations_are_valid_then_applies_them_in_order() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut batch_tasks =
+            BatchTasks::new(&mut repository, &workflow, TaskDeletePolicy::Reject, &clock);
+        let command = BatchTasksCommand::new(vec![
+            register("Batch Task"),
+            BatchTaskOperation::Update(
+                UpdateTaskCommand::new(
+                    &ids[0].to_string(),
+                    Some("Batch Update"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+            BatchTaskOperation::Delete(
+                DeleteTaskCommand::new(&ids[1].to_string(), None, None)
+                    .ok()
+                    .unwrap(),
+            ),
+        ]);
+        let outcomes = batch_tasks.execute(command).ok().unwrap();
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(
+            matches!(&outcomes[0], BatchTaskOutcome::Registered(task) if task.title == "Batch Task")
+        );
+        assert!(
+            matches!(&outcomes[1], BatchTaskOutcome::Updated(task) if task.title == "Batch Update")
+        );
+        assert!(matches!(&outcomes[2], BatchTaskOutcome::Deleted(id) if *id == ids[1].to_string()));
+        assert!(repository.get_by_id(&ids[1]).unwrap().is_trashed());
+    }
+
+    #[test]
+    fn execute_when_an_operation_fails_then_rolls_back_previous_operations() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let before = repository.search(&TaskQuery::default()).unwrap().total;
+        let history = repository.history(&ids[0]).unwrap().len();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut batch_tasks =
+            BatchTasks::new(&mut repository, &workflow, TaskDeletePolicy::Reject, &clock);
+        let command = BatchTasksCommand::new(vec![
+            register("Batch Task"),
+            BatchTaskOperation::Delete(
+                DeleteTaskCommand::new(&ids[0].to_string(), None, None)
+                    .ok()
+                    .unwrap(),
+            ),
+            register(""),
+        ]);
+        let result = batch_tasks.execute(command);
+
+        assert_eq!(
+            result.err().unwrap(),
+            BatchTasksError::OperationFailed {
+                index: 2,
+                error: BatchTaskOperationError::Register(RegisterTaskError::InvalidFields(vec![
+                    TaskFieldError::Title(TaskTitleParseError::Empty)
+                ])),
+            }
+        );
+        assert_eq!(
+            repository.search(&TaskQuery::default()).unwrap().total,
+            before
+        );
+        assert!(!repository.get_by_id(&ids[0]).unwrap().is_trashed());
+        assert_eq!(repository.history(&ids[0]).unwrap().len(), history);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::use_cases::task::delete_task_command::DeleteTaskCommand;
+use crate::use_cases::task::register_task_command::RegisterTaskCommand;
+use crate::use_cases::task::update_task_command::UpdateTaskCommand;
+
+pub enum BatchTaskOperation {
+    Register(RegisterTaskCommand),
+    Update(UpdateTaskCommand),
+    Delete(DeleteTaskCommand),
+}
+
+pub struct BatchTasksCommand {
+    pub operations: Vec<BatchTaskOperation>,
+}
+
+impl BatchTasksCommand {
+    pub fn new(operations: Vec<BatchTaskOperation>) -> Self {
+        Self { operations }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::use_cases::task::register_task_result::RegisterTaskResult;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+pub enum BatchTaskOutcome {
+    Registered(RegisterTaskResult),
+    Updated(UpdateTaskResult),
+    Deleted(String),
+}
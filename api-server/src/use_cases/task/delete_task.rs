@@ -1,20 +1,311 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_delete_policy::TaskDeletePolicy;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_hierarchy;
 use crate::domain::task::task_id::TaskId;
-use crate::domain::task::task_repository::TaskRepository;
+use crate::domain::task::task_repository::{self, TaskRepository, TaskRepositoryError};
 use crate::use_cases::task::delete_task_command::DeleteTaskCommand;
 
-pub struct DeleteTask<'a, T: TaskRepository> {
+#[derive(Debug, Eq, PartialEq)]
+pub enum DeleteTaskError {
+    TaskNotFound,
+    HasChildren,
+    PreconditionFailed,
+    Conflict,
+    RepositoryError,
+}
+
+pub struct DeleteTask<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
     repository: &'a mut T,
+    policy: TaskDeletePolicy,
+    clock: &'a C,
 }
 
-impl<'a, T: TaskRepository> DeleteTask<'a, T> {
-    pub fn new(repository: &'a mut T) -> Self {
-        Self { repository }
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> DeleteTask<'a, T, C> {
+    pub fn new(repository: &'a mut T, policy: TaskDeletePolicy, clock: &'a C) -> Self {
+        Self {
+            repository,
+            policy,
+            clock,
+        }
+    }
+
+    pub fn execute(&mut self, command: DeleteTaskCommand) -> Result<(), DeleteTaskError> {
+        let repository = &*self.repository;
+        match task_repository::transaction(repository, || self.execute_in_transaction(command)) {
+            Ok(result) => result,
+            Err(_) => Err(DeleteTaskError::RepositoryError),
+        }
     }
 
-    pub fn execute(&mut self, command: DeleteTaskCommand) -> Result<(), ()> {
+    pub fn execute_in_transaction(
+        &self,
+        command: DeleteTaskCommand,
+    ) -> Result<(), DeleteTaskError> {
         let id = TaskId::from(command.id);
-        let _ = self.repository.delete(&id);
+        let actor = command.actor.as_deref();
+        let task = match self.repository.get_by_id(&id) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(DeleteTaskError::TaskNotFound);
+            }
+            Err(_) => return Err(DeleteTaskError::RepositoryError),
+        };
+        if command
+            .expected_version
+            .is_some_and(|version| version != task.version)
+        {
+            return Err(DeleteTaskError::PreconditionFailed);
+        }
+
+        let children = match task_hierarchy::children(self.repository, &id) {
+            Ok(children) => children,
+            Err(_) => return Err(DeleteTaskError::RepositoryError),
+        };
+        if !children.is_empty() {
+            match self.policy {
+                TaskDeletePolicy::Reject => return Err(DeleteTaskError::HasChildren),
+                TaskDeletePolicy::Reparent => {
+                    for child in children {
+                        let now = self.clock.now();
+                        let updated = child
+                            .clone()
+                            .change_parent(task.parent_id.clone())
+                            .change_updated_at(now);
+                        let updated = match self.repository.update(updated) {
+                            Ok(updated) => updated,
+                            Err(_) => return Err(DeleteTaskError::RepositoryError),
+                        };
+                        self.record(TaskEvent::updated(&child, &updated, actor, now))?;
+                    }
+                }
+                TaskDeletePolicy::Cascade => {
+                    for child in children {
+                        self.trash_subtree(child, actor)?;
+                    }
+                }
+            }
+        }
+
+        self.trash(task, actor)
+    }
+
+    fn trash_subtree(&self, task: Task, actor: Option<&str>) -> Result<(), DeleteTaskError> {
+        let children = match task_hierarchy::children(self.repository, &task.id) {
+            Ok(children) => children,
+            Err(_) => return Err(DeleteTaskError::RepositoryError),
+        };
+        for child in children {
+            self.trash_subtree(child, actor)?;
+        }
+
+        self.trash(task, actor)
+    }
+
+    fn trash(&self, task: Task, actor: Option<&str>) -> Result<(), DeleteTaskError> {
+        let now = self.clock.now();
+        let task = match self.repository.update(task.change_deleted_at(Some(now))) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound) => return Err(DeleteTaskError::TaskNotFound),
+            Err(TaskRepositoryError::Conflict) => return Err(DeleteTaskError::Conflict),
+            Err(_) => return Err(DeleteTaskError::RepositoryError),
+        };
+        self.record(TaskEvent::deleted(&task, actor, now))
+    }
+
+    fn record(&self, event: TaskEvent) -> Result<(), DeleteTaskError> {
+        match self.repository.append(event) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(DeleteTaskError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::repositories::task::task_failing_repository::{
+        TaskFailingOperation, TaskFailingRepository,
+    };
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use uuid::Uuid;
+
+    #[test]
+    fn execute_when_task_exists_then_deletes_task() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), Some("alice"), None)
+            .ok()
+            .unwrap();
+        delete_task.execute(command).unwrap();
+
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        assert_eq!(task.deleted_at, Some(clock.now()));
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 2);
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, TaskEventKind::Deleted);
+        assert_eq!(history[0].actor, Some(String::from("alice")));
+        assert_eq!(history[0].changes[0].before, Some(String::from("AAA")));
+    }
+
+    #[test]
+    fn execute_when_task_not_found_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        let command = DeleteTaskCommand::new(Uuid::new_v4().to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        let result = delete_task.execute(command).err().unwrap();
+        assert_eq!(result, DeleteTaskError::TaskNotFound);
+    }
+
+    #[test]
+    fn execute_when_task_is_already_trashed_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        for expected in [Ok(()), Err(DeleteTaskError::TaskNotFound)] {
+            let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), None, None)
+                .ok()
+                .unwrap();
+            assert_eq!(delete_task.execute(command), expected);
+        }
+    }
+
+    #[test]
+    fn execute_when_expected_version_is_stale_then_returns_precondition_failed() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), None, Some(2))
+            .ok()
+            .unwrap();
+        let result = delete_task.execute(command).err().unwrap();
+        assert_eq!(result, DeleteTaskError::PreconditionFailed);
+        assert!(repository.get_by_id(&ids[0]).is_ok());
+    }
+
+    fn register_tree(repository: &mut TaskInMemoryRepository) -> Vec<TaskId> {
+        let ids = repository.register_test_data();
+        for (child, parent) in [(1, 0), (2, 1)] {
+            let task = repository.get_by_id(&ids[child]).unwrap();
+            repository
+                .update(task.change_parent(Some(ids[parent].clone())))
+                .unwrap();
+        }
+        ids
+    }
+
+    #[test]
+    fn execute_when_policy_is_reject_and_task_has_children_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = register_tree(&mut repository);
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        let result = delete_task.execute(command).err().unwrap();
+        assert_eq!(result, DeleteTaskError::HasChildren);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+    }
+
+    #[test]
+    fn execute_when_policy_is_reject_and_child_is_archived_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = register_tree(&mut repository);
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let task = repository.get_by_id(&ids[2]).unwrap();
+        repository
+            .update(task.change_archived_at(Some(clock.now())))
+            .unwrap();
+
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reject, &clock);
+        let command = DeleteTaskCommand::new(ids[1].to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        let result = delete_task.execute(command).err().unwrap();
+        assert_eq!(result, DeleteTaskError::HasChildren);
+        assert!(!repository.get_by_id(&ids[1]).unwrap().is_trashed());
+    }
+
+    #[test]
+    fn execute_when_policy_is_reparent_then_moves_children_to_grandparent() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = register_tree(&mut repository);
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Reparent, &clock);
+        let command = DeleteTaskCommand::new(ids[1].to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        delete_task.execute(command).unwrap();
+
+        let child = repository.get_by_id(&ids[2]).unwrap();
+        assert_eq!(child.parent_id, Some(ids[0].clone()));
+
+        let history = repository.history(&ids[2]).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, TaskEventKind::Updated);
+        assert_eq!(history[0].changes[0].field, "parent_id");
+        assert_eq!(history[0].changes[0].before, Some(ids[1].to_string()));
+        assert_eq!(history[0].changes[0].after, Some(ids[0].to_string()));
+    }
+
+    #[test]
+    fn execute_when_policy_is_cascade_then_trashes_descendants() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = register_tree(&mut repository);
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Cascade, &clock);
+        let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        delete_task.execute(command).unwrap();
+
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 0);
+        for id in &ids {
+            assert!(repository.get_by_id(id).unwrap().is_trashed());
+            let history = repository.history(id).unwrap();
+            assert_eq!(history.last().unwrap().kind, TaskEventKind::Deleted);
+        }
+    }
+
+    #[test]
+    fn execute_when_cascade_fails_midway_then_rolls_back_trashed_descendants() {
+        let mut inner = TaskInMemoryRepository::new();
+        let ids = register_tree(&mut inner);
+        let mut repository =
+            TaskFailingRepository::new(inner, TaskFailingOperation::Update).after(1);
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut delete_task = DeleteTask::new(&mut repository, TaskDeletePolicy::Cascade, &clock);
+        let command = DeleteTaskCommand::new(ids[0].to_string().as_str(), None, None)
+            .ok()
+            .unwrap();
+        let result = delete_task.execute(command).err().unwrap();
+        assert_eq!(result, DeleteTaskError::RepositoryError);
 
-        Ok(())
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+        for id in &ids {
+            assert!(repository.history(id).unwrap().is_empty());
+        }
     }
 }
@@ -6,15 +6,25 @@ pub enum DeleteTaskCommandError {
 
 pub struct DeleteTaskCommand {
     pub id: Uuid,
+    pub actor: Option<String>,
+    pub expected_version: Option<u64>,
 }
 
 impl DeleteTaskCommand {
-    pub fn new(id: &str) -> Result<Self, DeleteTaskCommandError> {
+    pub fn new(
+        id: &str,
+        actor: Option<&str>,
+        expected_version: Option<u64>,
+    ) -> Result<Self, DeleteTaskCommandError> {
         let id = match Uuid::parse_str(id) {
             Ok(id) => id,
             Err(_) => return Err(DeleteTaskCommandError::InvalidFormatTaskId),
         };
 
-        Ok(Self { id })
+        Ok(Self {
+            id,
+            actor: actor.map(String::from),
+            expected_version,
+        })
     }
 }
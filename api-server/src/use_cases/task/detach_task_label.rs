@@ -0,0 +1,69 @@
+use crate::domain::clock::Clock;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::task_label_command::TaskLabelCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DetachTaskLabelError {
+    TaskNotFound,
+    RepositoryError,
+}
+
+pub struct DetachTaskLabel<'a, T: TaskRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository, C: Clock> DetachTaskLabel<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: TaskLabelCommand,
+    ) -> Result<UpdateTaskResult, DetachTaskLabelError> {
+        let task = match self.repository.get_by_id(&TaskId::from(command.task_id)) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(DetachTaskLabelError::TaskNotFound);
+            }
+            Err(_) => return Err(DetachTaskLabelError::RepositoryError),
+        };
+
+        let label_id = LabelId::from(command.label_id);
+        match self.repository.update(task.detach_label(&label_id)) {
+            Ok(task) => Ok(UpdateTaskResult::from(&task, self.clock.now())),
+            Err(TaskRepositoryError::NotFound) => Err(DetachTaskLabelError::TaskNotFound),
+            Err(_) => Err(DetachTaskLabelError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    #[test]
+    fn execute_when_label_attached_then_detaches_it() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let label_id = LabelId::new();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.attach_label(label_id.clone()))
+            .unwrap();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut detach_task_label = DetachTaskLabel::new(&mut repository, &clock);
+        let command =
+            TaskLabelCommand::new(ids[0].to_string().as_str(), label_id.to_string().as_str())
+                .unwrap();
+        let result = detach_task_label.execute(command).ok().unwrap();
+        assert!(result.labels.is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_async_repository::TaskAsyncRepository;
+use crate::domain::task::task_cursor::TaskCursor;
+use crate::domain::task::task_query::{TASK_QUERY_MAX_LIMIT, TaskQuery};
+use crate::use_cases::task::export_tasks_command::ExportTasksCommand;
+use std::sync::Arc;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ExportTasksError {
+    RepositoryError,
+}
+
+pub struct ExportTasks<T: TaskAsyncRepository> {
+    repository: Arc<T>,
+    query: Option<TaskQuery>,
+}
+
+impl<T: TaskAsyncRepository> ExportTasks<T> {
+    pub fn new(repository: Arc<T>, command: ExportTasksCommand) -> Self {
+        Self {
+            repository,
+            query: Some(TaskQuery {
+                include_archived: command.include_archived,
+                limit: TASK_QUERY_MAX_LIMIT,
+                ..TaskQuery::default()
+            }),
+        }
+    }
+
+    pub async fn next_page(&mut self) -> Result<Option<Vec<Task>>, ExportTasksError> {
+        let Some(query) = self.query.as_mut() else {
+            return Ok(None);
+        };
+        let page = match self.repository.search(query).await {
+            Ok(page) => page,
+            Err(_) => return Err(ExportTasksError::RepositoryError),
+        };
+        if page.tasks.is_empty() {
+            self.query = None;
+            return Ok(None);
+        }
+        match page.tasks.last() {
+            Some(last) if page.tasks.len() == query.limit => {
+                query.after = Some(TaskCursor::new(last, query.sort, query.order));
+            }
+            _ => self.query = None,
+        }
+
+        Ok(Some(page.tasks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::task_repository::TaskRepository;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use crate::repositories::task::task_locked_repository::TaskLockedRepository;
+    use chrono::Utc;
+
+    async fn export(
+        repository: &Arc<TaskLockedRepository>,
+        command: ExportTasksCommand,
+    ) -> Vec<Vec<Task>> {
+        let mut export_tasks = ExportTasks::new(repository.clone(), command);
+        let mut pages = Vec::new();
+        while let Some(tasks) = export_tasks.next_page().await.unwrap() {
+            pages.push(tasks);
+        }
+        pages
+    }
+
+    #[tokio::test]
+    async fn next_page_when_tasks_exceed_page_size_then_returns_every_page() {
+        let mut repository = TaskInMemoryRepository::new();
+        while repository.search(&TaskQuery::default()).unwrap().total <= TASK_QUERY_MAX_LIMIT {
+            repository.register_test_data();
+        }
+        let total = repository.search(&TaskQuery::default()).unwrap().total;
+        let repository = Arc::new(TaskLockedRepository::new(repository));
+
+        let pages = export(&repository, ExportTasksCommand::default()).await;
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].len(), TASK_QUERY_MAX_LIMIT);
+        assert_eq!(pages.iter().map(Vec::len).sum::<usize>(), total);
+    }
+
+    #[tokio::test]
+    async fn next_page_when_task_is_trashed_between_pages_then_exports_every_other_task_once() {
+        let mut repository = TaskInMemoryRepository::new();
+        while repository.search(&TaskQuery::default()).unwrap().total <= TASK_QUERY_MAX_LIMIT {
+            repository.register_test_data();
+        }
+        let total = repository.search(&TaskQuery::default()).unwrap().total;
+        let repository = Arc::new(TaskLockedRepository::new(repository));
+
+        let mut export_tasks = ExportTasks::new(repository.clone(), ExportTasksCommand::default());
+        let mut exported = export_tasks.next_page().await.unwrap().unwrap();
+        {
+            let repository = repository.lock();
+            let task = repository.get_by_id(&exported[0].id).unwrap();
+            repository
+                .update(task.change_deleted_at(Some(Utc::now())))
+                .unwrap();
+        }
+        while let Some(tasks) = export_tasks.next_page().await.unwrap() {
+            exported.extend(tasks);
+        }
+
+        let mut ids: Vec<String> = exported.iter().map(|task| task.id.to_string()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(exported.len(), total);
+        assert_eq!(ids.len(), total);
+    }
+
+    #[tokio::test]
+    async fn next_page_when_task_is_archived_then_exports_it_only_when_archived_are_included() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.change_archived_at(Some(Utc::now())))
+            .unwrap();
+        let repository = Arc::new(TaskLockedRepository::new(repository));
+
+        let pages = export(&repository, ExportTasksCommand::default()).await;
+        assert_eq!(pages.concat().len(), 2);
+
+        let command = ExportTasksCommand {
+            include_archived: true,
+        };
+        let pages = export(&repository, command).await;
+        assert_eq!(pages.concat().len(), 3);
+    }
+}
@@ -0,0 +1,4 @@
+#[derive(Debug, Default)]
+pub struct ExportTasksCommand {
+    pub include_archived: bool,
+}
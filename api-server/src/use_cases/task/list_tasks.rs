@@ -0,0 +1,498 @@
+use crate::domain::clock::Clock;
+use crate::domain::label::label_id::LabelId;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_cursor::TaskCursor;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_hierarchy;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_query::{SortOrder, TaskQuery, TaskSortKey, TaskTrashFilter};
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::use_cases::task::list_tasks_command::ListTasksCommand;
+use crate::use_cases::task::list_tasks_result::ListTasksResult;
+use std::collections::HashMap;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ListTasksError {
+    InvalidStatus,
+    InvalidPriority,
+    InvalidLabel,
+    InvalidParent,
+    ParentNotFound,
+    InvalidDueBefore,
+    InvalidSort,
+    InvalidOrder,
+    InvalidLimit,
+    InvalidCursor,
+    RepositoryError,
+}
+
+pub struct ListTasks<'a, T: TaskRepository, C: Clock> {
+    repository: &'a T,
+    workflow: &'a TaskWorkflow,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository, C: Clock> ListTasks<'a, T, C> {
+    pub fn new(repository: &'a T, workflow: &'a TaskWorkflow, clock: &'a C) -> Self {
+        Self {
+            repository,
+            workflow,
+            clock,
+        }
+    }
+
+    pub fn execute(&self, command: ListTasksCommand) -> Result<ListTasksResult, ListTasksError> {
+        let mut query = TaskQuery::default();
+        if command.trashed {
+            query.trash = TaskTrashFilter::Only;
+        }
+        query.include_archived = command.trashed || command.include_archived;
+        if let Some(status) = command.status {
+            query.status = match self.workflow.status(status) {
+                Ok(status) => Some(status),
+                Err(_) => return Err(ListTasksError::InvalidStatus),
+            };
+        }
+        if let Some(priority) = command.priority {
+            query.priority = match TaskPriority::try_from(priority) {
+                Ok(priority) => Some(priority),
+                Err(_) => return Err(ListTasksError::InvalidPriority),
+            };
+        }
+        if let Some(label) = command.label {
+            query.label = match LabelId::try_from(label.as_str()) {
+                Ok(label) => Some(label),
+                Err(_) => return Err(ListTasksError::InvalidLabel),
+            };
+        }
+        if let Some(parent) = command.parent {
+            let parent_id = match TaskId::try_from(parent.as_str()) {
+                Ok(parent_id) => parent_id,
+                Err(_) => return Err(ListTasksError::InvalidParent),
+            };
+            match self.repository.get_by_id(&parent_id) {
+                Ok(parent) if !parent.is_trashed() => {}
+                Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                    return Err(ListTasksError::ParentNotFound);
+                }
+                Err(_) => return Err(ListTasksError::RepositoryError),
+            }
+            query.parent = Some(Some(parent_id));
+        } else if command.tree {
+            query.parent = Some(None);
+        }
+        if let Some(due_before) = command.due_before {
+            query.due_before = match TaskDueDate::try_from(due_before.as_str()) {
+                Ok(due_before) => Some(due_before.deadline()),
+                Err(_) => return Err(ListTasksError::InvalidDueBefore),
+            };
+        }
+        if command.overdue == Some(true) {
+            query.overdue_at = Some(self.clock.now());
+        }
+        if let Some(sort) = command.sort {
+            query.sort = match TaskSortKey::try_from(sort.as_str()) {
+                Ok(sort) => sort,
+                Err(_) => return Err(ListTasksError::InvalidSort),
+            };
+        }
+        if let Some(order) = command.order {
+            query.order = match SortOrder::try_from(order.as_str()) {
+                Ok(order) => order,
+                Err(_) => return Err(ListTasksError::InvalidOrder),
+            };
+        }
+        if let Some(limit) = command.limit {
+            query = match query.with_limit(limit) {
+                Ok(query) => query,
+                Err(_) => return Err(ListTasksError::InvalidLimit),
+            };
+        }
+        if let Some(cursor) = command.cursor {
+            query.after = match TaskCursor::try_from(cursor.as_str()) {
+                Ok(cursor) if cursor.sort == query.sort && cursor.order == query.order => {
+                    Some(cursor)
+                }
+                _ => return Err(ListTasksError::InvalidCursor),
+            };
+        }
+
+        query.limit += 1;
+        let mut page = match self.repository.search(&query) {
+            Ok(page) => page,
+            Err(_) => return Err(ListTasksError::RepositoryError),
+        };
+        query.limit -= 1;
+        let mut next_cursor = None;
+        if page.tasks.len() > query.limit {
+            page.tasks.truncate(query.limit);
+            next_cursor = page
+                .tasks
+                .last()
+                .map(|task| TaskCursor::new(task, query.sort, query.order).encode());
+        }
+        let mut children = HashMap::new();
+        if command.tree {
+            for task in &page.tasks {
+                if self
+                    .collect_descendants(task, query.include_archived, &mut children)
+                    .is_err()
+                {
+                    return Err(ListTasksError::RepositoryError);
+                }
+            }
+        }
+
+        Ok(ListTasksResult {
+            next_cursor,
+            tasks: page.tasks,
+            total: page.total,
+            children,
+        })
+    }
+
+    fn collect_descendants(
+        &self,
+        task: &Task,
+        include_archived: bool,
+        descendants: &mut HashMap<TaskId, Vec<Task>>,
+    ) -> Result<(), TaskRepositoryError> {
+        let mut children = task_hierarchy::children(self.repository, &task.id)?;
+        children.retain(|child| include_archived || !child.is_archived());
+        for child in &children {
+            self.collect_descendants(child, include_archived, descendants)?;
+        }
+        if !children.is_empty() {
+            descendants.insert(task.id.clone(), children);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_description::TaskDescription;
+    use crate::domain::task::task_title::TaskTitle;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use chrono::Utc;
+
+    #[test]
+    fn execute_when_no_parameters_then_returns_all_tasks() {
+        let mut repository = TaskInMemoryRepository::new();
+        repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand::default();
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.tasks.len(), 3);
+        assert_eq!(result.total, 3);
+        assert_eq!(result.next_cursor, None);
+    }
+
+    #[test]
+    fn execute_when_paginated_then_returns_next_cursor() {
+        let mut repository = TaskInMemoryRepository::new();
+        repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            sort: Some(String::from("title")),
+            order: Some(String::from("asc")),
+            limit: Some(2),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.tasks.len(), 2);
+        assert_eq!(result.tasks[0].title.to_string(), "AAA");
+        assert!(result.next_cursor.is_some());
+
+        let command = ListTasksCommand {
+            sort: Some(String::from("title")),
+            order: Some(String::from("asc")),
+            limit: Some(2),
+            cursor: result.next_cursor,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.tasks.len(), 1);
+        assert_eq!(result.tasks[0].title.to_string(), "CCC");
+        assert_eq!(result.total, 3);
+        assert_eq!(result.next_cursor, None);
+    }
+
+    #[test]
+    fn execute_when_task_is_registered_before_cursor_then_next_page_is_unchanged() {
+        let mut repository = TaskInMemoryRepository::new();
+        repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            sort: Some(String::from("title")),
+            limit: Some(1),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.tasks[0].title.to_string(), "AAA");
+
+        repository
+            .register(Task::new(
+                TaskId::new(),
+                TaskTitle::try_from("A").unwrap(),
+                TaskDescription::try_from("Task Description").unwrap(),
+                workflow.initial_status(),
+                Utc::now(),
+            ))
+            .unwrap();
+
+        let command = ListTasksCommand {
+            sort: Some(String::from("title")),
+            limit: Some(1),
+            cursor: result.next_cursor,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.tasks[0].title.to_string(), "BBB");
+        assert_eq!(result.total, 4);
+    }
+
+    #[test]
+    fn execute_when_task_is_trashed_then_lists_it_only_in_trash() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.change_deleted_at(Some(Utc::now())))
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let result = list_tasks
+            .execute(ListTasksCommand::default())
+            .ok()
+            .unwrap();
+        assert_eq!(result.total, 2);
+        assert!(result.tasks.iter().all(|task| task.id != ids[0]));
+
+        let command = ListTasksCommand {
+            trashed: true,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.tasks[0].id, ids[0]);
+    }
+
+    #[test]
+    fn execute_when_task_is_archived_then_lists_it_only_when_archived_are_included() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(task.change_archived_at(Some(Utc::now())))
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let result = list_tasks
+            .execute(ListTasksCommand::default())
+            .ok()
+            .unwrap();
+        assert_eq!(result.total, 2);
+        assert!(result.tasks.iter().all(|task| task.id != ids[0]));
+
+        let command = ListTasksCommand {
+            include_archived: true,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 3);
+    }
+
+    #[test]
+    fn execute_when_archived_task_is_trashed_then_lists_it_in_trash() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(
+                task.change_archived_at(Some(Utc::now()))
+                    .change_deleted_at(Some(Utc::now())),
+            )
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            trashed: true,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.tasks[0].id, ids[0]);
+    }
+
+    #[test]
+    fn execute_when_overdue_filter_then_returns_overdue_tasks() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        let due_at = TaskDueDate::try_from("2000-01-01").unwrap();
+        repository.update(task.change_due_at(Some(due_at))).unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            overdue: Some(true),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.tasks[0].id, ids[0]);
+
+        let command = ListTasksCommand {
+            due_before: Some(String::from("1999-12-31")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 0);
+    }
+
+    #[test]
+    fn execute_when_tree_requested_then_returns_roots_with_descendants() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        for (child, parent) in [(1, 0), (2, 1)] {
+            let task = repository.get_by_id(&ids[child]).unwrap();
+            repository
+                .update(task.change_parent(Some(ids[parent].clone())))
+                .unwrap();
+        }
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            tree: true,
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).ok().unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.tasks[0].id, ids[0]);
+        assert_eq!(result.children[&ids[0]][0].id, ids[1]);
+        assert_eq!(result.children[&ids[1]][0].id, ids[2]);
+        assert!(!result.children.contains_key(&ids[2]));
+    }
+
+    #[test]
+    fn execute_when_parent_not_found_then_returns_error() {
+        let repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            parent: Some(TaskId::new().to_string()),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::ParentNotFound);
+    }
+
+    #[test]
+    fn execute_when_invalid_parameters_then_returns_error() {
+        let repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+
+        let command = ListTasksCommand {
+            status: Some(9),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidStatus);
+
+        let command = ListTasksCommand {
+            due_before: Some(String::from("next week")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidDueBefore);
+
+        let command = ListTasksCommand {
+            priority: Some(7),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidPriority);
+
+        let command = ListTasksCommand {
+            label: Some(String::from("bug")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidLabel);
+
+        let command = ListTasksCommand {
+            sort: Some(String::from("color")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidSort);
+
+        let command = ListTasksCommand {
+            order: Some(String::from("sideways")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidOrder);
+
+        let command = ListTasksCommand {
+            limit: Some(1000),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidLimit);
+
+        let command = ListTasksCommand {
+            cursor: Some(String::from("2")),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidCursor);
+
+        let mut repository = TaskInMemoryRepository::new();
+        let task = repository.register_test_data()[0].clone();
+        let task = repository.get_by_id(&task).unwrap();
+        let list_tasks = ListTasks::new(&repository, &workflow, &clock);
+        let command = ListTasksCommand {
+            sort: Some(String::from("title")),
+            cursor: Some(TaskCursor::new(&task, TaskSortKey::Id, SortOrder::Asc).encode()),
+            ..ListTasksCommand::default()
+        };
+        let result = list_tasks.execute(command).err().unwrap();
+        assert_eq!(result, ListTasksError::InvalidCursor);
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug, Default)]
+pub struct ListTasksCommand {
+    pub status: Option<i32>,
+    pub priority: Option<i32>,
+    pub label: Option<String>,
+    pub parent: Option<String>,
+    pub due_before: Option<String>,
+    pub overdue: Option<bool>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub tree: bool,
+    pub trashed: bool,
+    pub include_archived: bool,
+}
@@ -0,0 +1,10 @@
+use crate::domain::task::task::Task;
+use crate::domain::task::task_id::TaskId;
+use std::collections::HashMap;
+
+pub struct ListTasksResult {
+    pub tasks: Vec<Task>,
+    pub total: usize,
+    pub next_cursor: Option<String>,
+    pub children: HashMap<TaskId, Vec<Task>>,
+}
@@ -0,0 +1,244 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task::Task;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_query::{TASK_QUERY_MAX_LIMIT, TaskQuery, TaskTrashFilter};
+use crate::domain::task::task_repository::TaskRepository;
+use chrono::TimeDelta;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub const TASK_TRASH_DEFAULT_RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum PurgeTrashError {
+    RepositoryError,
+}
+
+pub struct PurgeTrash<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
+    repository: &'a mut T,
+    retention: TimeDelta,
+    clock: &'a C,
+    cancelled: Option<&'a AtomicBool>,
+}
+
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> PurgeTrash<'a, T, C> {
+    pub fn new(repository: &'a mut T, retention: TimeDelta, clock: &'a C) -> Self {
+        Self {
+            repository,
+            retention,
+            clock,
+            cancelled: None,
+        }
+    }
+
+    pub fn with_cancellation(mut self, cancelled: &'a AtomicBool) -> Self {
+        self.cancelled = Some(cancelled);
+        self
+    }
+
+    pub fn execute(&mut self) -> Result<usize, PurgeTrashError> {
+        let now = self.clock.now();
+        let mut query = TaskQuery {
+            trash: TaskTrashFilter::Only,
+            deleted_before: Some(now - self.retention),
+            include_archived: true,
+            limit: TASK_QUERY_MAX_LIMIT,
+            ..TaskQuery::default()
+        };
+
+        let mut expired = Vec::new();
+        loop {
+            let page = match self.repository.search(&query) {
+                Ok(page) => page,
+                Err(_) => return Err(PurgeTrashError::RepositoryError),
+            };
+            let next_offset = query.next_offset(page.total);
+            expired.extend(page.tasks);
+            match next_offset {
+                Some(offset) => query.offset = offset,
+                None => break,
+            }
+        }
+
+        let mut purged = 0;
+        loop {
+            let mut leaves = Vec::new();
+            for task in &expired {
+                match self.has_children(task) {
+                    Ok(false) => leaves.push(task.clone()),
+                    Ok(true) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if leaves.is_empty() {
+                break;
+            }
+            for task in &leaves {
+                if self.is_cancelled() {
+                    return Ok(purged);
+                }
+                if self.repository.delete(&task.id).is_err() {
+                    return Err(PurgeTrashError::RepositoryError);
+                }
+                if self
+                    .repository
+                    .append(TaskEvent::purged(task, now))
+                    .is_err()
+                {
+                    return Err(PurgeTrashError::RepositoryError);
+                }
+                purged += 1;
+            }
+            expired.retain(|task| leaves.iter().all(|leaf| leaf.id != task.id));
+        }
+
+        Ok(purged)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    }
+
+    fn has_children(&self, task: &Task) -> Result<bool, PurgeTrashError> {
+        let query = TaskQuery {
+            parent: Some(Some(task.id.clone())),
+            trash: TaskTrashFilter::Include,
+            include_archived: true,
+            limit: 1,
+            ..TaskQuery::default()
+        };
+        match self.repository.search(&query) {
+            Ok(page) => Ok(page.total > 0),
+            Err(_) => Err(PurgeTrashError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_id::TaskId;
+    use crate::domain::task::task_workflow::TaskWorkflow;
+    use crate::repositories::sqlite_db::SqliteDb;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use crate::repositories::task::task_repository_behavior::register_test_data;
+    use crate::repositories::task::task_sqlite_repository::TaskSqliteRepository;
+    use chrono::{DateTime, Utc};
+
+    fn trash(repository: &TaskInMemoryRepository, id: &TaskId, deleted_at: &str) {
+        let deleted_at = DateTime::parse_from_rfc3339(deleted_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        let task = repository.get_by_id(id).unwrap();
+        repository
+            .update(task.change_deleted_at(Some(deleted_at)))
+            .unwrap();
+    }
+
+    #[test]
+    fn execute_when_retention_elapsed_then_deletes_expired_tasks_only() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        trash(&repository, &ids[0], "2026-09-01T00:00:00Z");
+        trash(&repository, &ids[1], "2026-10-17T00:00:00Z");
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut purge_trash = PurgeTrash::new(&mut repository, TimeDelta::days(30), &clock);
+        assert_eq!(purge_trash.execute().unwrap(), 1);
+
+        assert!(repository.get_by_id(&ids[0]).is_err());
+        assert!(repository.get_by_id(&ids[1]).unwrap().is_trashed());
+        assert!(!repository.get_by_id(&ids[2]).unwrap().is_trashed());
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.last().unwrap().kind, TaskEventKind::Purged);
+    }
+
+    #[test]
+    fn execute_when_trashed_subtree_expired_then_deletes_children_before_parents() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let mut repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        for (child, parent) in [(1, 0), (2, 1)] {
+            let task = repository.get_by_id(&ids[child]).unwrap();
+            repository
+                .update(task.change_parent(Some(ids[parent].clone())))
+                .unwrap();
+        }
+        for id in &ids {
+            let task = repository.get_by_id(id).unwrap();
+            repository
+                .update(task.change_deleted_at(Some(DateTime::UNIX_EPOCH)))
+                .unwrap();
+        }
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut purge_trash = PurgeTrash::new(&mut repository, TimeDelta::days(30), &clock);
+        assert_eq!(purge_trash.execute().unwrap(), 3);
+        for id in &ids {
+            assert!(repository.get_by_id(id).is_err());
+        }
+    }
+
+    #[test]
+    fn execute_when_archived_task_is_trashed_then_purges_it() {
+        let db = SqliteDb::open_in_memory();
+        let workflow = TaskWorkflow::default();
+        let mut repository = TaskSqliteRepository::new(&db, &workflow);
+        let ids = register_test_data(&repository);
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(task.change_parent(Some(ids[0].clone())))
+            .unwrap();
+        for id in &ids[0..2] {
+            let task = repository.get_by_id(id).unwrap();
+            repository
+                .update(
+                    task.change_archived_at(Some(DateTime::UNIX_EPOCH))
+                        .change_deleted_at(Some(DateTime::UNIX_EPOCH)),
+                )
+                .unwrap();
+        }
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut purge_trash = PurgeTrash::new(&mut repository, TimeDelta::days(30), &clock);
+        assert_eq!(purge_trash.execute().unwrap(), 2);
+        assert!(repository.get_by_id(&ids[0]).is_err());
+        assert!(repository.get_by_id(&ids[1]).is_err());
+        assert!(repository.get_by_id(&ids[2]).is_ok());
+    }
+
+    #[test]
+    fn execute_when_expired_parent_has_unexpired_trashed_child_then_keeps_parent() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(task.change_parent(Some(ids[0].clone())))
+            .unwrap();
+        trash(&repository, &ids[0], "2026-09-01T00:00:00Z");
+        trash(&repository, &ids[1], "2026-10-17T00:00:00Z");
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut purge_trash = PurgeTrash::new(&mut repository, TimeDelta::days(30), &clock);
+        assert_eq!(purge_trash.execute().unwrap(), 0);
+        assert!(repository.get_by_id(&ids[0]).unwrap().is_trashed());
+    }
+
+    #[test]
+    fn execute_when_cancelled_then_stops_before_deleting() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        trash(&repository, &ids[0], "2026-09-01T00:00:00Z");
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let cancelled = AtomicBool::new(true);
+        let mut purge_trash = PurgeTrash::new(&mut repository, TimeDelta::days(30), &clock)
+            .with_cancellation(&cancelled);
+        assert_eq!(purge_trash.execute().unwrap(), 0);
+        assert!(repository.get_by_id(&ids[0]).unwrap().is_trashed());
+    }
+}
@@ -1,127 +1,614 @@
+use crate::domain::clock::Clock;
 use crate::domain::task::task::Task;
 use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_hierarchy::{self, TaskHierarchyError};
 use crate::domain::task::task_id::TaskId;
-use crate::domain::task::task_repository::TaskRepository;
-use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_recurrence::TaskRecurrence;
+use crate::domain::task::task_repository::{self, TaskRepository};
 use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
 use crate::use_cases::task::register_task_command::RegisterTaskCommand;
 use crate::use_cases::task::register_task_result::RegisterTaskResult;
+use crate::use_cases::task::task_field_error::TaskFieldError;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum RegisterTaskError {
-    InvalidTitle,
-    InvalidDescription,
-    InvalidStatus,
+    InvalidFields(Vec<TaskFieldError>),
+    InvalidPriority,
+    InvalidDueDate,
+    InvalidParent,
+    MaxDepthExceeded,
+    InvalidRecurrence,
+    RecurrenceRequiresDueDate,
     RepositoryError,
 }
 
 #[derive(Debug)]
-pub struct RegisterTask<'a, T: TaskRepository> {
+pub struct RegisterTask<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
     repository: &'a mut T,
+    workflow: &'a TaskWorkflow,
+    clock: &'a C,
 }
 
-impl<'a, T: TaskRepository> RegisterTask<'a, T> {
-    pub fn new(repository: &'a mut T) -> Self {
-        Self { repository }
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> RegisterTask<'a, T, C> {
+    pub fn new(repository: &'a mut T, workflow: &'a TaskWorkflow, clock: &'a C) -> Self {
+        Self {
+            repository,
+            workflow,
+            clock,
+        }
     }
 
     pub fn execute(
         &mut self,
         command: RegisterTaskCommand,
+    ) -> Result<RegisterTaskResult, RegisterTaskError> {
+        let repository = &*self.repository;
+        match task_repository::transaction(repository, || self.execute_in_transaction(command)) {
+            Ok(result) => result,
+            Err(_) => Err(RegisterTaskError::RepositoryError),
+        }
+    }
+
+    pub fn execute_in_transaction(
+        &self,
+        command: RegisterTaskCommand,
     ) -> Result<RegisterTaskResult, RegisterTaskError> {
         let id = TaskId::new();
-        let title = match TaskTitle::try_from(command.title()) {
-            Ok(title) => title,
-            Err(_) => return Err(RegisterTaskError::InvalidTitle),
+        let mut errors = Vec::new();
+        let title = TaskTitle::try_from(command.title())
+            .map_err(|e| errors.push(TaskFieldError::Title(e)))
+            .ok();
+        let description = TaskDescription::try_from(command.description())
+            .map_err(|e| errors.push(TaskFieldError::Description(e)))
+            .ok();
+        let status = self
+            .workflow
+            .status(command.status())
+            .map_err(|e| errors.push(TaskFieldError::Status(e)))
+            .ok();
+        let (Some(title), Some(description), Some(status)) = (title, description, status) else {
+            return Err(RegisterTaskError::InvalidFields(errors));
+        };
+        let priority = match command.priority().map(TaskPriority::try_from).transpose() {
+            Ok(priority) => priority.unwrap_or_default(),
+            Err(_) => return Err(RegisterTaskError::InvalidPriority),
         };
-        let description = match TaskDescription::try_from(command.description()) {
-            Ok(description) => description,
-            Err(_) => return Err(RegisterTaskError::InvalidDescription),
+        let due_at = match command.due_at().map(TaskDueDate::try_from).transpose() {
+            Ok(due_at) => due_at,
+            Err(_) => return Err(RegisterTaskError::InvalidDueDate),
         };
-        let status = match TaskStatus::try_from(command.status()) {
-            Ok(status) => status,
-            Err(_) => return Err(RegisterTaskError::InvalidStatus),
+        let parent_id = match command.parent_id().map(TaskId::try_from).transpose() {
+            Ok(parent_id) => parent_id,
+            Err(_) => return Err(RegisterTaskError::InvalidParent),
         };
+        let recurrence = match command
+            .recurrence()
+            .map(TaskRecurrence::try_from)
+            .transpose()
+        {
+            Ok(recurrence) => recurrence,
+            Err(_) => return Err(RegisterTaskError::InvalidRecurrence),
+        };
+        if recurrence.is_some() && due_at.is_none() {
+            return Err(RegisterTaskError::RecurrenceRequiresDueDate);
+        }
+        if let Some(parent_id) = &parent_id {
+            match task_hierarchy::validate_parent(self.repository, &id, parent_id) {
+                Ok(_) => {}
+                Err(TaskHierarchyError::MaxDepthExceeded) => {
+                    return Err(RegisterTaskError::MaxDepthExceeded);
+                }
+                Err(TaskHierarchyError::RepositoryError) => {
+                    return Err(RegisterTaskError::RepositoryError);
+                }
+                Err(_) => return Err(RegisterTaskError::InvalidParent),
+            }
+        }
 
-        let task = Task::new(id, title, description, status);
+        let now = self.clock.now();
+        let completed_at = status.is_done().then_some(now);
+        let task = Task::new(id, title, description, status, now)
+            .change_priority(priority)
+            .change_due_at(due_at)
+            .change_parent(parent_id)
+            .change_recurrence(recurrence)
+            .change_completed_at(completed_at);
         let task = match self.repository.register(task) {
             Ok(task) => task,
             Err(_) => return Err(RegisterTaskError::RepositoryError),
         };
+        let event = TaskEvent::created(&task, command.actor(), now);
+        if self.repository.append(event).is_err() {
+            return Err(RegisterTaskError::RepositoryError);
+        }
 
-        Ok(RegisterTaskResult::from(&task))
+        Ok(RegisterTaskResult::from(&task, self.clock.now()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_description::TaskDescriptionParseError;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::domain::task::task_status::StatusParseError;
+    use crate::domain::task::task_title::TaskTitleParseError;
+    use crate::repositories::task::task_failing_repository::{
+        TaskFailingOperation, TaskFailingRepository,
+    };
     use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
 
     #[test]
     fn execute_when_valid_input_then_returns_registered_task() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
-        let command = RegisterTaskCommand::new("Task Title", "Task Description", 2);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            2,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
         let result = register_task.execute(command).ok().unwrap();
         assert_eq!(result.title, "Task Title");
         assert_eq!(result.description, "Task Description");
         assert_eq!(result.status, 2);
+        assert_eq!(result.priority, 2);
+        assert_eq!(result.due_at, None);
+    }
+
+    #[test]
+    fn execute_when_priority_given_then_returns_registered_task_with_priority() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            Some(4),
+            None,
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(result.priority, 4);
+    }
+
+    #[test]
+    fn execute_when_priority_is_invalid_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            Some(5),
+            None,
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(result, RegisterTaskError::InvalidPriority);
+    }
+
+    #[test]
+    fn execute_when_due_date_given_then_returns_registered_task_with_due_date() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            Some("2026-10-18"),
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(result.due_at, Some(String::from("2026-10-18")));
+    }
+
+    #[test]
+    fn execute_when_due_date_is_invalid_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            Some("2026-13-01"),
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(result, RegisterTaskError::InvalidDueDate);
+    }
+
+    #[test]
+    fn execute_when_parent_given_then_returns_registered_child_task() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let parent_id = ids[0].to_string();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            Some(parent_id.as_str()),
+            None,
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(result.parent_id, Some(parent_id));
+    }
+
+    #[test]
+    fn execute_when_parent_not_found_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let parent_id = TaskId::new().to_string();
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            Some(parent_id.as_str()),
+            None,
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(result, RegisterTaskError::InvalidParent);
     }
 
     #[test]
     fn execute_when_task_title_is_empty_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
-        let command = RegisterTaskCommand::new("", "Task Description", 2);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command =
+            RegisterTaskCommand::new("", "Task Description", 2, None, None, None, None, None);
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidTitle);
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Title(
+                TaskTitleParseError::Empty
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_title_is_too_long_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
         let task_title = String::from("A").repeat(65);
-        let command = RegisterTaskCommand::new(task_title.as_str(), "Task Description", 2);
+        let command = RegisterTaskCommand::new(
+            task_title.as_str(),
+            "Task Description",
+            2,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidTitle);
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Title(
+                TaskTitleParseError::TooLong {
+                    limit: 64,
+                    actual: 65
+                }
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_description_is_empty_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
-        let command = RegisterTaskCommand::new("Task Title", "", 2);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new("Task Title", "", 2, None, None, None, None, None);
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidDescription);
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Description(
+                TaskDescriptionParseError::Empty
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_description_is_too_long_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
         let task_description = String::from("A").repeat(257);
-        let command = RegisterTaskCommand::new("Task Title", task_description.as_str(), 2);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            task_description.as_str(),
+            2,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidDescription);
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Description(
+                TaskDescriptionParseError::TooLong {
+                    limit: 256,
+                    actual: 257
+                }
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_status_is_invalid_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
-        let command = RegisterTaskCommand::new("Task Title", "Task Description", 5);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            5,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidStatus);
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Status(
+                StatusParseError::InvalidStatus
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_status_is_empty_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut register_task = RegisterTask::new(&mut repository);
-        let command = RegisterTaskCommand::new("Task Title", "Task Description", 5);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            5,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![TaskFieldError::Status(
+                StatusParseError::InvalidStatus
+            )])
+        );
+    }
+
+    #[test]
+    fn execute_when_several_fields_are_invalid_then_returns_all_errors() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let task_title = String::from("A").repeat(65);
+        let command =
+            RegisterTaskCommand::new(task_title.as_str(), "", 5, None, None, None, None, None);
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(
+            result,
+            RegisterTaskError::InvalidFields(vec![
+                TaskFieldError::Title(TaskTitleParseError::TooLong {
+                    limit: 64,
+                    actual: 65
+                }),
+                TaskFieldError::Description(TaskDescriptionParseError::Empty),
+                TaskFieldError::Status(StatusParseError::InvalidStatus),
+            ])
+        );
+    }
+
+    #[test]
+    fn execute_when_recurrence_is_valid_then_returns_normalized_rule() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            Some("2026-10-18"),
+            None,
+            Some("RRULE:FREQ=weekly;INTERVAL=1"),
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(result.recurrence, Some(String::from("FREQ=WEEKLY")));
+    }
+
+    #[test]
+    fn execute_when_recurrence_is_invalid_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            Some("2026-10-18"),
+            None,
+            Some("FREQ=HOURLY"),
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(result, RegisterTaskError::InvalidRecurrence);
+    }
+
+    #[test]
+    fn execute_when_recurrence_without_due_date_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            None,
+            Some("FREQ=DAILY"),
+            None,
+        );
+        let result = register_task.execute(command).err().unwrap();
+        assert_eq!(result, RegisterTaskError::RecurrenceRequiresDueDate);
+    }
+
+    #[test]
+    fn execute_when_registered_then_sets_timestamps_from_clock() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(result.created_at, "2026-10-18T09:00:00Z");
+        assert_eq!(result.updated_at, "2026-10-18T09:00:00Z");
+        assert_eq!(result.completed_at, None);
+
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            3,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let result = register_task.execute(command).ok().unwrap();
+        assert_eq!(
+            result.completed_at,
+            Some(String::from("2026-10-18T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn execute_when_registered_then_records_created_event() {
+        let mut repository = TaskInMemoryRepository::new();
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            None,
+            None,
+            Some("alice"),
+        );
+        let result = register_task.execute(command).ok().unwrap();
+
+        let history = repository
+            .history(&TaskId::try_from(result.id.as_str()).unwrap())
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, TaskEventKind::Created);
+        assert_eq!(history[0].actor, Some(String::from("alice")));
+        assert_eq!(history[0].occurred_at, clock.now());
+        assert_eq!(history[0].changes[0].field, "title");
+        assert_eq!(history[0].changes[0].before, None);
+        assert_eq!(
+            history[0].changes[0].after,
+            Some(String::from("Task Title"))
+        );
+    }
+
+    #[test]
+    fn execute_when_event_append_fails_then_rolls_back_registration() {
+        let mut repository =
+            TaskFailingRepository::new(TaskInMemoryRepository::new(), TaskFailingOperation::Append);
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut register_task = RegisterTask::new(&mut repository, &workflow, &clock);
+        let command = RegisterTaskCommand::new(
+            "Task Title",
+            "Task Description",
+            1,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
         let result = register_task.execute(command).err().unwrap();
-        assert_eq!(result, RegisterTaskError::InvalidStatus);
+        assert_eq!(result, RegisterTaskError::RepositoryError);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 0);
     }
 }
@@ -2,14 +2,34 @@ pub struct RegisterTaskCommand {
     title: String,
     description: String,
     status: i32,
+    priority: Option<i32>,
+    due_at: Option<String>,
+    parent_id: Option<String>,
+    recurrence: Option<String>,
+    actor: Option<String>,
 }
 
 impl RegisterTaskCommand {
-    pub fn new(title: &str, description: &str, status: i32) -> RegisterTaskCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        title: &str,
+        description: &str,
+        status: i32,
+        priority: Option<i32>,
+        due_at: Option<&str>,
+        parent_id: Option<&str>,
+        recurrence: Option<&str>,
+        actor: Option<&str>,
+    ) -> RegisterTaskCommand {
         RegisterTaskCommand {
             title: String::from(title),
             description: String::from(description),
             status,
+            priority,
+            due_at: due_at.map(String::from),
+            parent_id: parent_id.map(String::from),
+            recurrence: recurrence.map(String::from),
+            actor: actor.map(String::from),
         }
     }
 
@@ -24,4 +44,24 @@ impl RegisterTaskCommand {
     pub fn status(&self) -> i32 {
         self.status
     }
+
+    pub fn priority(&self) -> Option<i32> {
+        self.priority
+    }
+
+    pub fn due_at(&self) -> Option<&str> {
+        self.due_at.as_deref()
+    }
+
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+
+    pub fn recurrence(&self) -> Option<&str> {
+        self.recurrence.as_deref()
+    }
+
+    pub fn actor(&self) -> Option<&str> {
+        self.actor.as_deref()
+    }
 }
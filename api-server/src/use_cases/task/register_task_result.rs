@@ -1,4 +1,5 @@
 use crate::domain::task::task::Task;
+use chrono::{DateTime, SecondsFormat, Utc};
 
 #[derive(Debug)]
 pub struct RegisterTaskResult {
@@ -6,15 +7,53 @@ pub struct RegisterTaskResult {
     pub title: String,
     pub description: String,
     pub status: i32,
+    pub priority: i32,
+    pub due_at: Option<String>,
+    pub overdue: bool,
+    pub labels: Vec<String>,
+    pub parent_id: Option<String>,
+    pub blocked_by: Vec<String>,
+    pub recurrence: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub completed_at: Option<String>,
+    pub deleted_at: Option<String>,
+    pub archived_at: Option<String>,
+    pub version: u64,
 }
 
 impl RegisterTaskResult {
-    pub fn from(task: &Task) -> Self {
+    pub fn from(task: &Task, now: DateTime<Utc>) -> Self {
         Self {
             id: task.id.to_string(),
             title: task.title.to_string(),
             description: task.description.to_string(),
             status: task.status.to_int(),
+            priority: task.priority.to_int(),
+            due_at: task.due_at.as_ref().map(|due_at| due_at.to_string()),
+            overdue: task.is_overdue(now),
+            labels: task.labels.iter().map(|label| label.to_string()).collect(),
+            parent_id: task
+                .parent_id
+                .as_ref()
+                .map(|parent_id| parent_id.to_string()),
+            blocked_by: task.blocked_by.iter().map(|id| id.to_string()).collect(),
+            recurrence: task
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.to_string()),
+            created_at: task.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            updated_at: task.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            completed_at: task
+                .completed_at
+                .map(|completed_at| completed_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            deleted_at: task
+                .deleted_at
+                .map(|deleted_at| deleted_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            archived_at: task
+                .archived_at
+                .map(|archived_at| archived_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            version: task.version,
         }
     }
 }
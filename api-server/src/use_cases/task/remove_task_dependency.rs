@@ -0,0 +1,65 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::task_dependency_command::TaskDependencyCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RemoveTaskDependencyError {
+    TaskNotFound,
+    RepositoryError,
+}
+
+pub struct RemoveTaskDependency<'a, T: TaskRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository, C: Clock> RemoveTaskDependency<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: TaskDependencyCommand,
+    ) -> Result<UpdateTaskResult, RemoveTaskDependencyError> {
+        let task = match self.repository.get_by_id(&TaskId::from(command.task_id)) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(RemoveTaskDependencyError::TaskNotFound);
+            }
+            Err(_) => return Err(RemoveTaskDependencyError::RepositoryError),
+        };
+
+        let blocker_id = TaskId::from(command.blocker_id);
+        match self.repository.update(task.remove_blocker(&blocker_id)) {
+            Ok(task) => Ok(UpdateTaskResult::from(&task, self.clock.now())),
+            Err(TaskRepositoryError::NotFound) => Err(RemoveTaskDependencyError::TaskNotFound),
+            Err(_) => Err(RemoveTaskDependencyError::RepositoryError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    #[test]
+    fn execute_when_blocker_exists_then_removes_it() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository.update(task.add_blocker(ids[0].clone())).unwrap();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut remove_task_dependency = RemoveTaskDependency::new(&mut repository, &clock);
+        let command =
+            TaskDependencyCommand::new(ids[1].to_string().as_str(), ids[0].to_string().as_str())
+                .unwrap();
+        let result = remove_task_dependency.execute(command).ok().unwrap();
+        assert!(result.blocked_by.is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::domain::clock::Clock;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_id::TaskId;
+use crate::domain::task::task_repository::{self, TaskRepository, TaskRepositoryError};
+use crate::use_cases::task::restore_task_command::RestoreTaskCommand;
+use crate::use_cases::task::update_task_result::UpdateTaskResult;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RestoreTaskError {
+    TaskNotFound,
+    NotTrashed,
+    ParentTrashed,
+    Conflict,
+    RepositoryError,
+}
+
+pub struct RestoreTask<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
+    repository: &'a mut T,
+    clock: &'a C,
+}
+
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> RestoreTask<'a, T, C> {
+    pub fn new(repository: &'a mut T, clock: &'a C) -> Self {
+        Self { repository, clock }
+    }
+
+    pub fn execute(
+        &mut self,
+        command: RestoreTaskCommand,
+    ) -> Result<UpdateTaskResult, RestoreTaskError> {
+        let repository = &*self.repository;
+        match task_repository::transaction(repository, || self.execute_in_transaction(command)) {
+            Ok(result) => result,
+            Err(_) => Err(RestoreTaskError::RepositoryError),
+        }
+    }
+
+    pub fn execute_in_transaction(
+        &self,
+        command: RestoreTaskCommand,
+    ) -> Result<UpdateTaskResult, RestoreTaskError> {
+        let task = match self.repository.get_by_id(&TaskId::from(command.id)) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound) => return Err(RestoreTaskError::TaskNotFound),
+            Err(_) => return Err(RestoreTaskError::RepositoryError),
+        };
+        if !task.is_trashed() {
+            return Err(RestoreTaskError::NotTrashed);
+        }
+        if let Some(parent_id) = &task.parent_id {
+            match self.repository.get_by_id(parent_id) {
+                Ok(parent) if parent.is_trashed() => return Err(RestoreTaskError::ParentTrashed),
+                Ok(_) => {}
+                Err(_) => return Err(RestoreTaskError::RepositoryError),
+            }
+        }
+
+        let now = self.clock.now();
+        let task = match self.repository.update(task.change_deleted_at(None)) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound) => return Err(RestoreTaskError::TaskNotFound),
+            Err(TaskRepositoryError::Conflict) => return Err(RestoreTaskError::Conflict),
+            Err(_) => return Err(RestoreTaskError::RepositoryError),
+        };
+        let event = TaskEvent::restored(&task, command.actor.as_deref(), now);
+        if self.repository.append(event).is_err() {
+            return Err(RestoreTaskError::RepositoryError);
+        }
+
+        Ok(UpdateTaskResult::from(&task, self.clock.now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+
+    fn trash(repository: &TaskInMemoryRepository, id: &TaskId, clock: &FixedClock) {
+        let task = repository.get_by_id(id).unwrap();
+        repository
+            .update(task.change_deleted_at(Some(clock.now())))
+            .unwrap();
+    }
+
+    #[test]
+    fn execute_when_task_is_trashed_then_restores_task() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        trash(&repository, &ids[0], &clock);
+
+        let mut restore_task = RestoreTask::new(&mut repository, &clock);
+        let command = RestoreTaskCommand::new(ids[0].to_string().as_str(), Some("alice"))
+            .ok()
+            .unwrap();
+        let result = restore_task.execute(command).unwrap();
+        assert_eq!(result.id, ids[0].to_string());
+        assert_eq!(result.deleted_at, None);
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.last().unwrap().kind, TaskEventKind::Restored);
+        assert_eq!(history.last().unwrap().actor, Some(String::from("alice")));
+    }
+
+    #[test]
+    fn execute_when_task_is_not_trashed_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut restore_task = RestoreTask::new(&mut repository, &clock);
+        let command = RestoreTaskCommand::new(ids[0].to_string().as_str(), None)
+            .ok()
+            .unwrap();
+        let result = restore_task.execute(command).err().unwrap();
+        assert_eq!(result, RestoreTaskError::NotTrashed);
+    }
+
+    #[test]
+    fn execute_when_parent_is_trashed_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(task.change_parent(Some(ids[0].clone())))
+            .unwrap();
+        trash(&repository, &ids[1], &clock);
+        trash(&repository, &ids[0], &clock);
+
+        let mut restore_task = RestoreTask::new(&mut repository, &clock);
+        let command = RestoreTaskCommand::new(ids[1].to_string().as_str(), None)
+            .ok()
+            .unwrap();
+        let result = restore_task.execute(command).err().unwrap();
+        assert_eq!(result, RestoreTaskError::ParentTrashed);
+    }
+}
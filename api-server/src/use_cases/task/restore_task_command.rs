@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+pub enum RestoreTaskCommandError {
+    InvalidFormatTaskId,
+}
+
+pub struct RestoreTaskCommand {
+    pub id: Uuid,
+    pub actor: Option<String>,
+}
+
+impl RestoreTaskCommand {
+    pub fn new(id: &str, actor: Option<&str>) -> Result<Self, RestoreTaskCommandError> {
+        let id = match Uuid::parse_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(RestoreTaskCommandError::InvalidFormatTaskId),
+        };
+
+        Ok(Self {
+            id,
+            actor: actor.map(String::from),
+        })
+    }
+}
@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum TaskDependencyCommandError {
+    InvalidFormatTaskId,
+    InvalidFormatBlockerId,
+}
+
+#[derive(Debug)]
+pub struct TaskDependencyCommand {
+    pub task_id: Uuid,
+    pub blocker_id: Uuid,
+}
+
+impl TaskDependencyCommand {
+    pub fn new(task_id: &str, blocker_id: &str) -> Result<Self, TaskDependencyCommandError> {
+        let task_id = match Uuid::parse_str(task_id) {
+            Ok(task_id) => task_id,
+            Err(_) => return Err(TaskDependencyCommandError::InvalidFormatTaskId),
+        };
+        let blocker_id = match Uuid::parse_str(blocker_id) {
+            Ok(blocker_id) => blocker_id,
+            Err(_) => return Err(TaskDependencyCommandError::InvalidFormatBlockerId),
+        };
+
+        Ok(Self {
+            task_id,
+            blocker_id,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use crate::domain::task::task_description::TaskDescriptionParseError;
+use crate::domain::task::task_status::StatusParseError;
+use crate::domain::task::task_title::TaskTitleParseError;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum TaskFieldError {
+    Title(TaskTitleParseError),
+    Description(TaskDescriptionParseError),
+    Status(StatusParseError),
+}
@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum TaskLabelCommandError {
+    InvalidFormatTaskId,
+    InvalidFormatLabelId,
+}
+
+#[derive(Debug)]
+pub struct TaskLabelCommand {
+    pub task_id: Uuid,
+    pub label_id: Uuid,
+}
+
+impl TaskLabelCommand {
+    pub fn new(task_id: &str, label_id: &str) -> Result<Self, TaskLabelCommandError> {
+        let task_id = match Uuid::parse_str(task_id) {
+            Ok(task_id) => task_id,
+            Err(_) => return Err(TaskLabelCommandError::InvalidFormatTaskId),
+        };
+        let label_id = match Uuid::parse_str(label_id) {
+            Ok(label_id) => label_id,
+            Err(_) => return Err(TaskLabelCommandError::InvalidFormatLabelId),
+        };
+
+        Ok(Self { task_id, label_id })
+    }
+}
@@ -1,80 +1,260 @@
+use crate::domain::clock::Clock;
 use crate::domain::task::task::Task;
+use crate::domain::task::task_dependency;
 use crate::domain::task::task_description::TaskDescription;
+use crate::domain::task::task_due_date::TaskDueDate;
+use crate::domain::task::task_event::TaskEvent;
+use crate::domain::task::task_event_repository::TaskEventRepository;
+use crate::domain::task::task_hierarchy::{self, TaskHierarchyError};
 use crate::domain::task::task_id::TaskId;
-use crate::domain::task::task_repository::TaskRepository;
-use crate::domain::task::task_status::TaskStatus;
+use crate::domain::task::task_priority::TaskPriority;
+use crate::domain::task::task_recurrence::TaskRecurrence;
+use crate::domain::task::task_repository::{self, TaskRepository, TaskRepositoryError};
+use crate::domain::task::task_status::TaskStatusCategory;
 use crate::domain::task::task_title::TaskTitle;
+use crate::domain::task::task_workflow::TaskWorkflow;
+use crate::use_cases::task::task_field_error::TaskFieldError;
 use crate::use_cases::task::update_task_command::UpdateTaskCommand;
 use crate::use_cases::task::update_task_result::UpdateTaskResult;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum UpdateTaskError {
-    InvalidTitle,
-    InvalidDescription,
-    InvalidStatus,
+    InvalidFields(Vec<TaskFieldError>),
+    InvalidPriority,
+    InvalidDueDate,
+    InvalidParent,
+    HierarchyCycle,
+    MaxDepthExceeded,
+    InvalidTransition,
+    BlockedByUnfinishedTasks,
+    InvalidRecurrence,
+    RecurrenceRequiresDueDate,
     TaskNotFound,
+    PreconditionFailed,
+    Conflict,
+    RepositoryError,
 }
 
 #[derive(Debug)]
-pub struct UpdateTask<'a, T: TaskRepository> {
+pub struct UpdateTask<'a, T: TaskRepository + TaskEventRepository, C: Clock> {
     repository: &'a mut T,
+    workflow: &'a TaskWorkflow,
+    clock: &'a C,
 }
 
-impl<'a, T: TaskRepository> UpdateTask<'a, T> {
-    pub fn new(repository: &'a mut T) -> Self {
-        Self { repository }
+impl<'a, T: TaskRepository + TaskEventRepository, C: Clock> UpdateTask<'a, T, C> {
+    pub fn new(repository: &'a mut T, workflow: &'a TaskWorkflow, clock: &'a C) -> Self {
+        Self {
+            repository,
+            workflow,
+            clock,
+        }
     }
 
     pub fn execute(
         &mut self,
         command: UpdateTaskCommand,
+    ) -> Result<UpdateTaskResult, UpdateTaskError> {
+        let repository = &*self.repository;
+        match task_repository::transaction(repository, || self.execute_in_transaction(command)) {
+            Ok(result) => result,
+            Err(_) => Err(UpdateTaskError::RepositoryError),
+        }
+    }
+
+    pub fn execute_in_transaction(
+        &self,
+        command: UpdateTaskCommand,
     ) -> Result<UpdateTaskResult, UpdateTaskError> {
         let id = TaskId::from(command.id);
-        let task = match self.repository.get_by_id(&id) {
-            Ok(task) => task,
-            Err(_) => return Err(UpdateTaskError::TaskNotFound),
+        let mut task = match self.repository.get_by_id(&id) {
+            Ok(task) if !task.is_trashed() => task,
+            Ok(_) | Err(TaskRepositoryError::NotFound) => {
+                return Err(UpdateTaskError::TaskNotFound);
+            }
+            Err(_) => return Err(UpdateTaskError::RepositoryError),
         };
+        if command
+            .expected_version
+            .is_some_and(|version| version != task.version)
+        {
+            return Err(UpdateTaskError::PreconditionFailed);
+        }
+        let before = task.clone();
 
-        let mut task = Task::new(
-            task.id.clone(),
-            TaskTitle::try_from(task.title.to_string().as_str()).unwrap(),
-            TaskDescription::try_from(task.description.to_string().as_str()).unwrap(),
-            TaskStatus::try_from(task.status.to_int()).unwrap(),
-        );
-        if command.title.is_some() {
-            let title = match TaskTitle::try_from(command.title.unwrap().as_str()) {
-                Ok(title) => title,
-                Err(_) => return Err(UpdateTaskError::InvalidTitle),
-            };
+        let mut errors = Vec::new();
+        let title = command
+            .title
+            .as_deref()
+            .map(TaskTitle::try_from)
+            .transpose()
+            .unwrap_or_else(|e| {
+                errors.push(TaskFieldError::Title(e));
+                None
+            });
+        let description = command
+            .description
+            .as_deref()
+            .map(TaskDescription::try_from)
+            .transpose()
+            .unwrap_or_else(|e| {
+                errors.push(TaskFieldError::Description(e));
+                None
+            });
+        let status = command
+            .status
+            .map(|status| self.workflow.status(status))
+            .transpose()
+            .unwrap_or_else(|e| {
+                errors.push(TaskFieldError::Status(e));
+                None
+            });
+        if !errors.is_empty() {
+            return Err(UpdateTaskError::InvalidFields(errors));
+        }
+
+        if let Some(title) = title {
             task = task.change_title(title);
         }
-        if command.description.is_some() {
-            let description = match TaskDescription::try_from(command.description.unwrap().as_str())
-            {
-                Ok(description) => description,
-                Err(_) => return Err(UpdateTaskError::InvalidDescription),
-            };
+        if let Some(description) = description {
             task = task.change_description(description);
         }
-        if command.status.is_some() {
-            let status = match TaskStatus::try_from(command.status.unwrap()) {
-                Ok(status) => status,
-                Err(_) => return Err(UpdateTaskError::InvalidStatus),
-            };
+        if let Some(status) = status {
+            if !self.workflow.can_transition(&task.status, &status) {
+                return Err(UpdateTaskError::InvalidTransition);
+            }
+            if status.category() != TaskStatusCategory::Todo && status != task.status {
+                match task_dependency::unfinished_blockers(self.repository, &task) {
+                    Ok(blockers) if blockers.is_empty() => {}
+                    Ok(_) => return Err(UpdateTaskError::BlockedByUnfinishedTasks),
+                    Err(_) => return Err(UpdateTaskError::RepositoryError),
+                }
+            }
             task = task.change_status(status);
         }
+        if let Some(priority) = command.priority {
+            let priority = match TaskPriority::try_from(priority) {
+                Ok(priority) => priority,
+                Err(_) => return Err(UpdateTaskError::InvalidPriority),
+            };
+            task = task.change_priority(priority);
+        }
+        if let Some(due_at) = command.due_at {
+            let due_at = match due_at.as_deref().map(TaskDueDate::try_from).transpose() {
+                Ok(due_at) => due_at,
+                Err(_) => return Err(UpdateTaskError::InvalidDueDate),
+            };
+            task = task.change_due_at(due_at);
+        }
+        if let Some(parent_id) = command.parent_id {
+            let parent_id = match parent_id.as_deref().map(TaskId::try_from).transpose() {
+                Ok(parent_id) => parent_id,
+                Err(_) => return Err(UpdateTaskError::InvalidParent),
+            };
+            if let Some(parent_id) = &parent_id {
+                match task_hierarchy::validate_parent(self.repository, &task.id, parent_id) {
+                    Ok(_) => {}
+                    Err(TaskHierarchyError::CycleDetected) => {
+                        return Err(UpdateTaskError::HierarchyCycle);
+                    }
+                    Err(TaskHierarchyError::MaxDepthExceeded) => {
+                        return Err(UpdateTaskError::MaxDepthExceeded);
+                    }
+                    Err(_) => return Err(UpdateTaskError::InvalidParent),
+                }
+            }
+            task = task.change_parent(parent_id);
+        }
+        if let Some(recurrence) = command.recurrence {
+            let recurrence = match recurrence
+                .as_deref()
+                .map(TaskRecurrence::try_from)
+                .transpose()
+            {
+                Ok(recurrence) => recurrence,
+                Err(_) => return Err(UpdateTaskError::InvalidRecurrence),
+            };
+            task = task.change_recurrence(recurrence);
+        }
+        if task.recurrence.is_some() && task.due_at.is_none() {
+            return Err(UpdateTaskError::RecurrenceRequiresDueDate);
+        }
 
-        match self.repository.update(task) {
-            Ok(task) => Ok(UpdateTaskResult::from(&task)),
-            Err(_) => Err(UpdateTaskError::TaskNotFound),
+        let now = self.clock.now();
+        let next_occurrence = match (before.status.is_done(), task.status.is_done()) {
+            (false, true) => {
+                task = task.change_completed_at(Some(now));
+                self.next_occurrence(&task)
+            }
+            (true, false) => {
+                task = task.change_completed_at(None);
+                None
+            }
+            _ => None,
+        };
+        let task = match self.repository.update(task.change_updated_at(now)) {
+            Ok(task) => task,
+            Err(TaskRepositoryError::NotFound) => return Err(UpdateTaskError::TaskNotFound),
+            Err(TaskRepositoryError::Conflict) => return Err(UpdateTaskError::Conflict),
+            Err(_) => return Err(UpdateTaskError::RepositoryError),
+        };
+        let actor = command.actor.as_deref();
+        let event = TaskEvent::updated(&before, &task, actor, now);
+        if !event.changes.is_empty() && self.repository.append(event).is_err() {
+            return Err(UpdateTaskError::RepositoryError);
         }
+        if let Some(next_occurrence) = next_occurrence {
+            let next_occurrence = match self.repository.register(next_occurrence) {
+                Ok(next_occurrence) => next_occurrence,
+                Err(_) => return Err(UpdateTaskError::RepositoryError),
+            };
+            let event = TaskEvent::created(&next_occurrence, actor, now);
+            if self.repository.append(event).is_err() {
+                return Err(UpdateTaskError::RepositoryError);
+            }
+        }
+
+        Ok(UpdateTaskResult::from(&task, self.clock.now()))
+    }
+
+    fn next_occurrence(&self, task: &Task) -> Option<Task> {
+        let (due_at, recurrence) = task
+            .recurrence
+            .as_ref()?
+            .next_occurrence(task.due_at.as_ref()?)?;
+
+        Some(
+            Task::new(
+                TaskId::new(),
+                task.title.clone(),
+                task.description.clone(),
+                self.workflow.initial_status(),
+                self.clock.now(),
+            )
+            .change_priority(task.priority.clone())
+            .change_due_at(Some(due_at))
+            .change_labels(task.labels.clone())
+            .change_parent(task.parent_id.clone())
+            .change_recurrence(Some(recurrence)),
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::task_description::TaskDescriptionParseError;
+    use crate::domain::task::task_event::TaskEventKind;
+    use crate::domain::task::task_query::TaskQuery;
+    use crate::domain::task::task_status::{StatusParseError, TaskStatus};
+    use crate::domain::task::task_title::TaskTitleParseError;
+    use crate::domain::task::task_workflow::TaskStatusDefinition;
+    use crate::repositories::task::task_failing_repository::{
+        TaskFailingOperation, TaskFailingRepository,
+    };
     use crate::repositories::task::task_in_memory_repository::TaskInMemoryRepository;
+    use chrono::DateTime;
     use uuid::Uuid;
 
     #[test]
@@ -82,40 +262,117 @@ mod tests {
         let mut repository = TaskInMemoryRepository::new();
         let ids = repository.register_test_data();
 
-        let mut update_task = UpdateTask {
-            repository: &mut repository,
-        };
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
         let command = UpdateTaskCommand::new(
-            ids.get(0).unwrap().to_string().as_str(),
+            ids.first().unwrap().to_string().as_str(),
             Some(String::from("New Task Title").as_str()),
             Some(String::from("New Task Description").as_str()),
             Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
         let result = update_task.execute(command).ok().unwrap();
-        assert_eq!(result.id, ids.get(0).unwrap().to_string());
+        assert_eq!(result.id, ids.first().unwrap().to_string());
         assert_eq!(result.title, String::from("New Task Title"));
         assert_eq!(result.description, String::from("New Task Description"));
         assert_eq!(result.status, 2);
     }
 
+    #[test]
+    fn execute_when_expected_version_matches_then_increments_version() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            Some(String::from("New Task Title").as_str()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(1),
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.version, 2);
+    }
+
+    #[test]
+    fn execute_when_expected_version_is_stale_then_returns_precondition_failed() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            Some(String::from("New Task Title").as_str()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(2),
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::PreconditionFailed);
+
+        let task = repository.get_by_id(ids.first().unwrap()).unwrap();
+        assert_eq!(task.title.to_string(), "AAA");
+        assert_eq!(task.version, 1);
+    }
+
     #[test]
     fn execute_when_invalid_task_title_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
         let ids = repository.register_test_data();
 
-        let mut update_task = UpdateTask {
-            repository: &mut repository,
-        };
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
         let command = UpdateTaskCommand::new(
-            ids.get(0).unwrap().to_string().as_str(),
+            ids.first().unwrap().to_string().as_str(),
             Some(String::from("").as_str()),
             Some(String::from("New Task Description").as_str()),
             Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
         let result = update_task.execute(command).err().unwrap();
-        assert_eq!(result, UpdateTaskError::InvalidTitle);
+        assert_eq!(
+            result,
+            UpdateTaskError::InvalidFields(vec![TaskFieldError::Title(TaskTitleParseError::Empty)])
+        );
     }
 
     #[test]
@@ -123,18 +380,98 @@ mod tests {
         let mut repository = TaskInMemoryRepository::new();
         let ids = repository.register_test_data();
 
-        let mut update_task = UpdateTask {
-            repository: &mut repository,
-        };
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
         let command = UpdateTaskCommand::new(
-            ids.get(0).unwrap().to_string().as_str(),
+            ids.first().unwrap().to_string().as_str(),
             Some(String::from("New Task Title").as_str()),
             Some(String::from("").as_str()),
             Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(
+            result,
+            UpdateTaskError::InvalidFields(vec![TaskFieldError::Description(
+                TaskDescriptionParseError::Empty
+            )])
+        );
+    }
+
+    #[test]
+    fn execute_when_several_fields_are_invalid_then_returns_all_errors() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let task_description = String::from("A").repeat(257);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            Some(String::from("").as_str()),
+            Some(task_description.as_str()),
+            Some(4),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
         let result = update_task.execute(command).err().unwrap();
-        assert_eq!(result, UpdateTaskError::InvalidDescription);
+        assert_eq!(
+            result,
+            UpdateTaskError::InvalidFields(vec![
+                TaskFieldError::Title(TaskTitleParseError::Empty),
+                TaskFieldError::Description(TaskDescriptionParseError::TooLong {
+                    limit: 256,
+                    actual: 257
+                }),
+                TaskFieldError::Status(StatusParseError::InvalidStatus),
+            ])
+        );
+    }
+
+    #[test]
+    fn execute_when_repository_fails_then_returns_repository_error() {
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        for operation in [TaskFailingOperation::GetById, TaskFailingOperation::Update] {
+            let mut inner = TaskInMemoryRepository::new();
+            let ids = inner.register_test_data();
+            let mut repository = TaskFailingRepository::new(inner, operation);
+
+            let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+            let command = UpdateTaskCommand::new(
+                ids.first().unwrap().to_string().as_str(),
+                Some(String::from("New Task Title").as_str()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            let result = update_task.execute(command).err().unwrap();
+            assert_eq!(result, UpdateTaskError::RepositoryError);
+        }
     }
 
     #[test]
@@ -142,34 +479,632 @@ mod tests {
         let mut repository = TaskInMemoryRepository::new();
         let ids = repository.register_test_data();
 
-        let mut update_task = UpdateTask {
-            repository: &mut repository,
-        };
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
         let command = UpdateTaskCommand::new(
-            ids.get(0).unwrap().to_string().as_str(),
+            ids.first().unwrap().to_string().as_str(),
             Some(String::from("New Task Title").as_str()),
             Some(String::from("New Task Description").as_str()),
             Some(4),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
         let result = update_task.execute(command).err().unwrap();
-        assert_eq!(result, UpdateTaskError::InvalidStatus);
+        assert_eq!(
+            result,
+            UpdateTaskError::InvalidFields(vec![TaskFieldError::Status(
+                StatusParseError::InvalidStatus
+            )])
+        );
     }
 
     #[test]
     fn execute_when_task_not_found_then_returns_error() {
         let mut repository = TaskInMemoryRepository::new();
-        let mut update_task = UpdateTask {
-            repository: &mut repository,
-        };
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
         let command = UpdateTaskCommand::new(
             Uuid::new_v4().to_string().as_str(),
             Some(String::from("New Task Title").as_str()),
             Some(String::from("New Task Description").as_str()),
             Some(4),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
         let result = update_task.execute(command).err().unwrap();
         assert_eq!(result, UpdateTaskError::TaskNotFound);
     }
+
+    #[test]
+    fn execute_when_priority_given_then_returns_result_with_priority() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            None,
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.priority, 3);
+
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::InvalidPriority);
+    }
+
+    #[test]
+    fn execute_when_due_date_given_then_sets_and_clears_due_date() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            Some(Some("2026-10-18T12:00:00+09:00")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.due_at, Some(String::from("2026-10-18T03:00:00Z")));
+        assert_eq!(result.title, String::from("AAA"));
+
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            Some(None),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.due_at, None);
+    }
+
+    #[test]
+    fn execute_when_invalid_due_date_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids.first().unwrap().to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            Some(Some("someday")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::InvalidDueDate);
+    }
+
+    #[test]
+    fn execute_when_parent_is_descendant_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let child = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(child.change_parent(Some(ids[0].clone())))
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let parent_id = ids[1].to_string();
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(parent_id.as_str())),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::HierarchyCycle);
+    }
+
+    #[test]
+    fn execute_when_parent_is_null_then_detaches_from_parent() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let child = repository.get_by_id(&ids[1]).unwrap();
+        repository
+            .update(child.change_parent(Some(ids[0].clone())))
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[1].to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(None),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.parent_id, None);
+    }
+
+    #[test]
+    fn execute_when_started_with_unfinished_blocker_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository.update(task.add_blocker(ids[0].clone())).unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[1].to_string().as_str(),
+            None,
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::BlockedByUnfinishedTasks);
+    }
+
+    #[test]
+    fn execute_when_blocker_is_done_then_allows_status_change() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository.get_by_id(&ids[1]).unwrap();
+        repository.update(task.add_blocker(ids[0].clone())).unwrap();
+        let blocker = repository.get_by_id(&ids[0]).unwrap();
+        repository
+            .update(blocker.change_status(TaskStatus::new(3, TaskStatusCategory::Done)))
+            .unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[1].to_string().as_str(),
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.status, 3);
+    }
+
+    #[test]
+    fn execute_when_transition_not_allowed_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::new(
+            vec![
+                TaskStatusDefinition {
+                    status: TaskStatus::new(1, TaskStatusCategory::Todo),
+                    name: String::from("ToDo"),
+                },
+                TaskStatusDefinition {
+                    status: TaskStatus::new(2, TaskStatusCategory::InProgress),
+                    name: String::from("Doing"),
+                },
+                TaskStatusDefinition {
+                    status: TaskStatus::new(4, TaskStatusCategory::InProgress),
+                    name: String::from("Review"),
+                },
+            ],
+            vec![(1, 2), (2, 4)],
+        )
+        .unwrap();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(4),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::InvalidTransition);
+
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.status, 2);
+    }
+
+    #[test]
+    fn execute_when_recurring_task_is_done_then_registers_next_occurrence() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_status(TaskStatus::new(2, TaskStatusCategory::InProgress))
+            .change_due_at(Some(TaskDueDate::try_from("2026-01-31").unwrap()))
+            .change_recurrence(Some(TaskRecurrence::try_from("FREQ=MONTHLY").unwrap()));
+        repository.update(task).unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.status, 3);
+
+        let tasks = repository.search(&TaskQuery::default()).unwrap().tasks;
+        assert_eq!(tasks.len(), 4);
+        let next = tasks.iter().find(|task| !ids.contains(&task.id)).unwrap();
+        assert_eq!(next.title.to_string(), "AAA");
+        assert_eq!(next.status.to_int(), 1);
+        assert_eq!(next.due_at.as_ref().unwrap().to_string(), "2026-02-28");
+        assert_eq!(
+            next.recurrence.as_ref().unwrap().to_string(),
+            "FREQ=MONTHLY;BYMONTHDAY=31"
+        );
+    }
+
+    #[test]
+    fn execute_when_next_occurrence_fails_then_rolls_back_update() {
+        let mut inner = TaskInMemoryRepository::new();
+        let ids = inner.register_test_data();
+        let task = inner
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_status(TaskStatus::new(2, TaskStatusCategory::InProgress))
+            .change_due_at(Some(TaskDueDate::try_from("2026-01-31").unwrap()))
+            .change_recurrence(Some(TaskRecurrence::try_from("FREQ=MONTHLY").unwrap()));
+        inner.update(task).unwrap();
+        let mut repository = TaskFailingRepository::new(inner, TaskFailingOperation::Register);
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::RepositoryError);
+
+        let task = repository.get_by_id(&ids[0]).unwrap();
+        assert_eq!(task.status.to_int(), 2);
+        assert!(task.completed_at.is_none());
+        assert!(repository.history(&ids[0]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn execute_when_recurrence_is_exhausted_then_does_not_register_next_occurrence() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_due_at(Some(TaskDueDate::try_from("2026-10-18").unwrap()))
+            .change_recurrence(Some(
+                TaskRecurrence::try_from("FREQ=DAILY;COUNT=1").unwrap(),
+            ));
+        repository.update(task).unwrap();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        update_task.execute(command).ok().unwrap();
+
+        assert_eq!(repository.search(&TaskQuery::default()).unwrap().total, 3);
+    }
+
+    #[test]
+    fn execute_when_recurrence_without_due_date_then_returns_error() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some("FREQ=DAILY")),
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).err().unwrap();
+        assert_eq!(result, UpdateTaskError::RecurrenceRequiresDueDate);
+    }
+
+    #[test]
+    fn execute_when_status_becomes_done_then_sets_completed_at() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.created_at, "1970-01-01T00:00:00Z");
+        assert_eq!(result.updated_at, "2026-10-18T09:00:00Z");
+        assert_eq!(
+            result.completed_at,
+            Some(String::from("2026-10-18T09:00:00Z"))
+        );
+
+        let clock = FixedClock::new("2026-10-19T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            Some("New Task Title"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.updated_at, "2026-10-19T09:00:00Z");
+        assert_eq!(
+            result.completed_at,
+            Some(String::from("2026-10-18T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn execute_when_done_task_is_reopened_then_clears_completed_at() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+        let task = repository
+            .get_by_id(&ids[0])
+            .unwrap()
+            .change_status(TaskStatus::new(3, TaskStatusCategory::Done))
+            .change_completed_at(Some(DateTime::UNIX_EPOCH));
+        repository.update(task).unwrap();
+
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = update_task.execute(command).ok().unwrap();
+        assert_eq!(result.completed_at, None);
+    }
+
+    #[test]
+    fn execute_when_fields_changed_then_records_field_level_diff() {
+        let mut repository = TaskInMemoryRepository::new();
+        let ids = repository.register_test_data();
+
+        let workflow = TaskWorkflow::default();
+        let clock = FixedClock::new("2026-10-18T09:00:00Z");
+        let mut update_task = UpdateTask::new(&mut repository, &workflow, &clock);
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            Some("New Task Title"),
+            Some("AAA"),
+            Some(2),
+            None,
+            None,
+            None,
+            None,
+            Some("bob"),
+            None,
+        )
+        .unwrap();
+        update_task.execute(command).ok().unwrap();
+
+        let command = UpdateTaskCommand::new(
+            ids[0].to_string().as_str(),
+            Some("New Task Title"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        update_task.execute(command).ok().unwrap();
+
+        let history = repository.history(&ids[0]).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, TaskEventKind::Updated);
+        assert_eq!(history[0].actor, Some(String::from("bob")));
+        let fields: Vec<&str> = history[0]
+            .changes
+            .iter()
+            .map(|change| change.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["title", "status"]);
+        assert_eq!(history[0].changes[0].before, Some(String::from("AAA")));
+        assert_eq!(
+            history[0].changes[0].after,
+            Some(String::from("New Task Title"))
+        );
+    }
 }
@@ -11,14 +11,27 @@ pub struct UpdateTaskCommand {
     pub title: Option<String>,
     pub description: Option<String>,
     pub status: Option<i32>,
+    pub priority: Option<i32>,
+    pub due_at: Option<Option<String>>,
+    pub parent_id: Option<Option<String>>,
+    pub recurrence: Option<Option<String>>,
+    pub actor: Option<String>,
+    pub expected_version: Option<u64>,
 }
 
 impl UpdateTaskCommand {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: &str,
         title: Option<&str>,
         description: Option<&str>,
         status: Option<i32>,
+        priority: Option<i32>,
+        due_at: Option<Option<&str>>,
+        parent_id: Option<Option<&str>>,
+        recurrence: Option<Option<&str>>,
+        actor: Option<&str>,
+        expected_version: Option<u64>,
     ) -> Result<UpdateTaskCommand, UpdateTaskCommandError> {
         let id = match Uuid::parse_str(id) {
             Ok(id) => id,
@@ -27,18 +40,15 @@ impl UpdateTaskCommand {
 
         Ok(UpdateTaskCommand {
             id,
-            title: match title {
-                Some(title) => Some(String::from(title)),
-                None => None,
-            },
-            description: match description {
-                Some(description) => Some(String::from(description)),
-                None => None,
-            },
-            status: match status {
-                Some(status) => Some(status),
-                None => None,
-            },
+            title: title.map(String::from),
+            description: description.map(String::from),
+            status,
+            priority,
+            due_at: due_at.map(|due_at| due_at.map(String::from)),
+            parent_id: parent_id.map(|parent_id| parent_id.map(String::from)),
+            recurrence: recurrence.map(|recurrence| recurrence.map(String::from)),
+            actor: actor.map(String::from),
+            expected_version,
         })
     }
 }
@@ -1,15 +1,9 @@
 /**
- * DELETE /api/v1/tasks
+ * DELETE /api/v1/tasks/{id}
  */
 export async function deleteTask(id) {
-    const res = await fetch("http://127.0.0.1:8080/api/v1/tasks", {
+    const res = await fetch(`http://127.0.0.1:8080/api/v1/tasks/${id}`, {
         method: "DELETE",
-        headers: {
-            "Content-Type": "application/json",
-        },
-        body: JSON.stringify({
-            id,
-        }),
     });
 
     const json = await res.json();
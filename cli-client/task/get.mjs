@@ -0,0 +1,11 @@
+/**
+ * GET /api/v1/tasks/{id}
+ */
+export async function getTask(id) {
+    const res = await fetch(`http://127.0.0.1:8080/api/v1/tasks/${id}`, {
+        method: "GET",
+    });
+
+    const json = await res.json();
+    console.log(res.status, json);
+}
@@ -1,14 +1,24 @@
 /**
- * register multiple tasks
- * POST /api/v1/tasks
+ * register multiple tasks in a single batch
+ * POST /api/v1/tasks:batch
  */
 export async function registerTasks(count) {
+    const operations = [];
     for (let i = 0; i < count; i++) {
-        const res = await registerTask(newTask(i));
-        const json = await res.json();
-        console.log(res.status, json);
+        operations.push({op: "create", task: newTask(i)});
     }
 
+    const res = await fetch("http://127.0.0.1:8080/api/v1/tasks:batch", {
+        method: "POST",
+        headers: {
+            "Content-Type": "application/json",
+        },
+        body: JSON.stringify({operations}),
+    });
+
+    const json = await res.json();
+    console.log(res.status, json);
+
     function newTask(i) {
         const min = 1;
         const max = 3;
@@ -21,19 +31,3 @@ export async function registerTasks(count) {
         };
     }
 }
-
-/**
- * POST /api/v1/tasks
- */
-async function registerTask(body) {
-    const res = await fetch("http://127.0.0.1:8080/api/v1/tasks", {
-        method: "POST",
-        headers: {
-            "Content-Type": "application/json",
-        },
-        body: JSON.stringify(body),
-    });
-
-    const json = await res.json();
-    console.log(res.status, json);
-}
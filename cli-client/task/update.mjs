@@ -1,15 +1,15 @@
 
 /**
- * PATCH /api/v1/tasks
+ * PATCH /api/v1/tasks/{id}
  */
 export async function updateTask({id, title, description, status}) {
-    const res = await fetch("http://127.0.0.1:8080/api/v1/tasks", {
+    const res = await fetch(`http://127.0.0.1:8080/api/v1/tasks/${id}`, {
         method: "PATCH",
         headers: {
             "Content-Type": "application/json",
         },
         body: JSON.stringify((() => {
-            const ret = {id};
+            const ret = {};
             if (title !== "") {
                 ret.title = title;
             }
@@ -9,6 +9,11 @@ GRANT
         INSERT,
         UPDATE,
         DELETE,
+        CREATE,
+        ALTER,
+        DROP,
+        INDEX,
+        REFERENCES,
         USAGE
         ON app_db.*
     TO 'app_user_role';
@@ -17,11 +22,4 @@ GRANT
 GRANT `app_user_role` TO 'app_user'@'%';
 SET DEFAULT ROLE app_user_role TO 'app_user'@'%';
 
--- Create tables
-CREATE TABLE IF NOT EXISTS tasks
-(
-    id          VARCHAR(128) NOT NULL PRIMARY KEY,
-    title       VARCHAR(64)  NOT NULL,
-    description VARCHAR(256) NOT NULL,
-    status      INT          NOT NULL
-);
\ No newline at end of file
+-- Tables are created by the api_server migrations (api-server/migrations/mysql)
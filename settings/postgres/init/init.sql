@@ -0,0 +1,65 @@
+-- Database and owner are created by the postgres image from POSTGRES_DB / POSTGRES_USER
+
+-- Create tables
+CREATE TABLE IF NOT EXISTS tasks
+(
+    id           UUID         NOT NULL PRIMARY KEY,
+    title        VARCHAR(64)  NOT NULL,
+    description  VARCHAR(256) NOT NULL,
+    status       INTEGER      NOT NULL,
+    priority     INTEGER      NOT NULL DEFAULT 2,
+    due_at       TIMESTAMPTZ  NULL,
+    due_all_day  BOOLEAN      NOT NULL DEFAULT FALSE,
+    parent_id    UUID         NULL REFERENCES tasks (id),
+    recurrence   VARCHAR(256) NULL,
+    created_at   TIMESTAMPTZ  NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at   TIMESTAMPTZ  NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at TIMESTAMPTZ  NULL,
+    deleted_at   TIMESTAMPTZ  NULL,
+    archived_at  TIMESTAMPTZ  NULL,
+    version      BIGINT       NOT NULL DEFAULT 1
+);
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ NULL;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS archived_at TIMESTAMPTZ NULL;
+CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks (status);
+CREATE INDEX IF NOT EXISTS idx_tasks_priority ON tasks (priority, due_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_due_at ON tasks (due_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_parent_id ON tasks (parent_id);
+CREATE INDEX IF NOT EXISTS idx_tasks_deleted_at ON tasks (deleted_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_archived_at ON tasks (archived_at);
+
+CREATE TABLE IF NOT EXISTS labels
+(
+    id    UUID        NOT NULL PRIMARY KEY,
+    name  VARCHAR(32) NOT NULL,
+    color CHAR(7)     NOT NULL,
+    CONSTRAINT uq_labels_name UNIQUE (name)
+);
+
+CREATE TABLE IF NOT EXISTS task_labels
+(
+    task_id  UUID NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    label_id UUID NOT NULL REFERENCES labels (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, label_id)
+);
+CREATE INDEX IF NOT EXISTS idx_task_labels_label_id ON task_labels (label_id);
+
+CREATE TABLE IF NOT EXISTS task_dependencies
+(
+    task_id       UUID NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    blocked_by_id UUID NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, blocked_by_id)
+);
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_blocked_by_id ON task_dependencies (blocked_by_id);
+
+CREATE TABLE IF NOT EXISTS task_events
+(
+    id          BIGINT       GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    task_id     UUID         NOT NULL,
+    kind        VARCHAR(16)  NOT NULL,
+    actor       VARCHAR(128) NULL,
+    changes     JSONB        NOT NULL,
+    occurred_at TIMESTAMPTZ  NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_task_events_task_id ON task_events (task_id, id);
@@ -1,4 +1,4 @@
-import type { Task, TaskCreatePayload, TaskUpdatePayload } from "./define.ts";
+import type { Task, TaskCreatePayload, TaskUpdatePayload, Workflow } from "./define.ts";
 
 const endpoint = "/api/v1/tasks";
 const headers: HeadersInit = { "Content-Type": "application/json" };
@@ -19,8 +19,14 @@ export async function registerTask(payload: TaskCreatePayload): Promise<Task> {
   return json.data;
 }
 
-export async function updateTask(payload: TaskUpdatePayload): Promise<Task> {
-  const res = await fetch(endpoint, {
+export async function getTask(id: Task["id"]): Promise<Task> {
+  const res = await fetch(`${endpoint}/${id}`);
+  const json = await res.json();
+  return json.data;
+}
+
+export async function updateTask({ id, ...payload }: TaskUpdatePayload): Promise<Task> {
+  const res = await fetch(`${endpoint}/${id}`, {
     method: "PATCH",
     headers,
     body: JSON.stringify(payload),
@@ -30,10 +36,14 @@ export async function updateTask(payload: TaskUpdatePayload): Promise<Task> {
 }
 
 export async function deleteTask(id: Task["id"]) {
-  const res = await fetch(endpoint, {
+  const res = await fetch(`${endpoint}/${id}`, {
     method: "DELETE",
-    headers,
-    body: JSON.stringify({ id }),
   });
   return res.status === 200;
 }
+
+export async function getWorkflow(): Promise<Workflow> {
+  const res = await fetch("/api/v1/workflow");
+  const json = await res.json();
+  return json.data;
+}
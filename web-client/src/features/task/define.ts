@@ -14,6 +14,17 @@ export type TaskUpdatePayload = {
   status: TaskStatus;
 } & Partial<Omit<Task, "id" | "status">>;
 
+export type WorkflowStatus = {
+  id: number;
+  name: string;
+  category: "todo" | "in_progress" | "done";
+};
+
+export type Workflow = {
+  statuses: WorkflowStatus[];
+  transitions: { from: number; to: number }[];
+};
+
 export type TaskEditorSubmitSource =
   | {
       mode: "register";